@@ -50,6 +50,34 @@ impl Aabb {
         }
     }
 
+    /// The box corner furthest along `direction` - the leading corner an actor travelling that
+    /// way reaches the boundary with first, rather than its center. `direction` is in this box's
+    /// own local space; see [`Aabb::world_support_point`] for the world-space version.
+    pub fn support_corner(&self, direction: Vec3) -> Vec3 {
+        Vec3::new(
+            if direction.x < 0.0 { self.min.x } else { self.max.x },
+            if direction.y < 0.0 { self.min.y } else { self.max.y },
+            if direction.z < 0.0 { self.min.z } else { self.max.z },
+        )
+    }
+
+    /// World-space support point of this (local-space) box along the world-space `direction` -
+    /// the actual corner, after `transform`'s rotation and scale, furthest along `direction`.
+    /// `Aabb::min`/`max` are local-space (see `draw_aabb_system`), so a local corner can't just be
+    /// added onto a world position: for a rotated actor that isn't the true leading corner at all,
+    /// and nateroids spawn with a random rotation and keep spinning. Rotating `direction` into
+    /// local space first, picking the corner there, then mapping it back out through `transform`
+    /// keeps this correct at every orientation.
+    pub fn world_support_point(&self, transform: &Transform, direction: Vec3) -> Vec3 {
+        if direction == Vec3::ZERO {
+            return transform.translation;
+        }
+
+        let local_direction = transform.rotation.inverse() * direction;
+        let local_corner = self.support_corner(local_direction) * transform.scale;
+        transform.translation + transform.rotation * local_corner
+    }
+
     pub fn intersects(&self, other: &Self) -> bool {
         self.min.x <= other.max.x
             && self.max.x >= other.min.x
@@ -65,6 +93,31 @@ impl Aabb {
             max: (self.max * scale) + position,
         }
     }
+
+    /// Minimum-translation vector that moves `self` out of `other` along whichever axis has the
+    /// shallowest overlap, pushed away from `other`'s center on that axis. Returns `None` when
+    /// the boxes don't intersect.
+    pub fn penetration_vector(&self, other: &Self) -> Option<Vec3> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        let overlap = Vec3::new(
+            self.max.x.min(other.max.x) - self.min.x.max(other.min.x),
+            self.max.y.min(other.max.y) - self.min.y.max(other.min.y),
+            self.max.z.min(other.max.z) - self.min.z.max(other.min.z),
+        );
+
+        let away = self.center() - other.center();
+
+        if overlap.x <= overlap.y && overlap.x <= overlap.z {
+            Some(Vec3::new(overlap.x * away.x.signum(), 0.0, 0.0))
+        } else if overlap.y <= overlap.z {
+            Some(Vec3::new(0.0, overlap.y * away.y.signum(), 0.0))
+        } else {
+            Some(Vec3::new(0.0, 0.0, overlap.z * away.z.signum()))
+        }
+    }
 }
 
 fn draw_aabb_system(mut gizmos: Gizmos<AabbGizmo>, aabbs: Query<(&Transform, &Aabb)>) {
@@ -111,6 +164,50 @@ pub fn get_scene_aabb(
     }
 }
 
+/// Returns the first mesh found while walking `scene`, for collider construction that needs
+/// actual geometry (convex hull / trimesh) rather than just an AABB. Scenes in this project are
+/// single-mesh per actor, so the first hit is the one we want.
+pub fn get_scene_mesh<'a>(
+    scenes: &Assets<Scene>,
+    meshes: &'a Assets<Mesh>,
+    handle: &Handle<Scene>,
+) -> Option<&'a Mesh> {
+    let scene = scenes.get(handle)?;
+    let mut query_state = scene.world.try_query::<EntityRef>()?;
+    for entity in query_state.iter(&scene.world) {
+        if let Some(mesh_handle) = entity.get::<Mesh3d>()
+            && let Some(mesh) = meshes.get(mesh_handle)
+        {
+            return Some(mesh);
+        }
+    }
+    None
+}
+
+/// Pulls `Mesh::ATTRIBUTE_POSITION` and the index buffer out of `mesh`, for collider
+/// construction that needs real triangles (convex decomposition) rather than just a point
+/// cloud or an AABB. Returns `None` if either is missing so the caller can fall back to a
+/// simpler collider shape.
+pub fn get_mesh_vertices_indices(mesh: &Mesh) -> Option<(Vec<Vec3>, Vec<[u32; 3]>)> {
+    let positions = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|attr| attr.as_float3())?
+        .iter()
+        .map(|position| Vec3::from(*position))
+        .collect();
+
+    let indices = mesh
+        .indices()?
+        .iter()
+        .map(|index| index as u32)
+        .collect::<Vec<_>>()
+        .chunks_exact(3)
+        .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+        .collect();
+
+    Some((positions, indices))
+}
+
 fn get_mesh_aabb(mesh: &Mesh) -> Aabb {
     if let Some(positions) = mesh
         .attribute(Mesh::ATTRIBUTE_POSITION)
@@ -138,3 +235,30 @@ fn combine_aabb(a: Aabb, b: Aabb) -> Aabb {
         max: a.max.max(b.max),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn world_support_point_follows_rotation_and_scale() {
+        // Non-uniformly-scaled box, rotated 90 degrees around Z, so the world-space support
+        // corner along world +X is a *different* local corner than the one a naive "support
+        // corner added straight onto the world position" lookup would pick.
+        let aabb = Aabb {
+            min: Vec3::new(-1.0, -2.0, -0.5),
+            max: Vec3::new(3.0, 0.5, 2.0),
+        };
+        let transform = Transform {
+            translation: Vec3::new(5.0, 0.0, 0.0),
+            rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+            scale: Vec3::new(2.0, 1.0, 1.0),
+        };
+
+        let support = aabb.world_support_point(&transform, Vec3::X);
+
+        // Hand-derived: world +X rotates into local (0, -1, 0), whose support corner is
+        // (max.x, min.y, max.z) = (3, -2, 2); scaled then rotated back out and translated.
+        assert!(support.abs_diff_eq(Vec3::new(7.0, 6.0, 2.0), 1e-4));
+    }
+}
@@ -7,11 +7,19 @@ use bevy_inspector_egui::quick::ResourceInspectorPlugin;
 
 use super::Aabb;
 use super::aabb;
+use super::actor_content::ActorContent;
+use super::actor_content::ActorContentEntry;
+use super::actor_content::ActorContentLoader;
 use super::actor_template::MissileConfig;
+use super::collapse::CollapseEvent;
+use super::collapse::CollapseSequence;
+use super::g_force::GForce;
 use super::actor_template::NateroidConfig;
 use super::actor_template::SpaceshipConfig;
 use super::missile::Missile;
+use super::missile::WeaponEnergyConfig;
 use super::nateroid::Nateroid;
+use super::weapon::WeaponLoadout;
 use super::spaceship::Spaceship;
 use crate::asset_loader::AssetsState;
 use crate::asset_loader::SceneAssets;
@@ -23,18 +31,32 @@ use crate::game_input::toggle_active;
 // Shared between initial spawn and runtime 2D enforcement
 pub const GLTF_ROTATION_X: f32 = std::f32::consts::FRAC_PI_2; // +90Â°
 
+const ACTOR_CONTENT_PATH: &str = "content/actors.ron";
+
 // call flow is to initialize the ensemble config which has the defaults
-// for an actor - configure defaults in initial_actor_config.rs
+// for an actor - configure defaults in actor_template.rs, then override
+// them with whatever's in `assets/content/actors.ron`
 pub struct ActorConfigPlugin;
 
 impl Plugin for ActorConfigPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AssetsState::Loaded), initialize_actor_configs)
+        app.init_asset::<ActorContent>()
+            .init_asset_loader::<ActorContentLoader>()
+            .add_systems(PreStartup, load_actor_content)
+            .add_systems(OnEnter(AssetsState::Loaded), initialize_actor_configs)
+            .add_systems(
+                Update,
+                reload_actor_configs_on_content_change.run_if(in_state(AssetsState::Loaded)),
+            )
             .add_observer(propagate_render_layers_on_spawn)
             .add_plugins(
                 ResourceInspectorPlugin::<MissileConfig>::default()
                     .run_if(toggle_active(false, GameAction::MissileInspector)),
             )
+            .add_plugins(
+                ResourceInspectorPlugin::<WeaponEnergyConfig>::default()
+                    .run_if(toggle_active(false, GameAction::WeaponEnergyInspector)),
+            )
             .add_plugins(
                 ResourceInspectorPlugin::<NateroidConfig>::default()
                     .run_if(toggle_active(false, GameAction::NateroidInspector)),
@@ -46,6 +68,16 @@ impl Plugin for ActorConfigPlugin {
     }
 }
 
+/// Handle to the loaded `assets/content/actors.ron` asset. Kept around (rather than dropped
+/// after the initial load) so the asset stays loaded and its `AssetEvent::Modified` events keep
+/// firing when the file is edited on disk.
+#[derive(Resource)]
+struct ActorContentHandle(Handle<ActorContent>);
+
+fn load_actor_content(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ActorContentHandle(asset_server.load(ACTOR_CONTENT_PATH)));
+}
+
 #[derive(Reflect, InspectorOptions, Clone, Debug)]
 #[reflect(InspectorOptions)]
 pub struct ActorConfig {
@@ -55,12 +87,19 @@ pub struct ActorConfig {
     #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
     pub angular_damping:          Option<f32>,
     #[reflect(ignore)]
+    pub collapse_sequence:        Vec<CollapseEvent>,
+    #[reflect(ignore)]
     pub collider:                 Collider,
     #[inspector(min = 0.1, max = 3.0, display = NumberDisplay::Slider)]
     pub collider_margin:          f32,
     pub collider_type:            ColliderType,
     pub collision_damage:         f32,
     pub collision_layers:         CollisionLayers,
+    /// Acceleration (units/s²) this actor can sustain before structural-stress damage kicks in -
+    /// see `GForce`. `None` opts the actor out entirely (the default for anything not explicitly
+    /// configured).
+    #[inspector(min = 0.0, max = 500.0, display = NumberDisplay::Slider)]
+    pub g_force_tolerance:        Option<f32>,
     pub gravity_scale:            f32,
     pub health:                   f32,
     #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
@@ -79,6 +118,7 @@ pub struct ActorConfig {
     pub rigid_body:               RigidBody,
     #[reflect(ignore)]
     pub scene:                    Handle<Scene>,
+    pub shield:                   Option<ShieldConfig>,
     pub spawn_timer_seconds:      Option<f32>,
     pub transform:                Transform,
     #[reflect(ignore)]
@@ -93,10 +133,60 @@ pub struct Health(pub f32);
 #[reflect(Component)]
 pub struct CollisionDamage(pub f32);
 
+/// Static shield loadout for an `ActorConfig`. `None` means the actor has no shield layer and
+/// collision damage goes straight to `Health`, as it always has.
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct ShieldConfig {
+    pub max:           f32,
+    pub regen_per_sec: f32,
+    pub regen_delay:   f32,
+}
+
+/// A regenerating layer of defense that absorbs collision damage ahead of `Health`. Taking any
+/// damage resets the regen delay; once `regen_delay` seconds pass without taking damage,
+/// `current` climbs back toward `max` at `regen_per_sec`.
+#[derive(Reflect, Component, Clone, Debug)]
+#[reflect(Component)]
+pub struct Shield {
+    pub current:                     f32,
+    pub max:                         f32,
+    pub regen_per_sec:               f32,
+    pub regen_delay:                 f32,
+    pub(crate) seconds_since_damage: f32,
+}
+
+impl Shield {
+    pub fn new(config: ShieldConfig) -> Self {
+        Self {
+            current: config.max,
+            max: config.max,
+            regen_per_sec: config.regen_per_sec,
+            regen_delay: config.regen_delay,
+            seconds_since_damage: config.regen_delay,
+        }
+    }
+}
+
 #[derive(Reflect, Debug, Clone, PartialEq, Eq)]
 pub enum ColliderType {
     Ball,
     Cuboid,
+    /// Fast convex approximation of the scene's mesh geometry. Falls back to `Cuboid` if no
+    /// mesh can be found or avian3d can't build a hull from it.
+    ConvexHull,
+    /// Exact (concave-capable) geometry from the scene's mesh. Falls back to `Cuboid` under the
+    /// same conditions as `ConvexHull`. Avian3d builds trimesh colliders without mass
+    /// properties, so this is only suitable for non-dynamic actors.
+    Trimesh,
+    /// VHACD decomposition of the scene's mesh into a compound of convex pieces - keeps concave
+    /// detail (unlike `ConvexHull`) while still carrying mass properties (unlike `Trimesh`), at
+    /// the cost of more sub-colliders. Falls back to `Cuboid` under the same conditions as
+    /// `ConvexHull`.
+    ConvexDecomposition,
+    /// A capsule fit to the scene's AABB: the longest axis becomes the segment length, the max
+    /// of the other two half-extents becomes the radius. Cheapest dynamic-capable shape for
+    /// elongated actors that don't need `ConvexHull`'s silhouette accuracy.
+    Capsule,
 }
 
 type ActorRenderLayersQuery<'w, 'a> =
@@ -127,39 +217,97 @@ pub const LOCKED_AXES_SPACESHIP: LockedAxes = LockedAxes::new()
     .lock_translation_z();
 
 pub fn initialize_actor_configs(
-    mut commands: Commands,
+    commands: Commands,
+    content_handle: Res<ActorContentHandle>,
+    content_assets: Res<Assets<ActorContent>>,
+    meshes: Res<Assets<Mesh>>,
+    scenes: Res<Assets<Scene>>,
+    scene_assets: Res<SceneAssets>,
+) {
+    let content = content_assets.get(&content_handle.0);
+    build_actor_configs(commands, content, &scenes, &meshes, &scene_assets);
+}
+
+/// Re-runs `initialize_actor_configs` whenever `actors.ron` finishes (re)loading, so editing it
+/// on disk - with Bevy's `file_watcher` feature enabled - re-inserts the actor config resources
+/// without a recompile.
+fn reload_actor_configs_on_content_change(
+    commands: Commands,
+    content_handle: Res<ActorContentHandle>,
+    content_assets: Res<Assets<ActorContent>>,
     meshes: Res<Assets<Mesh>>,
     scenes: Res<Assets<Scene>>,
     scene_assets: Res<SceneAssets>,
 ) {
+    if !content_assets.is_changed() {
+        return;
+    }
+
+    let Some(content) = content_assets.get(&content_handle.0) else {
+        return;
+    };
+
+    info!("Reloading actor configs from {ACTOR_CONTENT_PATH}");
+    build_actor_configs(commands, Some(content), &scenes, &meshes, &scene_assets);
+}
+
+fn build_actor_configs(
+    mut commands: Commands,
+    content: Option<&ActorContent>,
+    scenes: &Assets<Scene>,
+    meshes: &Assets<Mesh>,
+    scene_assets: &SceneAssets,
+) {
+    let empty_entry = ActorContentEntry::default();
+    let entry_for = |name: &str| {
+        content
+            .and_then(|content| content.actor.get(name))
+            .unwrap_or(&empty_entry)
+    };
+
     let mut nateroid_defaults = NateroidConfig::default();
-    let nateroid_actor_config = initialize_actor_config(
+    let nateroid_entry = entry_for("nateroid");
+    nateroid_entry.apply_to_actor_config(&mut nateroid_defaults.actor_config, scene_assets);
+    if let Some(linear_velocity) = nateroid_entry.velocity_behavior.linear_velocity {
+        nateroid_defaults.linear_velocity = linear_velocity;
+    }
+    if let Some(angular_velocity) = nateroid_entry.velocity_behavior.angular_velocity {
+        nateroid_defaults.angular_velocity = angular_velocity;
+    }
+    nateroid_defaults.actor_config = initialize_actor_config(
         nateroid_defaults.actor_config,
-        &scenes,
-        &meshes,
-        &scene_assets.nateroid,
+        scenes,
+        meshes,
+        &scene_assets.scene("nateroid"),
     );
-    nateroid_defaults.actor_config = nateroid_actor_config;
     commands.insert_resource(nateroid_defaults);
 
     let mut missile_defaults = MissileConfig::default();
-    let missile_actor_config = initialize_actor_config(
+    let missile_entry = entry_for("missile");
+    missile_entry.apply_to_actor_config(&mut missile_defaults.actor_config, scene_assets);
+    missile_defaults.actor_config = initialize_actor_config(
         missile_defaults.actor_config,
-        &scenes,
-        &meshes,
-        &scene_assets.missile,
+        scenes,
+        meshes,
+        &scene_assets.scene("missile"),
     );
-    missile_defaults.actor_config = missile_actor_config;
     commands.insert_resource(missile_defaults);
 
+    let mut weapon_loadout = WeaponLoadout::default();
+    if let Some(base_velocity) = missile_entry.velocity_behavior.linear_velocity {
+        weapon_loadout.active_slot_mut().base_velocity = base_velocity;
+    }
+    commands.insert_resource(weapon_loadout);
+
     let mut spaceship_defaults = SpaceshipConfig::default();
-    let spaceship_actor_config = initialize_actor_config(
+    let spaceship_entry = entry_for("spaceship");
+    spaceship_entry.apply_to_actor_config(&mut spaceship_defaults.actor_config, scene_assets);
+    spaceship_defaults.actor_config = initialize_actor_config(
         spaceship_defaults.actor_config.clone(),
-        &scenes,
-        &meshes,
-        &scene_assets.spaceship,
+        scenes,
+        meshes,
+        &scene_assets.scene("spaceship"),
     );
-    spaceship_defaults.actor_config = spaceship_actor_config;
     commands.insert_resource(spaceship_defaults);
 }
 
@@ -188,6 +336,49 @@ fn initialize_actor_config(
             size.y * config.collider_margin,
             size.z * config.collider_margin,
         ),
+        ColliderType::ConvexHull => aabb::get_scene_mesh(scenes, meshes, scene_handle)
+            .and_then(Collider::convex_hull_from_mesh)
+            .unwrap_or_else(|| {
+                Collider::cuboid(
+                    size.x * config.collider_margin,
+                    size.y * config.collider_margin,
+                    size.z * config.collider_margin,
+                )
+            }),
+        ColliderType::Trimesh => aabb::get_scene_mesh(scenes, meshes, scene_handle)
+            .and_then(Collider::trimesh_from_mesh)
+            .unwrap_or_else(|| {
+                Collider::cuboid(
+                    size.x * config.collider_margin,
+                    size.y * config.collider_margin,
+                    size.z * config.collider_margin,
+                )
+            }),
+        ColliderType::ConvexDecomposition => aabb::get_scene_mesh(scenes, meshes, scene_handle)
+            .and_then(aabb::get_mesh_vertices_indices)
+            .map(|(vertices, indices)| Collider::convex_decomposition(vertices, indices))
+            .unwrap_or_else(|| {
+                Collider::cuboid(
+                    size.x * config.collider_margin,
+                    size.y * config.collider_margin,
+                    size.z * config.collider_margin,
+                )
+            }),
+        ColliderType::Capsule => {
+            let axes = [size.x, size.y, size.z];
+            let (longest_axis, &length) = axes
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .expect("axes is non-empty");
+            let radius = axes
+                .iter()
+                .enumerate()
+                .filter(|(axis, _)| *axis != longest_axis)
+                .map(|(_, extent)| extent / 2.0)
+                .fold(0.0_f32, f32::max);
+            Collider::capsule(radius * config.collider_margin, length * config.collider_margin)
+        },
     };
 
     config.aabb = aabb;
@@ -232,6 +423,26 @@ pub fn insert_configured_components(
             .insert(AngularDamping(angular));
     }
 
+    // only actors with a configured destruction timeline get a CollapseSequence - otherwise
+    // they despawn immediately on death, same as today
+    if !config.collapse_sequence.is_empty() {
+        commands
+            .entity(actor_entity)
+            .insert(CollapseSequence::new(config.collapse_sequence.clone()));
+    }
+
+    // only actors with a configured shield loadout get one - nateroids and missiles go
+    // straight to Health
+    if let Some(shield_config) = config.shield {
+        commands.entity(actor_entity).insert(Shield::new(shield_config));
+    }
+
+    // only actors with a configured g-force tolerance take structural-stress damage from
+    // violent acceleration - everything else is immune, same as today
+    if let Some(g_force_tolerance) = config.g_force_tolerance {
+        commands.entity(actor_entity).insert(GForce::new(g_force_tolerance));
+    }
+
     // reset the timer if there is a configured spawn_timer_seconds
     config.spawn_timer = create_spawn_timer(config.spawn_timer_seconds);
 }
@@ -0,0 +1,223 @@
+//! Data-driven overrides for [`ActorConfig`] defaults, loaded from `assets/content/actors.ron`.
+//!
+//! The file is loaded through Bevy's asset pipeline (see [`ActorContentLoader`]) rather than
+//! read directly off disk, so enabling Bevy's `file_watcher` feature gets designers live
+//! reload for free: editing the file re-triggers [`AssetEvent::Modified`], which
+//! `actor_config`'s hot-reload system picks up to rebuild and re-insert the actor config
+//! resources without a recompile.
+use std::collections::HashMap;
+
+use bevy::asset::AssetLoader;
+use bevy::asset::LoadContext;
+use bevy::asset::io::Reader;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use super::actor_config::ActorConfig;
+use super::actor_config::ColliderType;
+use super::collapse::CollapseEvent;
+use super::collapse::EffectSpec;
+use crate::asset_loader::SceneAssets;
+
+/// Root shape of `actors.ron`: one entry per actor kind, keyed by the same name used in
+/// `SceneAssets` (`"nateroid"`, `"missile"`, `"spaceship"`).
+#[derive(Asset, TypePath, Deserialize, Default, Debug, Clone)]
+pub struct ActorContent {
+    #[serde(default)]
+    pub actor: HashMap<String, ActorContentEntry>,
+}
+
+/// Overrides for a single actor kind. Every field is optional so the file only needs to
+/// mention what a designer actually wants to change from the Rust defaults in
+/// `actor_template.rs`.
+#[derive(Deserialize, Default, Debug, Clone)]
+pub struct ActorContentEntry {
+    pub health:           Option<f32>,
+    pub mass:             Option<f32>,
+    pub collision_damage: Option<f32>,
+    pub restitution:      Option<f32>,
+    pub gravity_scale:    Option<f32>,
+    pub collider_type:    Option<ColliderTypeContent>,
+    /// Uniform scale applied to the actor's `Transform`, replacing the hardcoded
+    /// `*_SCALE`/`*_SCALE_UP` constants in `actor_template.rs`.
+    pub mesh_scalar:      Option<f32>,
+    #[serde(default)]
+    pub velocity_behavior: VelocityBehaviorContent,
+    #[serde(default)]
+    pub spawn_position:    SpawnPositionContent,
+    /// Scripted destruction timeline - see `collapse.rs`. Left empty, an actor despawns
+    /// immediately on death same as always.
+    #[serde(default)]
+    pub collapse_sequence: Vec<CollapseEventContent>,
+}
+
+/// Mirrors [`ColliderType`] so the content file can stay decoupled from avian3d types.
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ColliderTypeContent {
+    Ball,
+    Cuboid,
+    ConvexHull,
+    Trimesh,
+}
+
+impl From<ColliderTypeContent> for ColliderType {
+    fn from(content: ColliderTypeContent) -> Self {
+        match content {
+            ColliderTypeContent::Ball => Self::Ball,
+            ColliderTypeContent::Cuboid => Self::Cuboid,
+            ColliderTypeContent::ConvexHull => Self::ConvexHull,
+            ColliderTypeContent::Trimesh => Self::Trimesh,
+        }
+    }
+}
+
+/// Overrides for an actor's movement defaults. Only `nateroid` uses both fields today;
+/// `missile` reads `linear_velocity` as its straight-line speed and ignores
+/// `angular_velocity`, and `spaceship` ignores both since it's player-driven.
+#[derive(Deserialize, Default, Debug, Clone, Copy)]
+pub struct VelocityBehaviorContent {
+    pub linear_velocity:  Option<f32>,
+    pub angular_velocity: Option<f32>,
+}
+
+/// Fixed world-space spawn position override, applied per-axis so an unset axis keeps
+/// whatever `actor_template.rs` already put in `ActorConfig::transform.translation`.
+#[derive(Deserialize, Default, Debug, Clone, Copy)]
+pub struct SpawnPositionContent {
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+    pub z: Option<f32>,
+}
+
+/// Mirrors [`CollapseEvent`], letting a destruction timeline be authored in `actors.ron`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CollapseEventContent {
+    pub time_seconds: f32,
+    #[serde(default)]
+    pub effects:      Vec<EffectSpecContent>,
+}
+
+impl CollapseEventContent {
+    fn resolve(&self, scene_assets: &SceneAssets) -> CollapseEvent {
+        CollapseEvent {
+            time_seconds: self.time_seconds,
+            effects:      self
+                .effects
+                .iter()
+                .map(|effect| effect.resolve(scene_assets))
+                .collect(),
+        }
+    }
+}
+
+/// Mirrors [`EffectSpec`], naming the spawned scene the same way `asset_manifest.ron` does
+/// (`SceneAssets` lookup) rather than a raw asset path.
+#[derive(Deserialize, Debug, Clone)]
+pub struct EffectSpecContent {
+    pub scene:         String,
+    pub size:          f32,
+    pub lifetime_secs: f32,
+}
+
+impl EffectSpecContent {
+    fn resolve(&self, scene_assets: &SceneAssets) -> EffectSpec {
+        EffectSpec {
+            scene:         scene_assets.scene(&self.scene),
+            size:          self.size,
+            lifetime_secs: self.lifetime_secs,
+        }
+    }
+}
+
+impl ActorContentEntry {
+    /// Applies the fields common to every actor kind. Non-reflectable runtime fields
+    /// (`aabb`, `collider`, `scene`, `spawn_timer`) are untouched here - `initialize_actor_config`
+    /// computes those afterward, same as it does for the hardcoded defaults.
+    pub fn apply_to_actor_config(&self, config: &mut ActorConfig, scene_assets: &SceneAssets) {
+        if let Some(health) = self.health {
+            config.health = health;
+        }
+        if let Some(mass) = self.mass {
+            config.mass = mass;
+        }
+        if let Some(collision_damage) = self.collision_damage {
+            config.collision_damage = collision_damage;
+        }
+        if let Some(restitution) = self.restitution {
+            config.restitution = restitution;
+        }
+        if let Some(gravity_scale) = self.gravity_scale {
+            config.gravity_scale = gravity_scale;
+        }
+        if let Some(collider_type) = self.collider_type {
+            config.collider_type = collider_type.into();
+        }
+        if let Some(mesh_scalar) = self.mesh_scalar {
+            config.transform.scale = Vec3::splat(mesh_scalar);
+        }
+        if let Some(x) = self.spawn_position.x {
+            config.transform.translation.x = x;
+        }
+        if let Some(y) = self.spawn_position.y {
+            config.transform.translation.y = y;
+        }
+        if let Some(z) = self.spawn_position.z {
+            config.transform.translation.z = z;
+        }
+        if !self.collapse_sequence.is_empty() {
+            config.collapse_sequence = self
+                .collapse_sequence
+                .iter()
+                .map(|event| event.resolve(scene_assets))
+                .collect();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ActorContentLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl std::fmt::Display for ActorContentLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read actor content file: {err}"),
+            Self::Ron(err) => write!(f, "could not parse actor content file: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ActorContentLoaderError {}
+
+impl From<std::io::Error> for ActorContentLoaderError {
+    fn from(err: std::io::Error) -> Self { Self::Io(err) }
+}
+
+impl From<ron::error::SpannedError> for ActorContentLoaderError {
+    fn from(err: ron::error::SpannedError) -> Self { Self::Ron(err) }
+}
+
+#[derive(Default)]
+pub struct ActorContentLoader;
+
+impl AssetLoader for ActorContentLoader {
+    type Asset = ActorContent;
+    type Error = ActorContentLoaderError;
+    type Settings = ();
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] { &["ron"] }
+}
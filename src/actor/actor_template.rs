@@ -15,16 +15,15 @@ use super::Aabb;
 use super::actor_config::ActorConfig;
 use super::actor_config::ColliderType;
 use super::actor_config::GLTF_ROTATION_X;
+use super::actor_config::ShieldConfig;
 use super::constants::MAX_MISSILE_ANGULAR_VELOCITY;
 use super::constants::MAX_MISSILE_LINEAR_VELOCITY;
 use super::constants::MAX_NATEROID_ANGULAR_VELOCITY;
 use super::constants::MAX_NATEROID_LINEAR_VELOCITY;
 use super::constants::MAX_SPACESHIP_ANGULAR_VELOCITY;
 use super::constants::MAX_SPACESHIP_LINEAR_VELOCITY;
-use super::constants::MISSILE_BASE_VELOCITY;
 use super::constants::MISSILE_COLLIDER_MARGIN;
 use super::constants::MISSILE_COLLISION_DAMAGE;
-use super::constants::MISSILE_FORWARD_DISTANCE_SCALAR;
 use super::constants::MISSILE_HEALTH;
 use super::constants::MISSILE_MASS;
 use super::constants::MISSILE_RESTITUTION;
@@ -37,6 +36,8 @@ use super::constants::NATEROID_COLLISION_DAMAGE;
 use super::constants::NATEROID_DEATH_DURATION_SECS;
 use super::constants::NATEROID_DEATH_SHRINK_PCT;
 use super::constants::NATEROID_DENSITY_CULLING_THRESHOLD;
+use super::constants::NATEROID_FRAGMENT_BURST_SPEED;
+use super::constants::NATEROID_FRAGMENT_MASS_SCALE;
 use super::constants::NATEROID_HEALTH;
 use super::constants::NATEROID_INITIAL_ALPHA;
 use super::constants::NATEROID_LINEAR_DAMPING;
@@ -44,6 +45,9 @@ use super::constants::NATEROID_LINEAR_VELOCITY;
 use super::constants::NATEROID_MASS;
 use super::constants::NATEROID_RESTITUTION;
 use super::constants::NATEROID_SCALE_UP;
+use super::constants::NATEROID_SPAWN_AREA_BUDGET;
+use super::constants::NATEROID_SPAWN_INTERVAL_MAX;
+use super::constants::NATEROID_SPAWN_INTERVAL_MIN;
 use super::constants::NATEROID_SPAWN_TIMER_SECONDS;
 use super::constants::NATEROID_TARGET_ALPHA;
 use super::constants::SPACESHIP_ANGULAR_DAMPING;
@@ -55,6 +59,9 @@ use super::constants::SPACESHIP_LINEAR_DAMPING;
 use super::constants::SPACESHIP_MASS;
 use super::constants::SPACESHIP_RESTITUTION;
 use super::constants::SPACESHIP_SCALE;
+use super::constants::SPACESHIP_SHIELD_MAX;
+use super::constants::SPACESHIP_SHIELD_REGEN_DELAY_SECS;
+use super::constants::SPACESHIP_SHIELD_REGEN_PER_SEC;
 use crate::camera::RenderLayer;
 use crate::traits::TransformExt;
 
@@ -68,12 +75,13 @@ pub enum GameLayer {
     Boundary,
 }
 
+/// Shared physical template for the `Missile` actor (collider, mass, scene, ...). Flight
+/// characteristics - velocity, lead/homing targeting, spawn cadence and spread - live per
+/// weapon on `WeaponSlot` instead, since those now vary by the player's selected loadout slot.
 #[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
 #[reflect(Resource)]
 pub struct MissileConfig {
-    pub actor_config:            ActorConfig,
-    pub forward_distance_scalar: f32,
-    pub base_velocity:           f32,
+    pub actor_config: ActorConfig,
 }
 
 impl Default for MissileConfig {
@@ -83,6 +91,7 @@ impl Default for MissileConfig {
                 spawnable:                true,
                 aabb:                     Aabb::default(),
                 angular_damping:          None,
+                collapse_sequence:        Vec::new(),
                 collider:                 Collider::cuboid(1., 1., 1.),
                 collider_margin:          MISSILE_COLLIDER_MARGIN,
                 collider_type:            ColliderType::Cuboid,
@@ -91,6 +100,7 @@ impl Default for MissileConfig {
                     [GameLayer::Missile],
                     [GameLayer::Asteroid],
                 ),
+                g_force_tolerance:        None,
                 gravity_scale:            0.,
                 health:                   MISSILE_HEALTH,
                 linear_damping:           None,
@@ -103,6 +113,7 @@ impl Default for MissileConfig {
                 restitution_combine_rule: CoefficientCombine::Max,
                 rigid_body:               RigidBody::Dynamic,
                 scene:                    Handle::default(),
+                shield:                   None,
                 spawn_timer_seconds:      Some(MISSILE_SPAWN_TIMER_SECONDS),
                 transform:                Transform::from_rotation(
                     Quat::from_rotation_x(GLTF_ROTATION_X)
@@ -111,8 +122,6 @@ impl Default for MissileConfig {
                 .with_scale(Vec3::splat(MISSILE_SCALE)),
                 spawn_timer:              None,
             },
-            forward_distance_scalar: MISSILE_FORWARD_DISTANCE_SCALAR,
-            base_velocity:           MISSILE_BASE_VELOCITY,
         }
     }
 }
@@ -134,6 +143,48 @@ pub enum DeathCorner {
     Directional,
 }
 
+/// Shape of the 0→1 launch progress `s(t)` that `animate_dying_nateroids` derives the death
+/// velocity from - see [`Self::ease`] and [`Self::ease_derivative`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeathEasing {
+    Linear,
+    Smoothstep,
+    EaseInOutCubic,
+}
+
+impl DeathEasing {
+    /// Eased progress for linear progress `t`, both in `[0, 1]`.
+    pub fn ease(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::Smoothstep => t * t * (3.0 - 2.0 * t),
+            Self::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            },
+        }
+    }
+
+    /// `ds/dt`, the derivative of [`Self::ease`] with respect to `t` - callers multiply by `1 /
+    /// duration` to turn this into a velocity.
+    pub fn ease_derivative(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => 1.0,
+            Self::Smoothstep => 6.0 * t * (1.0 - t),
+            Self::EaseInOutCubic => {
+                if t < 0.5 {
+                    12.0 * t * t
+                } else {
+                    3.0 * (-2.0 * t + 2.0).powi(2)
+                }
+            },
+        }
+    }
+}
+
 #[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
 #[reflect(Resource)]
 pub struct NateroidConfig {
@@ -143,9 +194,16 @@ pub struct NateroidConfig {
     pub death_duration_secs:       f32,
     pub death_shrink_pct:          f32,
     pub death_corner:              DeathCorner,
+    pub death_easing:              DeathEasing,
     pub initial_alpha:             f32,
     pub target_alpha:              f32,
     pub density_culling_threshold: f32,
+    pub spawn_area_budget:         f32,
+    pub fragment_mass_scale:       f32,
+    pub fragment_burst_speed:      f32,
+    pub spawn_interval_base:       f32,
+    pub spawn_interval_min:        f32,
+    pub spawn_interval_max:        f32,
 }
 
 impl Default for NateroidConfig {
@@ -155,9 +213,10 @@ impl Default for NateroidConfig {
                 spawnable:                true,
                 aabb:                     Aabb::default(),
                 angular_damping:          Some(NATEROID_ANGULAR_DAMPING),
+                collapse_sequence:        Vec::new(),
                 collider:                 Collider::cuboid(1., 1., 1.),
                 collider_margin:          NATEROID_COLLIDER_MARGIN,
-                collider_type:            ColliderType::Ball,
+                collider_type:            ColliderType::ConvexHull,
                 collision_damage:         NATEROID_COLLISION_DAMAGE,
                 collision_layers:         CollisionLayers::new(
                     [GameLayer::Asteroid],
@@ -167,6 +226,7 @@ impl Default for NateroidConfig {
                         GameLayer::Spaceship,
                     ],
                 ),
+                g_force_tolerance:        None,
                 gravity_scale:            0.,
                 health:                   NATEROID_HEALTH,
                 linear_damping:           Some(NATEROID_LINEAR_DAMPING),
@@ -179,6 +239,7 @@ impl Default for NateroidConfig {
                 restitution_combine_rule: CoefficientCombine::Max,
                 rigid_body:               RigidBody::Dynamic,
                 scene:                    Handle::default(),
+                shield:                   None,
                 spawn_timer_seconds:      Some(NATEROID_SPAWN_TIMER_SECONDS),
                 transform:                Transform::from_scale(Vec3::splat(NATEROID_SCALE_UP)),
                 spawn_timer:              None,
@@ -188,9 +249,16 @@ impl Default for NateroidConfig {
             death_duration_secs:       NATEROID_DEATH_DURATION_SECS,
             death_shrink_pct:          NATEROID_DEATH_SHRINK_PCT,
             death_corner:              DeathCorner::Directional,
+            death_easing:              DeathEasing::Smoothstep,
             initial_alpha:             NATEROID_INITIAL_ALPHA,
             target_alpha:              NATEROID_TARGET_ALPHA,
             density_culling_threshold: NATEROID_DENSITY_CULLING_THRESHOLD,
+            spawn_area_budget:         NATEROID_SPAWN_AREA_BUDGET,
+            fragment_mass_scale:       NATEROID_FRAGMENT_MASS_SCALE,
+            fragment_burst_speed:      NATEROID_FRAGMENT_BURST_SPEED,
+            spawn_interval_base:       NATEROID_SPAWN_TIMER_SECONDS,
+            spawn_interval_min:        NATEROID_SPAWN_INTERVAL_MIN,
+            spawn_interval_max:        NATEROID_SPAWN_INTERVAL_MAX,
         }
     }
 }
@@ -208,16 +276,23 @@ impl DerefMut for NateroidConfig {
 #[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
 #[reflect(Resource)]
 pub struct SpaceshipConfig {
-    pub actor_config: ActorConfig,
+    pub actor_config:        ActorConfig,
+    /// How long the landing/takeoff tween takes to settle, in seconds.
+    pub docking_duration_secs: f32,
+    /// Fraction of original scale the ship shrinks to once `Landed`.
+    pub docking_shrink_pct:    f32,
 }
 
 impl Default for SpaceshipConfig {
     fn default() -> Self {
         Self {
+            docking_duration_secs: 1.5,
+            docking_shrink_pct:    0.6,
             actor_config: ActorConfig {
                 spawnable:                true,
                 aabb:                     Aabb::default(),
                 angular_damping:          Some(SPACESHIP_ANGULAR_DAMPING),
+                collapse_sequence:        Vec::new(),
                 collider:                 Collider::cuboid(1., 1., 1.),
                 collider_margin:          SPACESHIP_COLLIDER_MARGIN,
                 collider_type:            ColliderType::Cuboid,
@@ -226,6 +301,7 @@ impl Default for SpaceshipConfig {
                     [GameLayer::Spaceship],
                     [GameLayer::Asteroid, GameLayer::Boundary],
                 ),
+                g_force_tolerance:        None,
                 gravity_scale:            0.,
                 health:                   SPACESHIP_HEALTH,
                 linear_damping:           Some(SPACESHIP_LINEAR_DAMPING),
@@ -241,6 +317,11 @@ impl Default for SpaceshipConfig {
                 restitution_combine_rule: CoefficientCombine::Max,
                 rigid_body:               RigidBody::Dynamic,
                 scene:                    Handle::default(),
+                shield:                   Some(ShieldConfig {
+                    max:           SPACESHIP_SHIELD_MAX,
+                    regen_per_sec: SPACESHIP_SHIELD_REGEN_PER_SEC,
+                    regen_delay:   SPACESHIP_SHIELD_REGEN_DELAY_SECS,
+                }),
                 spawn_timer_seconds:      None,
                 transform:                Transform::from_trs(
                     SPACESHIP_INITIAL_POSITION,
@@ -0,0 +1,118 @@
+//! Scripted multi-stage destruction: a [`CollapseSequence`] plays a timed list of
+//! [`CollapseEvent`]s once an actor's [`Health`] reaches zero, spawning independent effect
+//! entities along the way before despawning the root. This sits alongside the nateroid's
+//! existing shrink/fade tween (see `despawn.rs`) rather than replacing it - an actor only gets
+//! a collapse sequence if its `ActorConfig` configures a non-empty one.
+use bevy::prelude::*;
+
+use super::Health;
+use crate::schedule::InGameSet;
+
+pub struct CollapsePlugin;
+
+impl Plugin for CollapsePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (advance_collapse_sequences, advance_collapse_effects)
+                .chain()
+                .in_set(InGameSet::DespawnEntities),
+        );
+    }
+}
+
+/// A single effect spawned partway through a collapse sequence - an explosion flash, a piece of
+/// debris, and so on.
+#[derive(Debug, Clone)]
+pub struct EffectSpec {
+    pub scene:         Handle<Scene>,
+    pub size:          f32,
+    pub lifetime_secs: f32,
+}
+
+/// One timed step of a [`CollapseSequence`]: at `time_seconds` after the sequence starts, every
+/// effect in `effects` is spawned at the collapsing actor's current transform.
+#[derive(Debug, Clone)]
+pub struct CollapseEvent {
+    pub time_seconds: f32,
+    pub effects:      Vec<EffectSpec>,
+}
+
+/// Attached to an actor at spawn if its `ActorConfig` configured a non-empty destruction
+/// timeline; it starts playing once the actor's `Health` reaches zero.
+#[derive(Component, Debug, Clone)]
+pub struct CollapseSequence {
+    events:           Vec<CollapseEvent>,
+    elapsed_secs:     f32,
+    next_event_index: usize,
+}
+
+impl CollapseSequence {
+    pub fn new(events: Vec<CollapseEvent>) -> Self {
+        Self {
+            events,
+            elapsed_secs: 0.0,
+            next_event_index: 0,
+        }
+    }
+}
+
+/// An independent effect entity spawned by a collapse sequence - it outlives the actor that
+/// spawned it, so it just carries its own countdown.
+#[derive(Component, Debug)]
+struct CollapseEffect {
+    lifetime_secs: f32,
+    elapsed_secs:  f32,
+}
+
+fn advance_collapse_sequences(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut CollapseSequence, &Health, &Transform)>,
+) {
+    for (entity, mut sequence, health, transform) in &mut query {
+        if health.0 > 0.0 {
+            continue;
+        }
+
+        sequence.elapsed_secs += time.delta_secs();
+
+        while let Some(event) = sequence.events.get(sequence.next_event_index) {
+            if event.time_seconds > sequence.elapsed_secs {
+                break;
+            }
+
+            for effect in &event.effects {
+                commands.spawn((
+                    CollapseEffect {
+                        lifetime_secs: effect.lifetime_secs,
+                        elapsed_secs:  0.0,
+                    },
+                    SceneRoot(effect.scene.clone()),
+                    Transform::from_translation(transform.translation)
+                        .with_scale(Vec3::splat(effect.size)),
+                    Name::new("CollapseEffect"),
+                ));
+            }
+
+            sequence.next_event_index += 1;
+        }
+
+        if sequence.next_event_index >= sequence.events.len() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn advance_collapse_effects(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut effects: Query<(Entity, &mut CollapseEffect)>,
+) {
+    for (entity, mut effect) in &mut effects {
+        effect.elapsed_secs += time.delta_secs();
+        if effect.elapsed_secs >= effect.lifetime_secs {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
@@ -1,9 +1,13 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
 
+use super::Aabb;
 use super::Health;
 use super::Teleporter;
 use super::actor_config::CollisionDamage;
+use super::actor_config::Shield;
+use super::constants::TUNNELING_COOLDOWN_FRAMES;
+use super::constants::TUNNELING_NUDGE_DISTANCE;
 use super::spaceship::Spaceship;
 use crate::schedule::InGameSet;
 
@@ -13,14 +17,152 @@ impl Plugin for CollisionDetectionPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             FixedUpdate,
-            handle_collision_events.in_set(InGameSet::CollisionDetection),
-        );
+            (
+                sweep_for_tunneling,
+                tick_tunneling_cooldown,
+                handle_collision_events,
+                regenerate_shields,
+            )
+                .chain()
+                .in_set(InGameSet::CollisionDetection),
+        )
+        .add_observer(handle_swept_collision);
+    }
+}
+
+/// World-space translation as of the end of the previous `FixedUpdate` step, recorded for
+/// fast-moving entities (missiles) so [`sweep_for_tunneling`] can shape-cast the entity's own
+/// `Collider` across the displacement it just made, catching the overlaps a single discrete
+/// step can otherwise skip straight past.
+#[derive(Component, Reflect, Copy, Clone, Debug, Default)]
+#[reflect(Component, Default)]
+pub struct PreviousPosition(pub Vec3);
+
+/// Marks an entity as having just had a tunneling sweep resolved against it; counts down over
+/// [`TUNNELING_COOLDOWN_FRAMES`] `FixedUpdate` ticks before [`sweep_for_tunneling`] considers it
+/// again, giving Avian's own broadphase/narrowphase a chance to settle on the snapped-to-impact
+/// position instead of the same pair being resolved twice.
+#[derive(Component, Reflect, Copy, Clone, Debug)]
+#[reflect(Component)]
+pub struct Tunneling {
+    pub frames: u32,
+    pub dir:    Vec3,
+}
+
+/// Triggered by [`sweep_for_tunneling`] when a continuous-collision shape-cast catches a hit the
+/// discrete narrowphase would have missed this step. Handled by [`handle_swept_collision`] through
+/// the same [`resolve_collision_pair`] path `handle_collision_events` uses for a real
+/// `CollisionStart`, so a swept hit has identical gameplay consequences to a broadphase one.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct SweptCollision {
+    pub collider1: Entity,
+    pub collider2: Entity,
+}
+
+/// Shape-casts each fast-moving entity's `Collider` from [`PreviousPosition`] to its current
+/// `Transform` translation, only bothering when `velocity.length() * delta > collider_radius` -
+/// i.e. this step's displacement could plausibly have skipped clean over something the size of
+/// the entity's own bounding box. A hit closer than the full displacement means the discrete step
+/// tunneled through it; snap the entity back to the impact point, mark it [`Tunneling`] so the
+/// pair isn't resolved twice, and trigger [`SweptCollision`] so it takes damage exactly like a
+/// normal collision would have.
+fn sweep_for_tunneling(
+    mut commands: Commands,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+    mut fast_movers: Query<
+        (Entity, &mut Transform, &mut PreviousPosition, &LinearVelocity, &Collider, &Aabb),
+        Without<Tunneling>,
+    >,
+) {
+    let delta = time.delta_secs();
+    if delta <= 0.0 {
+        return;
+    }
+
+    for (entity, mut transform, mut previous, velocity, collider, aabb) in &mut fast_movers {
+        let displacement = transform.translation - previous.0;
+        let collider_radius = aabb.max_dimension() * 0.5;
+
+        if velocity.length() * delta > collider_radius
+            && let Ok(direction) = Dir3::new(displacement)
+        {
+            let distance = displacement.length();
+
+            if let Some(hit) = spatial_query.cast_shape(
+                collider,
+                previous.0,
+                transform.rotation,
+                direction,
+                &ShapeCastConfig::from_max_distance(distance),
+                &SpatialQueryFilter::default().with_excluded_entities([entity]),
+            ) {
+                let impact_point = previous.0 + displacement * (hit.distance / distance).min(1.0);
+                transform.translation = impact_point;
+
+                commands.entity(entity).insert(Tunneling {
+                    frames: TUNNELING_COOLDOWN_FRAMES,
+                    dir:    -*direction,
+                });
+                commands.trigger(SweptCollision {
+                    collider1: entity,
+                    collider2: hit.entity,
+                });
+            }
+        }
+
+        previous.0 = transform.translation;
     }
 }
 
+/// Nudges each [`Tunneling`] entity along its penetration-correction direction and counts down
+/// [`Tunneling::frames`], removing the marker once it reaches zero so [`sweep_for_tunneling`]
+/// resumes considering the entity. The nudge keeps the entity clear of the surface it was just
+/// snapped to while the solver settles, rather than leaving it sitting exactly on the contact
+/// point for the whole cooldown window.
+fn tick_tunneling_cooldown(
+    mut commands: Commands,
+    mut tunneling: Query<(Entity, &mut Transform, &mut Tunneling)>,
+) {
+    for (entity, mut transform, mut tunneling) in &mut tunneling {
+        transform.translation += tunneling.dir * TUNNELING_NUDGE_DISTANCE;
+
+        tunneling.frames = tunneling.frames.saturating_sub(1);
+        if tunneling.frames == 0 {
+            commands.entity(entity).remove::<Tunneling>();
+        }
+    }
+}
+
+/// Observer counterpart to `handle_collision_events` for a [`SweptCollision`] triggered by
+/// [`sweep_for_tunneling`] - applies damage through the exact same [`resolve_collision_pair`]
+/// logic a real `CollisionStart` gets.
+fn handle_swept_collision(
+    swept: On<SweptCollision>,
+    mut health_query: Query<&mut Health>,
+    mut shield_query: Query<&mut Shield>,
+    collision_damage_query: Query<&CollisionDamage>,
+    spaceship_query: Query<(Entity, &Teleporter), With<Spaceship>>,
+) {
+    let spaceship_just_teleported = spaceship_query
+        .single()
+        .map(|(entity, teleporter)| (entity, teleporter.just_teleported))
+        .ok();
+
+    resolve_collision_pair(
+        &mut health_query,
+        &mut shield_query,
+        &collision_damage_query,
+        spaceship_just_teleported,
+        swept.collider1,
+        swept.collider2,
+    );
+}
+
 fn handle_collision_events(
     mut collision_events: MessageReader<CollisionStart>,
     mut health_query: Query<&mut Health>,
+    mut shield_query: Query<&mut Shield>,
     collision_damage_query: Query<&CollisionDamage>,
     spaceship_query: Query<(Entity, &Teleporter), With<Spaceship>>,
 ) {
@@ -31,66 +173,92 @@ fn handle_collision_events(
         .ok();
 
     for event in collision_events.read() {
-        // Check if either entity is the spaceship that just teleported
-        let entity1_is_invincible_spaceship =
-            spaceship_just_teleported.is_some_and(|(ship_entity, just_teleported)| {
-                just_teleported && event.collider1 == ship_entity
-            });
-        let entity2_is_invincible_spaceship =
-            spaceship_just_teleported.is_some_and(|(ship_entity, just_teleported)| {
-                just_teleported && event.collider2 == ship_entity
-            });
-
-        if entity1_is_invincible_spaceship {
-            // Spaceship just teleported - instantly kill entity2
-            if let Ok(mut health) = health_query.get_mut(event.collider2) {
-                info!(
-                    "💀 Spaceship invincibility: killing nateroid that collided with just-teleported spaceship"
-                );
-                health.0 = -1.0; // Instant death
-            }
-            // Spaceship still takes normal damage
-            apply_collision_damage(
-                &mut health_query,
-                &collision_damage_query,
-                event.collider2,
-                event.collider1,
-            );
-        } else if entity2_is_invincible_spaceship {
-            // Spaceship just teleported - instantly kill entity1
-            if let Ok(mut health) = health_query.get_mut(event.collider1) {
-                info!(
-                    "💀 Spaceship invincibility: killing nateroid that collided with just-teleported spaceship"
-                );
-                health.0 = -1.0; // Instant death
-            }
-            // Spaceship still takes normal damage
-            apply_collision_damage(
-                &mut health_query,
-                &collision_damage_query,
-                event.collider1,
-                event.collider2,
-            );
-        } else {
-            // Normal collision handling
-            apply_collision_damage(
-                &mut health_query,
-                &collision_damage_query,
-                event.collider1,
-                event.collider2,
+        resolve_collision_pair(
+            &mut health_query,
+            &mut shield_query,
+            &collision_damage_query,
+            spaceship_just_teleported,
+            event.collider1,
+            event.collider2,
+        );
+    }
+}
+
+/// Shared collision-resolution logic for both a real `CollisionStart` (`handle_collision_events`)
+/// and a synthetic [`SweptCollision`] (`handle_swept_collision`): honors the just-teleported
+/// spaceship's invincibility (instantly killing whatever it hit, while still taking normal damage
+/// itself), and otherwise applies damage symmetrically to both colliders.
+fn resolve_collision_pair(
+    health_query: &mut Query<&mut Health>,
+    shield_query: &mut Query<&mut Shield>,
+    collision_damage_query: &Query<&CollisionDamage>,
+    spaceship_just_teleported: Option<(Entity, bool)>,
+    collider1: Entity,
+    collider2: Entity,
+) {
+    let entity1_is_invincible_spaceship =
+        spaceship_just_teleported.is_some_and(|(ship_entity, just_teleported)| {
+            just_teleported && collider1 == ship_entity
+        });
+    let entity2_is_invincible_spaceship =
+        spaceship_just_teleported.is_some_and(|(ship_entity, just_teleported)| {
+            just_teleported && collider2 == ship_entity
+        });
+
+    if entity1_is_invincible_spaceship {
+        // Spaceship just teleported - instantly kill entity2
+        if let Ok(mut health) = health_query.get_mut(collider2) {
+            info!(
+                "💀 Spaceship invincibility: killing nateroid that collided with just-teleported spaceship"
             );
-            apply_collision_damage(
-                &mut health_query,
-                &collision_damage_query,
-                event.collider2,
-                event.collider1,
+            health.0 = -1.0; // Instant death
+        }
+        // Spaceship still takes normal damage
+        apply_collision_damage(
+            health_query,
+            shield_query,
+            collision_damage_query,
+            collider2,
+            collider1,
+        );
+    } else if entity2_is_invincible_spaceship {
+        // Spaceship just teleported - instantly kill entity1
+        if let Ok(mut health) = health_query.get_mut(collider1) {
+            info!(
+                "💀 Spaceship invincibility: killing nateroid that collided with just-teleported spaceship"
             );
+            health.0 = -1.0; // Instant death
         }
+        // Spaceship still takes normal damage
+        apply_collision_damage(
+            health_query,
+            shield_query,
+            collision_damage_query,
+            collider1,
+            collider2,
+        );
+    } else {
+        // Normal collision handling
+        apply_collision_damage(
+            health_query,
+            shield_query,
+            collision_damage_query,
+            collider1,
+            collider2,
+        );
+        apply_collision_damage(
+            health_query,
+            shield_query,
+            collision_damage_query,
+            collider2,
+            collider1,
+        );
     }
 }
 
 fn apply_collision_damage(
     health_query: &mut Query<&mut Health>,
+    shield_query: &mut Query<&mut Shield>,
     collision_damage_query: &Query<&CollisionDamage>,
     applying_entity: Entity,
     receiving_entity: Entity,
@@ -98,6 +266,25 @@ fn apply_collision_damage(
     if let Ok(mut health) = health_query.get_mut(receiving_entity)
         && let Ok(collision_damage) = collision_damage_query.get(applying_entity)
     {
-        health.0 -= collision_damage.0;
+        let mut remaining_damage = collision_damage.0;
+
+        if let Ok(mut shield) = shield_query.get_mut(receiving_entity) {
+            shield.seconds_since_damage = 0.0;
+            let absorbed = remaining_damage.min(shield.current);
+            shield.current -= absorbed;
+            remaining_damage -= absorbed;
+        }
+
+        health.0 -= remaining_damage;
+    }
+}
+
+fn regenerate_shields(time: Res<Time>, mut shields: Query<&mut Shield>) {
+    for mut shield in &mut shields {
+        shield.seconds_since_damage += time.delta_secs();
+        if shield.seconds_since_damage >= shield.regen_delay {
+            let regenerated = shield.regen_per_sec * time.delta_secs();
+            shield.current = (shield.current + regenerated).min(shield.max);
+        }
     }
 }
@@ -10,6 +10,14 @@ pub const SPACESHIP_LINEAR_DAMPING: f32 = 0.05;
 pub const SPACESHIP_MASS: f32 = 10.0;
 pub const SPACESHIP_RESTITUTION: f32 = 0.1;
 pub const SPACESHIP_SCALE: f32 = 2.0;
+pub const SPACESHIP_SHIELD_MAX: f32 = 250.0;
+pub const SPACESHIP_SHIELD_REGEN_PER_SEC: f32 = 25.0;
+pub const SPACESHIP_SHIELD_REGEN_DELAY_SECS: f32 = 3.0;
+pub const SPACESHIP_OUTFIT_SPACE: f32 = 10.0;
+/// Half-extent of the clearance box a [`super::SpaceshipSpawnBuffer`] child occupies around the
+/// spaceship - comfortably larger than the ship's own collider so a freshly spawned nateroid
+/// never appears right on top of the player.
+pub const SPACESHIP_SPAWN_BUFFER_HALF_EXTENT: f32 = 40.0;
 
 // Nateroid constants
 pub const NATEROID_ANGULAR_DAMPING: f32 = 0.001;
@@ -19,6 +27,8 @@ pub const NATEROID_COLLISION_DAMAGE: f32 = 10.0;
 pub const NATEROID_DEATH_DURATION_SECS: f32 = 3.0;
 pub const NATEROID_DEATH_SHRINK_PCT: f32 = 0.3;
 pub const NATEROID_DENSITY_CULLING_THRESHOLD: f32 = 0.01;
+pub const NATEROID_FRAGMENT_BURST_SPEED: f32 = 15.0;
+pub const NATEROID_FRAGMENT_MASS_SCALE: f32 = 0.0002;
 pub const NATEROID_HEALTH: f32 = 200.0;
 pub const NATEROID_INITIAL_ALPHA: f32 = 0.35;
 pub const NATEROID_LINEAR_DAMPING: f32 = 0.001;
@@ -26,8 +36,26 @@ pub const NATEROID_LINEAR_VELOCITY: f32 = 35.0;
 pub const NATEROID_MASS: f32 = 1.0;
 pub const NATEROID_RESTITUTION: f32 = 0.3;
 pub const NATEROID_SCALE_UP: f32 = 100.0; // we need bigger nateroids than just donut sized ones
+pub const NATEROID_SPAWN_AREA_BUDGET: f32 = 12.0;
+pub const NATEROID_SPAWN_INTERVAL_MIN: f32 = 0.3;
+pub const NATEROID_SPAWN_INTERVAL_MAX: f32 = 5.0;
 pub const NATEROID_SPAWN_TIMER_SECONDS: f32 = 2.0;
 pub const NATEROID_TARGET_ALPHA: f32 = 0.05;
+pub const NATEROID_SPAWN_RELOCATION_ATTEMPTS: u32 = 4;
+
+// Hunter AI constants
+pub const HUNTER_TARGETS_K: usize = 2;
+pub const HUNTER_HIDDEN_SIZE: usize = 8;
+pub const HUNTER_POPULATION_SIZE: usize = 40;
+pub const HUNTER_GENERATIONS: usize = 30;
+pub const HUNTER_EPISODE_SECONDS: f32 = 20.0;
+pub const HUNTER_TIMESTEP_SECONDS: f32 = 1.0 / 30.0;
+pub const HUNTER_TOURNAMENT_SIZE: usize = 4;
+pub const HUNTER_MUTATION_RATE: f32 = 0.1;
+pub const HUNTER_ARENA_RADIUS: f32 = 200.0;
+/// Fraction of periodic nateroid spawns that come in as a [`super::HunterNateroid`] instead of a
+/// plain drifting one.
+pub const HUNTER_SPAWN_CHANCE: f32 = 0.15;
 
 // Missile constants
 pub const MISSILE_BASE_VELOCITY: f32 = 85.0;
@@ -39,6 +67,27 @@ pub const MISSILE_MASS: f32 = 0.1;
 pub const MISSILE_RESTITUTION: f32 = 0.1;
 pub const MISSILE_SCALE: f32 = 2.5;
 pub const MISSILE_SPAWN_TIMER_SECONDS: f32 = 1.0 / 20.0;
+pub const MISSILE_ACQUISITION_RANGE: f32 = 150.0;
+pub const MISSILE_MAX_TURN_RATE: f32 = std::f32::consts::PI;
+
+// Weapon energy constants
+pub const WEAPON_ENERGY_MAX: f32 = 100.0;
+pub const WEAPON_ENERGY_COST_PER_SHOT: f32 = 10.0;
+pub const WEAPON_ENERGY_REGEN_PER_SEC: f32 = 15.0;
+
+// Firing recoil constants
+pub const RECOIL_BUDGET_MAX: f32 = 600.0;
+pub const RECOIL_BUDGET_REGEN_PER_SEC: f32 = 400.0;
+
+/// `FixedUpdate` ticks a resolved sweep's `Tunneling` marker counts down before the entity is
+/// eligible to be swept again, giving Avian's own broadphase/narrowphase a chance to catch up on
+/// the snapped-to-impact position before `sweep_for_tunneling` runs on it once more.
+pub const TUNNELING_COOLDOWN_FRAMES: u32 = 3;
+
+/// World units a `Tunneling` entity is nudged along its penetration-correction direction on each
+/// cooldown tick, keeping it clear of the surface it was just snapped to instead of sitting
+/// exactly on the contact point while the solver catches up.
+pub const TUNNELING_NUDGE_DISTANCE: f32 = 0.02;
 
 // Actor physics velocity limits
 pub const MAX_MISSILE_ANGULAR_VELOCITY: f32 = 20.0;
@@ -47,3 +96,71 @@ pub const MAX_NATEROID_ANGULAR_VELOCITY: f32 = 20.0;
 pub const MAX_NATEROID_LINEAR_VELOCITY: f32 = 80.0;
 pub const MAX_SPACESHIP_ANGULAR_VELOCITY: f32 = 20.0;
 pub const MAX_SPACESHIP_LINEAR_VELOCITY: f32 = 80.0;
+
+/// Fixed capacity of `flame_gizmo`'s `TempEffects` pool - comfortably covers the one continuous
+/// thruster-flame slot plus a chain of simultaneous death rings without ever needing to grow.
+pub const GIZMO_EFFECT_POOL_CAPACITY: usize = 32;
+
+// Effect constants
+/// Minimum missile `CollisionDamage` that upgrades an impact burst from `"small_explosion"` to
+/// `"large_explosion"` in `effects.ron` - see `effects.rs::emit_impact_particles`.
+pub const LARGE_EXPLOSION_DAMAGE_THRESHOLD: f32 = 40.0;
+
+// Debris death-style constants
+/// Cubic units of `Aabb::max_dimension` per debris shard - the classic `SHARD_VOLUME`
+/// fragmentation-particle-count heuristic.
+pub const DEBRIS_SHARD_VOLUME: f32 = 10.0;
+pub const DEBRIS_SHARD_MIN_COUNT: usize = 4;
+pub const DEBRIS_SHARD_MAX_COUNT: usize = 24;
+pub const DEBRIS_SHARD_SPEED_MIN: f32 = 8.0;
+pub const DEBRIS_SHARD_SPEED_MAX: f32 = 20.0;
+/// Fraction of a shard's outward velocity direction randomized away from purely radial.
+pub const DEBRIS_SHARD_JITTER: f32 = 0.4;
+pub const DEBRIS_SHARD_LENGTH: f32 = 1.5;
+pub const DEBRIS_SHARD_TUMBLE_RATE_MAX: f32 = 6.0;
+/// Strength of the inward pull toward the death center that curves each shard's outward flight
+/// into an arc rather than a straight line.
+pub const DEBRIS_GRAVITY_STRENGTH: f32 = 6.0;
+
+// Muzzle flash / tracer constants
+/// World units forward of the spaceship's own origin the muzzle effects spawn at.
+pub const MUZZLE_OFFSET: f32 = 2.5;
+pub const MUZZLE_FLASH_DURATION_SECS: f32 = 0.08;
+pub const MUZZLE_FLASH_LINE_COUNT: usize = 6;
+pub const MUZZLE_FLASH_LINE_LENGTH: f32 = 1.2;
+pub const TRACER_DURATION_SECS: f32 = 0.15;
+pub const TRACER_LENGTH: f32 = 10.0;
+pub const TRACER_SEGMENT_COUNT: usize = 6;
+pub const TRACER_COLOR_ZONE_SIZE: f32 = 0.3;
+
+// Gizmo effect dynamic light constants
+/// Peak intensity of a death explosion's child `PointLight`, scaled down by its own ring's alpha
+/// envelope every frame so the light flashes and decays in lockstep with the gizmo ring.
+pub const DEATH_LIGHT_INTENSITY: f32 = 60_000.0;
+pub const DEATH_LIGHT_RANGE: f32 = 80.0;
+/// Peak intensity of the thruster's child `PointLight` - dimmer than a death flash since it's lit
+/// continuously rather than as a one-shot burst.
+pub const THRUSTER_LIGHT_INTENSITY: f32 = 8_000.0;
+pub const THRUSTER_LIGHT_RANGE: f32 = 40.0;
+
+// Thrust particle constants
+/// Fixed capacity of `thrust_particles`'s pool - comfortably covers the densest plume
+/// (`THRUST_PARTICLE_SPAWN_RATE_MAX` sustained for the longest `THRUST_PARTICLE_LIFETIME_MAX`)
+/// without ever needing to grow.
+pub const THRUST_PARTICLE_POOL_CAPACITY: usize = 256;
+pub const THRUST_PARTICLE_SPAWN_RATE_MIN: f32 = 20.0;
+pub const THRUST_PARTICLE_SPAWN_RATE_MAX: f32 = 80.0;
+pub const THRUST_PARTICLE_SPEED_MIN: f32 = 6.0;
+pub const THRUST_PARTICLE_SPEED_MAX: f32 = 18.0;
+/// Fraction of a particle's spawn velocity randomized away from the pure spread direction.
+pub const THRUST_PARTICLE_JITTER: f32 = 0.35;
+pub const THRUST_PARTICLE_CONE_HALF_ANGLE: f32 = 0.3;
+pub const THRUST_PARTICLE_LIFETIME_MIN: f32 = 0.25;
+pub const THRUST_PARTICLE_LIFETIME_MAX: f32 = 0.6;
+pub const THRUST_PARTICLE_START_SIZE: f32 = 0.35;
+pub const THRUST_PARTICLE_END_SIZE: f32 = 0.05;
+/// World units behind the spaceship's own origin the plume is emitted from.
+pub const THRUST_PARTICLE_OFFSET: f32 = 2.0;
+/// `spaceship.velocity.length()` that maps to full spawn-rate/speed scaling - faster than this
+/// doesn't make the plume denser, it's already maxed out.
+pub const THRUST_PARTICLE_VELOCITY_FOR_MAX_DENSITY: f32 = 60.0;
@@ -0,0 +1,361 @@
+//! Lightweight impact/death particle bursts, modeled on Galactica's
+//! `ParticleBuilder` and its "sticky particles" work. Particles are plain
+//! entities with no collider - they never touch the physics solver, they
+//! just carry a lifetime and (optionally) ride along with the body they
+//! struck.
+use std::collections::HashMap;
+use std::ops::Range;
+
+use avian3d::prelude::*;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+use bevy_inspector_egui::inspector_options::std_options::NumberDisplay;
+use bevy_inspector_egui::prelude::*;
+use bevy_inspector_egui::quick::ResourceInspectorPlugin;
+
+use super::Deaderoid;
+use super::actor_config::CollisionDamage;
+use super::actor_template::GameLayer;
+use super::constants::LARGE_EXPLOSION_DAMAGE_THRESHOLD;
+use super::effects_content::EffectLifetimeContent;
+use super::effects_content::EffectsContent;
+use super::effects_content::EffectsContentLoader;
+use super::missile::Missile;
+use crate::camera::RenderLayer;
+use crate::game_input::GameAction;
+use crate::game_input::toggle_active;
+use crate::rollback::RollbackRng;
+use crate::schedule::InGameSet;
+use crate::state::PlayingGame;
+
+const EFFECTS_CONTENT_PATH: &str = "content/effects.ron";
+
+/// Name looked up in `effects.ron` for a missile strike that didn't one-shot its target.
+const SMALL_EXPLOSION: &str = "small_explosion";
+/// Name looked up in `effects.ron` for a missile strike dealing at least
+/// [`LARGE_EXPLOSION_DAMAGE_THRESHOLD`] damage.
+const LARGE_EXPLOSION: &str = "large_explosion";
+/// Name looked up in `effects.ron` for a `Nateroid`'s own death - uses `lifetime: Inherit` so the
+/// debris disappears together with the `Deaderoid` it's attached to, rather than on its own timer.
+const NATEROID_DEATH_EXPLOSION: &str = "nateroid_death";
+
+pub struct EffectPlugin;
+
+impl Plugin for EffectPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<EffectsContent>()
+            .init_asset_loader::<EffectsContentLoader>()
+            .init_resource::<EffectConfig>()
+            .init_resource::<EffectRegistry>()
+            .add_systems(PreStartup, load_effects_content)
+            .add_systems(Update, sync_effect_registry)
+            .add_plugins(
+                ResourceInspectorPlugin::<EffectConfig>::default()
+                    .run_if(toggle_active(false, GameAction::EffectConfigInspector)),
+            )
+            .add_systems(
+                Update,
+                (
+                    emit_impact_particles,
+                    emit_death_particles,
+                    advance_particles,
+                )
+                    .chain()
+                    .in_set(InGameSet::EntityUpdates)
+                    .run_if(in_state(PlayingGame)),
+            );
+    }
+}
+
+/// Handle to the loaded `assets/content/effects.ron` asset. Kept around (rather than dropped
+/// after the initial load) so the asset stays loaded and its `AssetEvent::Modified` events keep
+/// firing when the file is edited on disk - same reasoning as `actor_config.rs`'s
+/// `ActorContentHandle`.
+#[derive(Resource)]
+struct EffectsContentHandle(Handle<EffectsContent>);
+
+fn load_effects_content(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(EffectsContentHandle(asset_server.load(EFFECTS_CONTENT_PATH)));
+}
+
+/// Named [`EffectPreset`]s [`spawn_effect`] looks up by name, built from whatever `effects.ron`
+/// currently contains. A name the file doesn't list falls back to [`EffectConfig`]'s live-tunable
+/// defaults, so the registry starts out empty and still behaves sensibly before the asset loads.
+#[derive(Resource, Default, Debug, Clone)]
+struct EffectRegistry(HashMap<String, EffectPreset>);
+
+impl EffectRegistry {
+    fn preset(&self, name: &str, config: &EffectConfig) -> EffectPreset {
+        self.0.get(name).copied().unwrap_or_else(|| EffectPreset {
+            spawn_count:    config.spawn_count,
+            lifetime:       ParticleLifetime::Fixed(config.lifetime_secs),
+            initial_speed:  config.initial_speed,
+            sticky:         config.sticky,
+            particle_scale: config.particle_scale,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct EffectPreset {
+    spawn_count:    usize,
+    lifetime:       ParticleLifetime,
+    initial_speed:  f32,
+    sticky:         bool,
+    particle_scale: f32,
+}
+
+/// Rebuilds [`EffectRegistry`] whenever `effects.ron` (re)loads, so editing it on disk - with
+/// Bevy's `file_watcher` feature enabled - updates named presets without a recompile.
+fn sync_effect_registry(
+    content_handle: Res<EffectsContentHandle>,
+    content_assets: Res<Assets<EffectsContent>>,
+    mut registry: ResMut<EffectRegistry>,
+) {
+    if !content_assets.is_changed() {
+        return;
+    }
+
+    let Some(content) = content_assets.get(&content_handle.0) else {
+        return;
+    };
+
+    registry.0 = content
+        .effect
+        .iter()
+        .map(|(name, preset)| {
+            let lifetime = match preset.lifetime {
+                EffectLifetimeContent::Fixed(secs) => ParticleLifetime::Fixed(secs),
+                EffectLifetimeContent::Inherit => ParticleLifetime::Inherit,
+            };
+            (
+                name.clone(),
+                EffectPreset {
+                    spawn_count: preset.spawn_count,
+                    lifetime,
+                    initial_speed: preset.initial_speed,
+                    sticky: preset.sticky,
+                    particle_scale: preset.particle_scale,
+                },
+            )
+        })
+        .collect();
+}
+
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct EffectConfig {
+    #[inspector(min = 1, max = 64)]
+    pub spawn_count:        usize,
+    #[inspector(min = 0.05, max = 3.0, display = NumberDisplay::Slider)]
+    pub lifetime_secs:       f32,
+    #[inspector(min = 0.0, max = 100.0, display = NumberDisplay::Slider)]
+    pub initial_speed:       f32,
+    pub sticky:              bool,
+    #[inspector(min = 0.01, max = 2.0, display = NumberDisplay::Slider)]
+    pub particle_scale:      f32,
+}
+
+impl Default for EffectConfig {
+    fn default() -> Self {
+        Self {
+            spawn_count:    12,
+            lifetime_secs:  0.5,
+            initial_speed:  20.0,
+            sticky:         true,
+            particle_scale: 0.15,
+        }
+    }
+}
+
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct Particle {
+    lifetime:     ParticleLifetime,
+    elapsed_secs: f32,
+    /// Entity this particle is stuck to, if spawned as `sticky`.
+    attached_to:  Option<Entity>,
+}
+
+/// How long a [`Particle`] sticks around - either its own countdown, or however long the entity
+/// it's [`attached_to`](Particle::attached_to) keeps existing. Mirrors
+/// [`EffectLifetimeContent`](super::effects_content::EffectLifetimeContent).
+#[derive(Reflect, Debug, Clone, Copy)]
+enum ParticleLifetime {
+    Fixed(f32),
+    Inherit,
+}
+
+/// Where a freshly spawned particle's initial velocity comes from, before the burst's own
+/// randomized spread is added on top.
+enum InheritVelocity {
+    /// Copy the struck actor's current `LinearVelocity`.
+    Target(Entity),
+    /// Copy the missile's current `LinearVelocity`.
+    Projectile(Entity),
+    /// Start from rest - the burst's randomized spread is the only motion.
+    None,
+}
+
+fn resolve_inherited_velocity(
+    inherit: InheritVelocity,
+    velocities: &Query<&LinearVelocity>,
+) -> Vec3 {
+    match inherit {
+        InheritVelocity::Target(entity) | InheritVelocity::Projectile(entity) => velocities
+            .get(entity)
+            .map_or(Vec3::ZERO, |velocity| velocity.0),
+        InheritVelocity::None => Vec3::ZERO,
+    }
+}
+
+/// Looks up `name` in `registry` (falling back to `config`'s live-tunable defaults if the content
+/// file doesn't list it) and spawns its burst of [`Particle`]s at `origin`, each inheriting
+/// `base_velocity` before the burst's own randomized spread is added on top. `attached_to` is the
+/// entity a `sticky` preset rides along with, and - for a `lifetime: Inherit` preset - the entity
+/// whose continued existence the particle's own lifetime is tied to.
+fn spawn_effect(
+    commands: &mut Commands,
+    registry: &EffectRegistry,
+    config: &EffectConfig,
+    rng: &mut RollbackRng,
+    name: &str,
+    origin: Vec3,
+    base_velocity: Vec3,
+    attached_to: Option<Entity>,
+) {
+    let preset = registry.preset(name, config);
+    let sticky_target = preset.sticky.then_some(attached_to).flatten();
+
+    for _ in 0..preset.spawn_count {
+        let direction = Vec3::new(
+            rng.random_range_f32(-1.0, 1.0),
+            rng.random_range_f32(-1.0, 1.0),
+            0.0,
+        )
+        .normalize_or_zero();
+        let speed = rng.random_range_f32(preset.initial_speed * 0.3, preset.initial_speed);
+
+        commands.spawn((
+            Particle {
+                lifetime:     preset.lifetime,
+                elapsed_secs: 0.0,
+                attached_to:  sticky_target,
+            },
+            Transform::from_translation(origin).with_scale(Vec3::splat(preset.particle_scale)),
+            LinearVelocity(base_velocity + direction * speed),
+            RenderLayers::from_layers(RenderLayer::Game.layers()),
+            Name::new("Particle"),
+        ));
+    }
+}
+
+fn emit_impact_particles(
+    mut commands: Commands,
+    registry: Res<EffectRegistry>,
+    config: Res<EffectConfig>,
+    mut rng: ResMut<RollbackRng>,
+    mut collision_events: MessageReader<CollisionStart>,
+    missiles: Query<(Entity, &Transform, &CollisionDamage), With<Missile>>,
+    asteroids: Query<Entity, With<super::Nateroid>>,
+    velocities: Query<&LinearVelocity>,
+) {
+    for event in collision_events.read() {
+        // only react to missile-vs-asteroid hits; other collision pairs
+        // (e.g. asteroid-vs-asteroid) don't get an impact burst
+        let ((missile_entity, missile_transform, collision_damage), struck_entity) =
+            if let (Ok(missile), Ok(entity)) = (
+                missiles.get(event.collider1),
+                asteroids.get(event.collider2),
+            ) {
+                (missile, entity)
+            } else if let (Ok(missile), Ok(entity)) = (
+                missiles.get(event.collider2),
+                asteroids.get(event.collider1),
+            ) {
+                (missile, entity)
+            } else {
+                continue;
+            };
+
+        // burst inherits the missile's momentum - it carries the impact forward
+        // rather than starting dead in space
+        let base_velocity =
+            resolve_inherited_velocity(InheritVelocity::Projectile(missile_entity), &velocities);
+
+        // a heavier hit gets the bigger, longer-lived burst
+        let effect_name = if collision_damage.0 >= LARGE_EXPLOSION_DAMAGE_THRESHOLD {
+            LARGE_EXPLOSION
+        } else {
+            SMALL_EXPLOSION
+        };
+
+        spawn_effect(
+            &mut commands,
+            &registry,
+            &config,
+            &mut rng,
+            effect_name,
+            missile_transform.translation,
+            base_velocity,
+            Some(struck_entity),
+        );
+    }
+}
+
+/// Emits a burst the moment a `Nateroid` enters its death tween.
+fn emit_death_particles(
+    mut commands: Commands,
+    registry: Res<EffectRegistry>,
+    config: Res<EffectConfig>,
+    mut rng: ResMut<RollbackRng>,
+    new_deaderoids: Query<(Entity, &Transform), Added<Deaderoid>>,
+    velocities: Query<&LinearVelocity>,
+) {
+    for (entity, transform) in &new_deaderoids {
+        // burst inherits the dying asteroid's own tumbling velocity at the moment of death
+        let base_velocity =
+            resolve_inherited_velocity(InheritVelocity::Target(entity), &velocities);
+
+        spawn_effect(
+            &mut commands,
+            &registry,
+            &config,
+            &mut rng,
+            NATEROID_DEATH_EXPLOSION,
+            transform.translation,
+            base_velocity,
+            Some(entity),
+        );
+    }
+}
+
+fn advance_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut particles: Query<(Entity, &mut Particle, &mut Transform, &mut LinearVelocity)>,
+    attachments: Query<(&Transform, &LinearVelocity), Without<Particle>>,
+) {
+    for (entity, mut particle, mut transform, mut linear_velocity) in &mut particles {
+        particle.elapsed_secs += time.delta_secs();
+
+        let attachment = particle.attached_to.map(|attached_to| attachments.get(attached_to));
+        let expired = match particle.lifetime {
+            ParticleLifetime::Fixed(lifetime_secs) => particle.elapsed_secs >= lifetime_secs,
+            // no timer of its own - it only goes away once the entity it's tracking does
+            ParticleLifetime::Inherit => !matches!(attachment, Some(Ok(_))),
+        };
+        if expired {
+            commands.entity(entity).try_despawn();
+            continue;
+        }
+
+        if let Some(Ok((_, attached_velocity))) = attachment {
+            // sticky particles ride along with the body they struck rather
+            // than flying free - each frame they simply inherit its velocity
+            linear_velocity.0 = attached_velocity.0;
+        }
+
+        transform.translation += linear_velocity.0 * time.delta_secs();
+    }
+}
@@ -0,0 +1,87 @@
+//! Named particle-burst presets that take the place of
+//! [`EffectConfig`](super::effects::EffectConfig)'s defaults for the names they list, loaded from `assets/content/effects.ron` the same way `actor_content.rs` loads `actors.ron`:
+//! through Bevy's asset pipeline, so `file_watcher` reloads a preset the instant it's saved.
+use std::collections::HashMap;
+
+use bevy::asset::AssetLoader;
+use bevy::asset::LoadContext;
+use bevy::asset::io::Reader;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Root shape of `effects.ron`: named presets `spawn_effect` looks up by name, falling back to
+/// [`EffectConfig`](super::effects::EffectConfig)'s live-tunable defaults for any name the file
+/// doesn't list.
+#[derive(Asset, TypePath, Deserialize, Default, Debug, Clone)]
+pub struct EffectsContent {
+    #[serde(default)]
+    pub effect: HashMap<String, EffectPresetContent>,
+}
+
+/// One named effect's spawn parameters - mirrors `EffectConfig`'s fields plus a `lifetime` that
+/// can opt out of its own timer entirely in favor of tracking the entity it's attached to.
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct EffectPresetContent {
+    pub spawn_count:    usize,
+    pub lifetime:       EffectLifetimeContent,
+    pub initial_speed:  f32,
+    pub sticky:         bool,
+    pub particle_scale: f32,
+}
+
+/// Mirrors [`ParticleLifetime`](super::effects::ParticleLifetime).
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectLifetimeContent {
+    Fixed(f32),
+    /// The particle lives exactly as long as the entity it's attached to - see
+    /// `EffectPresetContent::sticky` and `spawn_effect`'s `attached_to` argument.
+    Inherit,
+}
+
+#[derive(Debug)]
+pub enum EffectsContentLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl std::fmt::Display for EffectsContentLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read effects content file: {err}"),
+            Self::Ron(err) => write!(f, "could not parse effects content file: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for EffectsContentLoaderError {}
+
+impl From<std::io::Error> for EffectsContentLoaderError {
+    fn from(err: std::io::Error) -> Self { Self::Io(err) }
+}
+
+impl From<ron::error::SpannedError> for EffectsContentLoaderError {
+    fn from(err: ron::error::SpannedError) -> Self { Self::Ron(err) }
+}
+
+#[derive(Default)]
+pub struct EffectsContentLoader;
+
+impl AssetLoader for EffectsContentLoader {
+    type Asset = EffectsContent;
+    type Error = EffectsContentLoaderError;
+    type Settings = ();
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] { &["ron"] }
+}
@@ -13,6 +13,17 @@ use super::constants::DEATH_EFFECT_LINE_COUNT;
 use super::constants::DEATH_EFFECT_LINE_LENGTH_BASE;
 use super::constants::DEATH_EFFECT_LINE_LENGTH_VARIANCE;
 use super::constants::DEATH_EFFECT_RADIUS_MARGIN;
+use super::constants::DEATH_LIGHT_INTENSITY;
+use super::constants::DEATH_LIGHT_RANGE;
+use super::constants::DEBRIS_GRAVITY_STRENGTH;
+use super::constants::DEBRIS_SHARD_JITTER;
+use super::constants::DEBRIS_SHARD_LENGTH;
+use super::constants::DEBRIS_SHARD_MAX_COUNT;
+use super::constants::DEBRIS_SHARD_MIN_COUNT;
+use super::constants::DEBRIS_SHARD_SPEED_MAX;
+use super::constants::DEBRIS_SHARD_SPEED_MIN;
+use super::constants::DEBRIS_SHARD_TUMBLE_RATE_MAX;
+use super::constants::DEBRIS_SHARD_VOLUME;
 use super::constants::FLAME_COLOR_FLICKER_SPEED;
 use super::constants::FLAME_GIZMO_LINE_WIDTH;
 use super::constants::FLAME_LENGTH_FLICKER_PHASE_MULT;
@@ -20,18 +31,30 @@ use super::constants::FLAME_LENGTH_FLICKER_SPEED;
 use super::constants::FLAME_PHASE_SPREAD;
 use super::constants::FLAME_VIBRATION_AMPLITUDE;
 use super::constants::FLAME_VIBRATION_SPEED;
+use super::constants::GIZMO_EFFECT_POOL_CAPACITY;
+use super::constants::MUZZLE_FLASH_DURATION_SECS;
+use super::constants::MUZZLE_FLASH_LINE_COUNT;
+use super::constants::MUZZLE_FLASH_LINE_LENGTH;
+use super::constants::MUZZLE_OFFSET;
 use super::constants::THRUSTER_COLOR_FLICKER_INTENSITY;
 use super::constants::THRUSTER_COLOR_ZONE_SIZE;
 use super::constants::THRUSTER_CONE_HALF_ANGLE;
+use super::constants::THRUSTER_LIGHT_INTENSITY;
+use super::constants::THRUSTER_LIGHT_RANGE;
 use super::constants::THRUSTER_LINE_COUNT;
 use super::constants::THRUSTER_LINE_LENGTH_BASE;
 use super::constants::THRUSTER_LINE_LENGTH_VARIANCE;
 use super::constants::THRUSTER_LINE_OFFSET;
 use super::constants::THRUSTER_VIBRATION_VERTICAL_PHASE_MULT;
 use super::constants::THRUSTER_VIBRATION_VERTICAL_SPEED_MULT;
+use super::constants::TRACER_COLOR_ZONE_SIZE;
+use super::constants::TRACER_DURATION_SECS;
+use super::constants::TRACER_LENGTH;
+use super::constants::TRACER_SEGMENT_COUNT;
 use super::spaceship::Spaceship;
 use super::spaceship_control::SpaceshipControl;
 use crate::camera::RenderLayer;
+use crate::despawn::despawn;
 use crate::state::GameState;
 use crate::state::PauseState;
 
@@ -40,6 +63,8 @@ pub struct FlameGizmoPlugin;
 impl Plugin for FlameGizmoPlugin {
     fn build(&self, app: &mut App) {
         app.init_gizmo_group::<FlameGizmo>()
+            .init_resource::<TempEffects>()
+            .init_resource::<GizmoLightsEnabled>()
             .add_systems(Startup, configure_flame_gizmo)
             .add_observer(on_deaderoid_added)
             .add_systems(
@@ -48,7 +73,11 @@ impl Plugin for FlameGizmoPlugin {
             )
             .add_systems(
                 Update,
-                (draw_thruster_flames, draw_death_effects).run_if(in_state(GameState::InGame)),
+                update_muzzle_effects.run_if(in_state(PauseState::Playing)),
+            )
+            .add_systems(
+                Update,
+                advance_and_draw_effects.run_if(in_state(GameState::InGame)),
             );
     }
 }
@@ -56,17 +85,83 @@ impl Plugin for FlameGizmoPlugin {
 #[derive(Default, Reflect, GizmoConfigGroup)]
 struct FlameGizmo {}
 
+/// Lets players on low-end hardware disable the dynamic point lights death explosions and
+/// thrusters emit, while keeping the gizmo line art itself.
+#[derive(Resource)]
+pub struct GizmoLightsEnabled(pub bool);
+
+impl Default for GizmoLightsEnabled {
+    fn default() -> Self { Self(true) }
+}
+
 fn configure_flame_gizmo(mut config_store: ResMut<GizmoConfigStore>) {
     let (config, _) = config_store.config_mut::<FlameGizmo>();
     config.line.width = FLAME_GIZMO_LINE_WIDTH;
     config.render_layers = RenderLayers::from_layers(RenderLayer::Game.layers());
 }
 
-/// observer that adds a death effect to a `Deaderoid`
-fn on_deaderoid_added(deaderoid: On<Add, Deaderoid>, mut commands: Commands, query: Query<&Aabb>) {
-    if let Ok(aabb) = query.get(deaderoid.entity) {
-        let death_effect = DeathEffect::new(aabb.max_dimension());
-        commands.entity(deaderoid.entity).insert(death_effect);
+/// observer that spawns a death effect at the `Deaderoid` - a ring that keeps tracking the
+/// deaderoid's own shrink/spin animation, or a one-shot debris burst seeded at its death position.
+fn on_deaderoid_added(
+    deaderoid: On<Add, Deaderoid>,
+    mut commands: Commands,
+    mut temp_effects: ResMut<TempEffects>,
+    lights_enabled: Res<GizmoLightsEnabled>,
+    aabbs: Query<&Aabb>,
+    transforms: Query<&Transform>,
+) {
+    let Ok(aabb) = aabbs.get(deaderoid.entity) else {
+        return;
+    };
+
+    let max_dimension = aabb.max_dimension();
+    let style = DeathStyle::random();
+
+    match style.ring_config() {
+        Some(config) => {
+            let index = temp_effects.spawn(
+                EffectSource::Entity(deaderoid.entity),
+                DEATH_EFFECT_DURATION_SECS,
+                GizmoEffectKind::DeathRing {
+                    radius: max_dimension + DEATH_EFFECT_RADIUS_MARGIN,
+                    config,
+                },
+            );
+
+            if let Some(index) = index
+                && lights_enabled.0
+            {
+                let light_entity = commands
+                    .spawn((
+                        PointLight {
+                            color: Color::from(tailwind::ORANGE_500),
+                            intensity: DEATH_LIGHT_INTENSITY,
+                            range: DEATH_LIGHT_RANGE,
+                            shadows_enabled: false,
+                            ..default()
+                        },
+                        Transform::IDENTITY,
+                        RenderLayers::from_layers(RenderLayer::Game.layers()),
+                        ChildOf(deaderoid.entity),
+                        Name::new("DeathLight"),
+                    ))
+                    .id();
+                temp_effects.set_light(index, light_entity);
+            }
+        },
+        None => {
+            let Ok(transform) = transforms.get(deaderoid.entity) else {
+                return;
+            };
+            let mut rng = rand::rng();
+            temp_effects.spawn(
+                EffectSource::Fixed(Isometry3d::new(transform.translation, transform.rotation)),
+                DEATH_EFFECT_DURATION_SECS,
+                GizmoEffectKind::Debris {
+                    shards: spawn_shards(max_dimension, &mut rng),
+                },
+            );
+        },
     }
 }
 
@@ -75,50 +170,375 @@ pub enum DeathStyle {
     ExpandingRing,
     StaticFlash,
     MultipleRings,
+    Implosion,
+    Debris,
 }
 
 impl DeathStyle {
-    const ALL: [Self; 3] = [Self::ExpandingRing, Self::StaticFlash, Self::MultipleRings];
+    const ALL: [Self; 5] = [
+        Self::ExpandingRing,
+        Self::StaticFlash,
+        Self::MultipleRings,
+        Self::Implosion,
+        Self::Debris,
+    ];
 
     pub fn random() -> Self {
         let mut rng = rand::rng();
         Self::ALL[rng.random_range(0..Self::ALL.len())]
     }
 
-    const fn config(self) -> RingEffectConfig {
+    /// `None` for styles (like `Debris`) that aren't ring-shaped at all.
+    fn ring_config(self) -> Option<RingEffectConfig> {
+        match self {
+            Self::ExpandingRing => Some(RingEffectConfig::expanding_ring()),
+            Self::StaticFlash => Some(RingEffectConfig::static_flash()),
+            Self::MultipleRings => Some(RingEffectConfig::multiple_rings()),
+            Self::Implosion => Some(RingEffectConfig::implosion()),
+            Self::Debris => None,
+        }
+    }
+}
+
+/// A temporary gizmo-drawn effect's type and the data its draw routine needs beyond the shared
+/// [`EffectCtx`] - dispatched from [`advance_and_draw_effects`].
+enum GizmoEffectKind {
+    DeathRing { radius: f32, config: RingEffectConfig },
+    Debris { shards: Vec<Shard> },
+    ThrusterFlame,
+    MuzzleFlash,
+    Tracer { length: f32 },
+}
+
+impl GizmoEffectKind {
+    fn draw(&self, gizmos: &mut Gizmos<FlameGizmo>, isometry: Isometry3d, ctx: &EffectCtx) {
+        match self {
+            Self::DeathRing { radius, config } => {
+                draw_death_effect_ring(gizmos, *radius, config, isometry, ctx);
+            },
+            Self::Debris { shards } => {
+                draw_debris(gizmos, Vec3::from(isometry.translation), shards, ctx);
+            },
+            Self::ThrusterFlame => draw_exhaust_flames(gizmos, isometry, ctx.elapsed),
+            Self::MuzzleFlash => draw_muzzle_flash(gizmos, isometry, ctx),
+            Self::Tracer { length } => draw_tracer(gizmos, isometry, *length, ctx),
+        }
+    }
+}
+
+/// Where a pooled effect samples its draw position/rotation from each frame.
+enum EffectSource {
+    /// A position baked in at spawn time - doesn't move or rotate. Used by effects (like
+    /// `Debris`) that fly free of whatever entity triggered them.
+    Fixed(Isometry3d),
+    /// Sampled from `Entity`'s `Transform` every frame, so the effect tracks e.g. a spinning
+    /// deaderoid. Expires on its own the frame the entity is gone.
+    Entity(Entity),
+}
+
+impl EffectSource {
+    fn isometry(&self, transforms: &Query<&Transform>) -> Option<Isometry3d> {
         match self {
-            Self::ExpandingRing => RingEffectConfig::EXPANDING_RING,
-            Self::StaticFlash => RingEffectConfig::STATIC_FLASH,
-            Self::MultipleRings => RingEffectConfig::MULTIPLE_RINGS,
+            Self::Fixed(isometry) => Some(*isometry),
+            Self::Entity(entity) => transforms
+                .get(*entity)
+                .ok()
+                .map(|transform| Isometry3d::new(transform.translation, transform.rotation)),
+        }
+    }
+}
+
+/// A claimed or free pool slot. `elapsed`/`duration` are this effect's own countdown, independent
+/// of [`EffectCtx::elapsed`] (the shared, pause-frozen visual clock flicker/vibration is keyed
+/// off) - a long-lived effect still flickers in sync with every other effect on screen.
+struct EffectSlot {
+    source:       EffectSource,
+    elapsed:      f32,
+    duration:     f32,
+    kind:         GizmoEffectKind,
+    /// Child `PointLight` entity following this effect, if the light layer spawned one for it.
+    light_entity: Option<Entity>,
+}
+
+impl Default for EffectSlot {
+    fn default() -> Self {
+        Self {
+            source:       EffectSource::Fixed(Isometry3d::IDENTITY),
+            elapsed:      0.0,
+            duration:     0.0,
+            kind:         GizmoEffectKind::ThrusterFlame,
+            light_entity: None,
         }
     }
 }
 
-/// Visual death effect that follows the entity's current position each frame.
-/// Duration is independent of entity lifetime.
-#[derive(Component, Reflect)]
-#[reflect(Component)]
-pub struct DeathEffect {
-    pub style:    DeathStyle,
-    pub radius:   f32,
-    pub duration: f32,
-    pub elapsed:  f32,
+/// Per-frame context shared by every effect kind's draw routine.
+struct EffectCtx {
+    /// Pause-frozen "visual clock" flicker/vibration math is keyed off, shared by every effect.
+    elapsed:        f32,
+    /// This slot's own elapsed time since it was spawned.
+    effect_elapsed: f32,
+    /// This slot's own duration, after which it's freed.
+    duration:       f32,
 }
 
-impl DeathEffect {
-    pub fn new(radius: f32) -> Self {
+/// Fixed-capacity pool of temporary gizmo effects (death rings, thruster flames, ...), modeled on
+/// a classic temp-entity pool: `slots` never grows past [`GIZMO_EFFECT_POOL_CAPACITY`], `active`
+/// tracks which slots currently hold a live effect, and `free` is reused instead of reallocating
+/// every time an effect starts or ends.
+#[derive(Resource)]
+struct TempEffects {
+    slots:  Vec<EffectSlot>,
+    active: Vec<usize>,
+    free:   Vec<usize>,
+}
+
+impl TempEffects {
+    fn with_capacity(capacity: usize) -> Self {
         Self {
-            style:    DeathStyle::random(),
-            radius:   radius + DEATH_EFFECT_RADIUS_MARGIN,
-            duration: DEATH_EFFECT_DURATION_SECS,
-            elapsed:  0.0,
+            slots:  (0..capacity).map(|_| EffectSlot::default()).collect(),
+            active: Vec::with_capacity(capacity),
+            free:   (0..capacity).rev().collect(),
+        }
+    }
+
+    /// Claims a free slot and starts a new effect, or drops the request (with a warning) if the
+    /// pool is already full rather than growing it - nothing should ever need more than
+    /// `GIZMO_EFFECT_POOL_CAPACITY` effects on screen at once.
+    fn spawn(
+        &mut self,
+        source: EffectSource,
+        duration: f32,
+        kind: GizmoEffectKind,
+    ) -> Option<usize> {
+        let Some(index) = self.free.pop() else {
+            warn!(
+                "TempEffects pool exhausted at {} active effects - dropping new effect",
+                self.active.len()
+            );
+            return None;
+        };
+
+        self.slots[index] = EffectSlot {
+            source,
+            elapsed: 0.0,
+            duration,
+            kind,
+            light_entity: None,
+        };
+        self.active.push(index);
+        Some(index)
+    }
+
+    /// Attaches a child `PointLight` entity to an already-spawned effect so its intensity/color
+    /// can be driven alongside the gizmo line art.
+    fn set_light(&mut self, index: usize, light_entity: Entity) {
+        self.slots[index].light_entity = Some(light_entity);
+    }
+
+    /// Ends an effect before its duration naturally expires, e.g. when the thruster stops firing.
+    /// Despawns its light too - `Commands` is threaded through since the light is a real entity,
+    /// not just pool bookkeeping.
+    fn cancel(&mut self, index: usize, commands: &mut Commands) {
+        if let Some(position) = self.active.iter().position(|&i| i == index) {
+            self.active.remove(position);
+            self.free.push(index);
+            if let Some(light_entity) = self.slots[index].light_entity.take() {
+                despawn(commands, light_entity);
+            }
         }
     }
 }
 
-#[derive(Component, Reflect)]
-#[reflect(Component)]
-pub struct ThrusterEffect;
+impl Default for TempEffects {
+    fn default() -> Self { Self::with_capacity(GIZMO_EFFECT_POOL_CAPACITY) }
+}
+
+/// Tracks which `TempEffects` slot the spaceship's continuous thruster-flame effect is using, so
+/// it can be canceled the instant acceleration stops instead of waiting out a duration.
+#[derive(Component)]
+struct ThrusterEffectSlot(usize);
+
+fn update_thruster_effect(
+    mut commands: Commands,
+    mut temp_effects: ResMut<TempEffects>,
+    lights_enabled: Res<GizmoLightsEnabled>,
+    query: Query<
+        (
+            Entity,
+            &ActionState<SpaceshipControl>,
+            Option<&ThrusterEffectSlot>,
+        ),
+        With<Spaceship>,
+    >,
+) {
+    let Ok((entity, controls, thruster_slot)) = query.single() else {
+        return;
+    };
+
+    let is_accelerating = controls.pressed(&SpaceshipControl::Accelerate);
+
+    match (is_accelerating, thruster_slot) {
+        (true, None) => {
+            // Thruster flames last as long as the ship is accelerating, not a fixed duration -
+            // `f32::MAX` means the pool only ever frees this slot via `cancel`.
+            if let Some(index) = temp_effects.spawn(
+                EffectSource::Entity(entity),
+                f32::MAX,
+                GizmoEffectKind::ThrusterFlame,
+            ) {
+                commands.entity(entity).insert(ThrusterEffectSlot(index));
+
+                if lights_enabled.0 {
+                    let light_entity = commands
+                        .spawn((
+                            PointLight {
+                                color: Color::from(tailwind::ORANGE_500),
+                                intensity: THRUSTER_LIGHT_INTENSITY,
+                                range: THRUSTER_LIGHT_RANGE,
+                                shadows_enabled: false,
+                                ..default()
+                            },
+                            Transform::from_translation(Vec3::Z * THRUSTER_LINE_OFFSET),
+                            RenderLayers::from_layers(RenderLayer::Game.layers()),
+                            ChildOf(entity),
+                            Name::new("ThrusterLight"),
+                        ))
+                        .id();
+                    temp_effects.set_light(index, light_entity);
+                }
+            }
+        },
+        (false, Some(&ThrusterEffectSlot(index))) => {
+            temp_effects.cancel(index, &mut commands);
+            commands.entity(entity).remove::<ThrusterEffectSlot>();
+        },
+        _ => {},
+    }
+}
+
+/// Fires a muzzle flash and a tracer beam, both one-shot effects through the same pooled
+/// death-effect-style lifecycle, each time the spaceship's fire action is newly pressed.
+fn update_muzzle_effects(
+    mut temp_effects: ResMut<TempEffects>,
+    query: Query<(&Transform, &ActionState<SpaceshipControl>), With<Spaceship>>,
+) {
+    let Ok((transform, controls)) = query.single() else {
+        return;
+    };
+
+    if !controls.just_pressed(&SpaceshipControl::Fire) {
+        return;
+    }
+
+    let muzzle_position = transform.translation + transform.forward() * MUZZLE_OFFSET;
+    let isometry = Isometry3d::new(muzzle_position, transform.rotation);
+
+    temp_effects.spawn(
+        EffectSource::Fixed(isometry),
+        MUZZLE_FLASH_DURATION_SECS,
+        GizmoEffectKind::MuzzleFlash,
+    );
+    temp_effects.spawn(
+        EffectSource::Fixed(isometry),
+        TRACER_DURATION_SECS,
+        GizmoEffectKind::Tracer { length: TRACER_LENGTH },
+    );
+}
+
+/// Centralizes the freeze-on-pause elapsed logic, advances and expires pooled effects, and
+/// dispatches each live effect's draw routine - the one system every `GizmoEffectKind` shares
+/// instead of each effect duplicating its own pause handling.
+fn advance_and_draw_effects(
+    mut commands: Commands,
+    mut temp_effects: ResMut<TempEffects>,
+    mut gizmos: Gizmos<FlameGizmo>,
+    mut point_lights: Query<&mut PointLight>,
+    time: Res<Time>,
+    pause_state: Res<State<PauseState>>,
+    mut frozen_elapsed: Local<f32>,
+    transforms: Query<&Transform>,
+) {
+    let is_paused = *pause_state.get() == PauseState::Paused;
+    let elapsed = if is_paused {
+        *frozen_elapsed
+    } else {
+        *frozen_elapsed = time.elapsed_secs();
+        time.elapsed_secs()
+    };
+
+    let active_slots = temp_effects.active.clone();
+    let mut expired = Vec::new();
+
+    for index in active_slots {
+        let slot = &mut temp_effects.slots[index];
+
+        if !is_paused {
+            slot.elapsed += time.delta_secs();
+        }
+
+        if slot.elapsed >= slot.duration {
+            expired.push(index);
+            continue;
+        }
+
+        let Some(isometry) = slot.source.isometry(&transforms) else {
+            // Source entity despawned mid-effect - drop it instead of drawing garbage.
+            expired.push(index);
+            continue;
+        };
+
+        let ctx = EffectCtx {
+            elapsed,
+            effect_elapsed: slot.elapsed,
+            duration: slot.duration,
+        };
+
+        if let Some(light_entity) = slot.light_entity
+            && let Ok(mut point_light) = point_lights.get_mut(light_entity)
+        {
+            update_effect_light(&mut point_light, &slot.kind, &ctx);
+        }
+
+        slot.kind.draw(&mut gizmos, isometry, &ctx);
+    }
+
+    for index in expired {
+        temp_effects.cancel(index, &mut commands);
+    }
+}
+
+/// Drives an effect's optional child [`PointLight`] from the same progress/flicker math its
+/// gizmo line art uses, so death flashes and thruster exhaust actually illuminate nearby meshes.
+fn update_effect_light(point_light: &mut PointLight, kind: &GizmoEffectKind, ctx: &EffectCtx) {
+    match kind {
+        GizmoEffectKind::DeathRing { config, .. } => {
+            let progress = (ctx.effect_elapsed / ctx.duration).clamp(0.0, 1.0);
+            let alpha = if config.converge_fraction > 0.0 && progress < config.converge_fraction {
+                let converge_progress = progress / config.converge_fraction;
+                converge_progress * converge_progress
+            } else {
+                let expand_progress = if config.converge_fraction > 0.0 {
+                    (progress - config.converge_fraction) / (1.0 - config.converge_fraction)
+                } else {
+                    progress
+                };
+                config.alpha_envelope.sample(expand_progress)
+            };
+            point_light.intensity = DEATH_LIGHT_INTENSITY * alpha;
+            point_light.color = lerp_color(
+                Color::from(tailwind::ORANGE_500),
+                Color::from(tailwind::YELLOW_400),
+                alpha,
+            );
+        },
+        GizmoEffectKind::ThrusterFlame => {
+            let flicker = compute_flicker(ctx.elapsed, 0.0, 0.0);
+            point_light.intensity = THRUSTER_LIGHT_INTENSITY * flicker.color;
+        },
+        GizmoEffectKind::Debris { .. } | GizmoEffectKind::MuzzleFlash | GizmoEffectKind::Tracer { .. } => {},
+    }
+}
 
 struct FlickerValues {
     length: f32,
@@ -202,129 +622,278 @@ impl FlameZone {
     }
 }
 
-/// Alpha fade curve for death effects.
+/// Interpolation used for the segment leading into a [`Keyframe`] from the previous one.
 #[derive(Clone, Copy)]
-enum AlphaCurve {
-    /// Linear fade from 1.0 to 0.0
-    LinearFade,
-    /// Quick flash in, then fade out
-    FlashInFadeOut { flash_in_fraction: f32 },
+enum Interpolation {
+    /// Holds the previous keyframe's value until this one's `time`, then jumps.
+    Step,
+    Linear,
+    SmoothStep,
 }
 
-impl AlphaCurve {
-    fn compute(self, progress: f32) -> f32 {
-        match self {
-            Self::LinearFade => 1.0 - progress,
-            Self::FlashInFadeOut { flash_in_fraction } => {
-                if progress < flash_in_fraction {
-                    progress / flash_in_fraction
-                } else {
-                    1.0 - ((progress - flash_in_fraction) / (1.0 - flash_in_fraction))
-                }
+/// One point in an [`Envelope`] - `time` is normalized 0..1 over the sampled effect's own
+/// elapsed/duration, not wall-clock seconds.
+#[derive(Clone, Copy)]
+struct Keyframe {
+    time:  f32,
+    value: f32,
+    mode:  Interpolation,
+}
+
+/// An ordered-by-time list of keyframes sampled by normalized progress (0..1), replacing the old
+/// fixed-shape `AlphaCurve` with a declarative description any ring dimension (alpha, radius
+/// scale, line-length scale) can use - new death styles shape these purely as data, no new math.
+#[derive(Clone)]
+struct Envelope {
+    keyframes: Vec<Keyframe>,
+}
+
+impl Envelope {
+    /// Keyframes must be sorted ascending by `time`; the first/last keyframe's value is held flat
+    /// outside the `0..1` range.
+    fn new(keyframes: Vec<Keyframe>) -> Self {
+        debug_assert!(!keyframes.is_empty(), "Envelope needs at least one keyframe");
+        Self { keyframes }
+    }
+
+    /// A flat envelope holding one constant value for the whole effect.
+    fn constant(value: f32) -> Self {
+        Self::new(vec![Keyframe {
+            time: 0.0,
+            value,
+            mode: Interpolation::Step,
+        }])
+    }
+
+    fn sample(&self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        let first = &self.keyframes[0];
+        if progress <= first.time {
+            return first.value;
+        }
+
+        let last = &self.keyframes[self.keyframes.len() - 1];
+        if progress >= last.time {
+            return last.value;
+        }
+
+        // Binary search for the first keyframe past `progress` - `a`/`b` are the bracketing pair.
+        let next = self.keyframes.partition_point(|keyframe| keyframe.time <= progress);
+        let a = &self.keyframes[next - 1];
+        let b = &self.keyframes[next];
+        let t = (progress - a.time) / (b.time - a.time).max(f32::EPSILON);
+
+        match b.mode {
+            Interpolation::Step => a.value,
+            Interpolation::Linear => a.value + (b.value - a.value) * t,
+            Interpolation::SmoothStep => {
+                let smooth_t = t * t * (3.0 - 2.0 * t);
+                a.value + (b.value - a.value) * smooth_t
             },
         }
     }
 }
 
-/// Configuration for ring-based death effects.
-#[derive(Clone, Copy)]
+/// Configuration for ring-based death effects: alpha, radius scale, and line-length scale are
+/// each their own [`Envelope`] over the ring's normalized progress, so `DeathStyle::ring_config()` can
+/// express e.g. "flash in, hold, expand late, then fade" purely as keyframe data.
+#[derive(Clone)]
 struct RingEffectConfig {
-    ring_count:        usize,
-    expands:           bool,
-    radius_scale:      f32,
-    line_length_scale: f32,
-    ring_delay_secs:   f32,
-    ring_phase_offset: f32,
-    alpha_curve:       AlphaCurve,
+    ring_count:            usize,
+    ring_delay_secs:       f32,
+    ring_phase_offset:     f32,
+    /// Fraction of the ring's progress spent converging inward before handing off to the normal
+    /// expand envelopes below - `0.0` for every outward-only style.
+    converge_fraction:     f32,
+    alpha_envelope:        Envelope,
+    radius_scale_envelope: Envelope,
+    line_length_envelope:  Envelope,
 }
 
 impl RingEffectConfig {
-    const EXPANDING_RING: Self = Self {
-        ring_count:        1,
-        expands:           true,
-        radius_scale:      1.0,
-        line_length_scale: 0.5,
-        ring_delay_secs:   0.0,
-        ring_phase_offset: 0.0,
-        alpha_curve:       AlphaCurve::LinearFade,
-    };
+    fn expanding_ring() -> Self {
+        Self {
+            ring_count:            1,
+            ring_delay_secs:       0.0,
+            ring_phase_offset:     0.0,
+            converge_fraction:     0.0,
+            alpha_envelope:        Envelope::new(vec![
+                Keyframe { time: 0.0, value: 1.0, mode: Interpolation::Step },
+                Keyframe { time: 1.0, value: 0.0, mode: Interpolation::Linear },
+            ]),
+            radius_scale_envelope: Envelope::new(vec![
+                Keyframe {
+                    time:  0.0,
+                    value: DEATH_EFFECT_EXPANDING_RING_START_SCALE,
+                    mode:  Interpolation::Step,
+                },
+                Keyframe { time: 1.0, value: 1.0, mode: Interpolation::SmoothStep },
+            ]),
+            line_length_envelope:  Envelope::constant(0.5),
+        }
+    }
 
-    const STATIC_FLASH: Self = Self {
-        ring_count:        1,
-        expands:           false,
-        radius_scale:      0.4,
-        line_length_scale: 0.5,
-        ring_delay_secs:   0.0,
-        ring_phase_offset: 0.0,
-        alpha_curve:       AlphaCurve::FlashInFadeOut {
-            flash_in_fraction: 0.2,
-        },
-    };
+    fn static_flash() -> Self {
+        Self {
+            ring_count:            1,
+            ring_delay_secs:       0.0,
+            ring_phase_offset:     0.0,
+            converge_fraction:     0.0,
+            alpha_envelope:        Envelope::new(vec![
+                Keyframe { time: 0.0, value: 0.0, mode: Interpolation::Step },
+                Keyframe { time: 0.2, value: 1.0, mode: Interpolation::Linear },
+                Keyframe { time: 1.0, value: 0.0, mode: Interpolation::Linear },
+            ]),
+            radius_scale_envelope: Envelope::constant(0.4),
+            line_length_envelope:  Envelope::constant(0.5),
+        }
+    }
 
-    const MULTIPLE_RINGS: Self = Self {
-        ring_count:        3,
-        expands:           true,
-        radius_scale:      1.0,
-        line_length_scale: 1.0 / 3.0,
-        ring_delay_secs:   0.4,
-        ring_phase_offset: 2.0,
-        alpha_curve:       AlphaCurve::LinearFade,
-    };
+    fn multiple_rings() -> Self {
+        Self {
+            ring_count:            3,
+            ring_delay_secs:       0.4,
+            ring_phase_offset:     2.0,
+            converge_fraction:     0.0,
+            alpha_envelope:        Envelope::new(vec![
+                Keyframe { time: 0.0, value: 1.0, mode: Interpolation::Step },
+                Keyframe { time: 1.0, value: 0.0, mode: Interpolation::Linear },
+            ]),
+            radius_scale_envelope: Envelope::new(vec![
+                Keyframe {
+                    time:  0.0,
+                    value: DEATH_EFFECT_EXPANDING_RING_START_SCALE,
+                    mode:  Interpolation::Step,
+                },
+                Keyframe { time: 1.0, value: 1.0, mode: Interpolation::SmoothStep },
+            ]),
+            line_length_envelope:  Envelope::constant(1.0 / 3.0),
+        }
+    }
+
+    /// Lines converge inward over `converge_fraction` of the effect before the remaining fraction
+    /// flips to the same bright expanding flash as [`Self::expanding_ring`], giving a "collapse
+    /// then burst" read.
+    fn implosion() -> Self {
+        Self {
+            ring_count:            1,
+            ring_delay_secs:       0.0,
+            ring_phase_offset:     0.0,
+            converge_fraction:     0.6,
+            alpha_envelope:        Envelope::new(vec![
+                Keyframe { time: 0.0, value: 1.0, mode: Interpolation::Step },
+                Keyframe { time: 1.0, value: 0.0, mode: Interpolation::Linear },
+            ]),
+            radius_scale_envelope: Envelope::new(vec![
+                Keyframe {
+                    time:  0.0,
+                    value: DEATH_EFFECT_EXPANDING_RING_START_SCALE,
+                    mode:  Interpolation::Step,
+                },
+                Keyframe { time: 1.0, value: 1.0, mode: Interpolation::SmoothStep },
+            ]),
+            line_length_envelope:  Envelope::constant(0.5),
+        }
+    }
 }
 
-fn update_thruster_effect(
-    mut commands: Commands,
-    query: Query<
-        (
-            Entity,
-            &ActionState<SpaceshipControl>,
-            Option<&ThrusterEffect>,
-        ),
-        With<Spaceship>,
-    >,
-) {
-    let Ok((entity, controls, thruster_effect)) = query.single() else {
-        return;
-    };
+/// A single fragment of a shattered `Deaderoid`: a short line segment flying outward from the
+/// death position while tumbling in place.
+struct Shard {
+    /// Local segment endpoints (shard-space), rotated in place by `tumble_rate` each frame.
+    local_start:  Vec3,
+    local_end:    Vec3,
+    /// Initial outward velocity (radial direction plus jitter), world-space.
+    velocity:     Vec3,
+    tumble_axis:  Vec3,
+    tumble_rate:  f32,
+    /// Per-shard color-flicker phase so shards don't all flicker in lockstep.
+    phase_offset: f32,
+}
 
-    let is_accelerating = controls.pressed(&SpaceshipControl::Accelerate);
+fn random_unit_vector(rng: &mut impl Rng) -> Vec3 {
+    let theta = rng.random_range(0.0..std::f32::consts::TAU);
+    let phi = 2.0f32.mul_add(rng.random_range(0.0..1.0), -1.0).acos();
+    Vec3::new(theta.cos() * phi.sin(), theta.sin() * phi.sin(), phi.cos())
+}
 
-    match (is_accelerating, thruster_effect) {
-        (true, None) => {
-            commands.entity(entity).insert(ThrusterEffect);
-        },
-        (false, Some(_)) => {
-            commands.entity(entity).remove::<ThrusterEffect>();
-        },
-        _ => {},
-    }
+/// Seeds a debris burst sized roughly one shard per [`DEBRIS_SHARD_VOLUME`] cubic units of
+/// `max_dimension` cubed - the classic `SHARD_VOLUME` fragmentation heuristic - clamped to a
+/// sane range.
+fn spawn_shards(max_dimension: f32, rng: &mut impl Rng) -> Vec<Shard> {
+    let volume = max_dimension.powi(3);
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let count = ((volume / DEBRIS_SHARD_VOLUME) as usize)
+        .clamp(DEBRIS_SHARD_MIN_COUNT, DEBRIS_SHARD_MAX_COUNT);
+
+    (0..count)
+        .map(|_| {
+            let direction = random_unit_vector(rng);
+            let jitter = random_unit_vector(rng) * DEBRIS_SHARD_JITTER;
+            let speed = rng.random_range(DEBRIS_SHARD_SPEED_MIN..DEBRIS_SHARD_SPEED_MAX);
+            let velocity = (direction + jitter).normalize() * speed;
+            let half_length = DEBRIS_SHARD_LENGTH * 0.5;
+
+            Shard {
+                local_start: -direction * half_length,
+                local_end: direction * half_length,
+                velocity,
+                tumble_axis: random_unit_vector(rng),
+                tumble_rate: rng
+                    .random_range(-DEBRIS_SHARD_TUMBLE_RATE_MAX..DEBRIS_SHARD_TUMBLE_RATE_MAX),
+                phase_offset: rng.random_range(0.0..std::f32::consts::TAU),
+            }
+        })
+        .collect()
 }
 
-fn draw_thruster_flames(
-    mut gizmos: Gizmos<FlameGizmo>,
-    time: Res<Time>,
-    pause_state: Res<State<PauseState>>,
-    mut frozen_elapsed: Local<f32>,
-    query: Query<&Transform, With<ThrusterEffect>>,
-) {
-    let elapsed = if *pause_state.get() == PauseState::Paused {
-        *frozen_elapsed
-    } else {
-        *frozen_elapsed = time.elapsed_secs();
-        time.elapsed_secs()
-    };
+/// Holds near-full alpha for most of the burst, then fades out at the end.
+fn debris_alpha_envelope() -> Envelope {
+    Envelope::new(vec![
+        Keyframe { time: 0.0, value: 1.0, mode: Interpolation::Step },
+        Keyframe { time: 0.7, value: 1.0, mode: Interpolation::Linear },
+        Keyframe { time: 1.0, value: 0.0, mode: Interpolation::Linear },
+    ])
+}
 
-    for transform in query.iter() {
-        draw_exhaust_flames(&mut gizmos, transform, elapsed);
+/// Offset from the death center at `elapsed` seconds: velocity integrates linearly, with a mild
+/// inward pull back along the shard's own outward direction so its flight path arcs instead of
+/// running in a straight line forever.
+fn shard_offset(shard: &Shard, elapsed: f32) -> Vec3 {
+    let outward_direction = shard.velocity.normalize_or_zero();
+    let gravity_pull = outward_direction * (-0.5 * DEBRIS_GRAVITY_STRENGTH * elapsed * elapsed);
+    shard.velocity * elapsed + gravity_pull
+}
+
+fn draw_debris(gizmos: &mut Gizmos<FlameGizmo>, center: Vec3, shards: &[Shard], ctx: &EffectCtx) {
+    let progress = (ctx.effect_elapsed / ctx.duration).clamp(0.0, 1.0);
+    let alpha = debris_alpha_envelope().sample(progress);
+
+    for shard in shards {
+        let offset = shard_offset(shard, ctx.effect_elapsed);
+        let rotation = Quat::from_axis_angle(shard.tumble_axis, shard.tumble_rate * ctx.effect_elapsed);
+
+        let start = center + offset + rotation * shard.local_start;
+        let end = center + offset + rotation * shard.local_end;
+
+        let flicker = compute_flicker(ctx.elapsed, 0.0, shard.phase_offset);
+        let color = lerp_color(
+            Color::from(tailwind::ORANGE_500),
+            Color::from(tailwind::YELLOW_400),
+            flicker.color,
+        )
+        .with_alpha(alpha);
+
+        gizmos.line(start, end, color);
     }
 }
 
-fn draw_exhaust_flames(gizmos: &mut Gizmos<FlameGizmo>, transform: &Transform, elapsed: f32) {
-    let back_direction = -transform.forward().as_vec3();
-    let right = transform.right().as_vec3();
-    let up = transform.up().as_vec3();
+fn draw_exhaust_flames(gizmos: &mut Gizmos<FlameGizmo>, isometry: Isometry3d, elapsed: f32) {
+    let back_direction = isometry.rotation * Vec3::Z;
+    let right = isometry.rotation * Vec3::X;
+    let up = isometry.rotation * Vec3::Y;
 
-    let base_position = transform.translation + back_direction * THRUSTER_LINE_OFFSET;
+    let base_position = Vec3::from(isometry.translation) + back_direction * THRUSTER_LINE_OFFSET;
 
     let color_yellow = Color::from(tailwind::YELLOW_400);
     let color_orange = Color::from(tailwind::ORANGE_500);
@@ -368,38 +937,85 @@ fn draw_exhaust_flames(gizmos: &mut Gizmos<FlameGizmo>, transform: &Transform, e
     }
 }
 
-fn draw_death_effects(
-    mut commands: Commands,
-    mut gizmos: Gizmos<FlameGizmo>,
-    time: Res<Time>,
-    pause_state: Res<State<PauseState>>,
-    mut frozen_elapsed: Local<f32>,
-    mut death_effect_query: Query<(Entity, &mut DeathEffect, &Transform)>,
-) {
-    let is_paused = *pause_state.get() == PauseState::Paused;
-    let elapsed = if is_paused {
-        *frozen_elapsed
-    } else {
-        *frozen_elapsed = time.elapsed_secs();
-        time.elapsed_secs()
-    };
+/// Rapid decay so the flash reads as one bright frame plus a short dimming tail.
+fn muzzle_flash_alpha_envelope() -> Envelope {
+    Envelope::new(vec![
+        Keyframe { time: 0.0, value: 1.0, mode: Interpolation::Step },
+        Keyframe { time: 0.3, value: 0.4, mode: Interpolation::Linear },
+        Keyframe { time: 1.0, value: 0.0, mode: Interpolation::Linear },
+    ])
+}
 
-    for (entity, mut death_effect, transform) in &mut death_effect_query {
-        // Only advance effect timer when not paused
-        if !is_paused {
-            death_effect.elapsed += time.delta_secs();
+fn draw_muzzle_flash(gizmos: &mut Gizmos<FlameGizmo>, isometry: Isometry3d, ctx: &EffectCtx) {
+    let progress = (ctx.effect_elapsed / ctx.duration).clamp(0.0, 1.0);
+    let alpha = muzzle_flash_alpha_envelope().sample(progress);
 
-            if death_effect.elapsed >= death_effect.duration {
-                commands.entity(entity).remove::<DeathEffect>();
-                continue;
-            }
-        }
+    let position = Vec3::from(isometry.translation);
+    let right = isometry.rotation * Vec3::X;
+    let up = isometry.rotation * Vec3::Y;
+    let color = Color::from(tailwind::YELLOW_400).with_alpha(alpha);
+
+    #[allow(clippy::cast_precision_loss)]
+    let line_count_f32 = MUZZLE_FLASH_LINE_COUNT as f32;
 
-        // Use the deaderoid's rotation so the ring follows its spin
-        let isometry = Isometry3d::new(transform.translation, transform.rotation);
-        let config = death_effect.style.config();
+    for i in 0..MUZZLE_FLASH_LINE_COUNT {
+        #[allow(clippy::cast_precision_loss)]
+        let line_index = i as f32;
+        let angle = std::f32::consts::TAU * line_index / line_count_f32;
+        let direction = right * angle.cos() + up * angle.sin();
 
-        draw_death_effect_ring(&mut gizmos, &death_effect, &config, isometry, elapsed);
+        gizmos.line(position, position + direction * MUZZLE_FLASH_LINE_LENGTH, color);
+    }
+}
+
+/// Holds the tracer's full length for most of its life, then tapers it down toward the tail end.
+fn tracer_length_envelope() -> Envelope {
+    Envelope::new(vec![
+        Keyframe { time: 0.0, value: 1.0, mode: Interpolation::Step },
+        Keyframe { time: 0.5, value: 1.0, mode: Interpolation::Linear },
+        Keyframe { time: 1.0, value: 0.15, mode: Interpolation::Linear },
+    ])
+}
+
+fn tracer_alpha_envelope() -> Envelope {
+    Envelope::new(vec![
+        Keyframe { time: 0.0, value: 1.0, mode: Interpolation::Step },
+        Keyframe { time: 0.7, value: 0.8, mode: Interpolation::Linear },
+        Keyframe { time: 1.0, value: 0.0, mode: Interpolation::Linear },
+    ])
+}
+
+/// Draws a tapered beam from the muzzle toward the travel direction, color-graded hot (at the
+/// muzzle) to cool (at the tapering tail) via the same [`FlameZone`] zones the thruster uses.
+fn draw_tracer(gizmos: &mut Gizmos<FlameGizmo>, isometry: Isometry3d, length: f32, ctx: &EffectCtx) {
+    let progress = (ctx.effect_elapsed / ctx.duration).clamp(0.0, 1.0);
+    let alpha = tracer_alpha_envelope().sample(progress);
+    let visible_length = length * tracer_length_envelope().sample(progress);
+
+    let position = Vec3::from(isometry.translation);
+    let forward = isometry.rotation * Vec3::NEG_Z;
+
+    let color_red = Color::from(tailwind::RED_600).with_alpha(alpha);
+    let color_orange = Color::from(tailwind::ORANGE_500).with_alpha(alpha);
+    let color_yellow = Color::from(tailwind::YELLOW_400).with_alpha(alpha);
+
+    #[allow(clippy::cast_precision_loss)]
+    let segment_count_f32 = TRACER_SEGMENT_COUNT as f32;
+
+    for i in 0..TRACER_SEGMENT_COUNT {
+        #[allow(clippy::cast_precision_loss)]
+        let segment_index = i as f32;
+        let t0 = segment_index / segment_count_f32;
+        let t1 = (segment_index + 1.0) / segment_count_f32;
+
+        // Nearest the muzzle (t0 == 0) is hottest; the tapering tail is coolest.
+        let zone = FlameZone::from_center_factor(1.0 - t0, TRACER_COLOR_ZONE_SIZE);
+        let color = zone.color(ctx.elapsed, 0.0, color_red, color_orange, color_yellow);
+
+        let start = position + forward * (t0 * visible_length);
+        let end = position + forward * (t1 * visible_length);
+
+        gizmos.line(start, end, color);
     }
 }
 
@@ -452,28 +1068,25 @@ fn draw_ring_lines(
     }
 }
 
-/// Unified death effect drawing using `RingEffectConfig`.
+/// Unified death effect drawing, driven entirely by `RingEffectConfig`'s envelopes.
 fn draw_death_effect_ring(
     gizmos: &mut Gizmos<FlameGizmo>,
-    death_effect: &DeathEffect,
+    radius: f32,
     config: &RingEffectConfig,
     isometry: Isometry3d,
-    elapsed: f32,
+    ctx: &EffectCtx,
 ) {
-    let line_length_base = DEATH_EFFECT_LINE_LENGTH_BASE * config.line_length_scale;
-    let line_length_variance = DEATH_EFFECT_LINE_LENGTH_VARIANCE * config.line_length_scale;
-
     for ring_idx in 0..config.ring_count {
         #[allow(clippy::cast_precision_loss)]
         let ring_idx_f32 = ring_idx as f32;
         let ring_start_time = ring_idx_f32 * config.ring_delay_secs;
 
-        if death_effect.elapsed < ring_start_time {
+        if ctx.effect_elapsed < ring_start_time {
             continue;
         }
 
-        let ring_elapsed = death_effect.elapsed - ring_start_time;
-        let ring_duration = death_effect.duration - ring_start_time;
+        let ring_elapsed = ctx.effect_elapsed - ring_start_time;
+        let ring_duration = ctx.duration - ring_start_time;
 
         if ring_elapsed > ring_duration {
             continue;
@@ -481,28 +1094,41 @@ fn draw_death_effect_ring(
 
         let progress = ring_elapsed / ring_duration;
 
-        let radius = if config.expands {
-            let ease_out = (1.0 - progress).mul_add(-(1.0 - progress), 1.0);
-            let scale = (1.0 - DEATH_EFFECT_EXPANDING_RING_START_SCALE)
-                .mul_add(ease_out, DEATH_EFFECT_EXPANDING_RING_START_SCALE);
-            death_effect.radius * config.radius_scale * scale
-        } else {
-            death_effect.radius * config.radius_scale
-        };
+        let (ring_radius, line_length_scale, alpha) =
+            if config.converge_fraction > 0.0 && progress < config.converge_fraction {
+                let converge_progress = progress / config.converge_fraction;
+                (
+                    radius * (1.0 - converge_progress),
+                    config.line_length_envelope.sample(0.0),
+                    converge_progress * converge_progress,
+                )
+            } else {
+                let expand_progress = if config.converge_fraction > 0.0 {
+                    (progress - config.converge_fraction) / (1.0 - config.converge_fraction)
+                } else {
+                    progress
+                };
+                (
+                    radius * config.radius_scale_envelope.sample(expand_progress),
+                    config.line_length_envelope.sample(expand_progress),
+                    config.alpha_envelope.sample(expand_progress),
+                )
+            };
+        let line_length_base = DEATH_EFFECT_LINE_LENGTH_BASE * line_length_scale;
+        let line_length_variance = DEATH_EFFECT_LINE_LENGTH_VARIANCE * line_length_scale;
 
-        let alpha = config.alpha_curve.compute(progress);
         let color_orange = Color::from(tailwind::ORANGE_500).with_alpha(alpha);
         let color_yellow = Color::from(tailwind::YELLOW_400).with_alpha(alpha);
 
         draw_ring_lines(
             gizmos,
             isometry,
-            radius,
+            ring_radius,
             line_length_base,
             line_length_variance,
             color_orange,
             color_yellow,
-            elapsed,
+            ctx.elapsed,
             ring_idx_f32 * config.ring_phase_offset,
         );
     }
@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::Health;
+use super::Teleporter;
+use crate::schedule::InGameSet;
+
+/// How many recent per-frame acceleration samples [`GForce`] keeps, so a sustained high-g
+/// maneuver can be told apart from a single-frame spike.
+const G_FORCE_WINDOW_FRAMES: usize = 5;
+
+pub struct GForcePlugin;
+
+impl Plugin for GForcePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            apply_g_force_damage.in_set(InGameSet::EntityUpdates),
+        );
+    }
+}
+
+/// Opts an actor into structural-stress damage: exceeding `tolerance` units/s² of acceleration
+/// (sustained across [`G_FORCE_WINDOW_FRAMES`], not just a single-frame spike) costs `Health`
+/// proportional to the overshoot. Added alongside `ActorConfig::g_force_tolerance` by
+/// `insert_configured_components`.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct GForce {
+    pub tolerance:      f32,
+    last_velocity:      Vec3,
+    recent_magnitudes:  VecDeque<f32>,
+}
+
+impl GForce {
+    pub fn new(tolerance: f32) -> Self {
+        Self {
+            tolerance,
+            last_velocity: Vec3::ZERO,
+            recent_magnitudes: VecDeque::with_capacity(G_FORCE_WINDOW_FRAMES),
+        }
+    }
+}
+
+/// Tracks each opted-in actor's `LinearVelocity` across frames, derives instantaneous
+/// acceleration magnitude `|Δv| / dt`, and once [`G_FORCE_WINDOW_FRAMES`] consecutive samples
+/// all exceed `GForce::tolerance`, applies damage to `Health` scaled by how far the newest
+/// sample in the window overshoots it. Skips the frame a teleport lands on - the instantaneous
+/// position (and therefore velocity-direction) swap isn't a real acceleration event - by
+/// resyncing `last_velocity` and clearing the window without recording a sample.
+fn apply_g_force_damage(
+    time: Res<Time>,
+    mut actors: Query<(&LinearVelocity, &Teleporter, &mut GForce, &mut Health)>,
+) {
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+
+    for (velocity, teleporter, mut g_force, mut health) in &mut actors {
+        if teleporter.just_teleported {
+            g_force.last_velocity = velocity.0;
+            g_force.recent_magnitudes.clear();
+            continue;
+        }
+
+        let acceleration = (velocity.0 - g_force.last_velocity).length() / dt;
+        g_force.last_velocity = velocity.0;
+
+        if g_force.recent_magnitudes.len() == G_FORCE_WINDOW_FRAMES {
+            g_force.recent_magnitudes.pop_front();
+        }
+        g_force.recent_magnitudes.push_back(acceleration);
+
+        let sustained = g_force.recent_magnitudes.len() == G_FORCE_WINDOW_FRAMES
+            && g_force.recent_magnitudes.iter().all(|&sample| sample > g_force.tolerance);
+
+        if sustained {
+            let overshoot = acceleration - g_force.tolerance;
+            health.0 -= overshoot;
+        }
+    }
+}
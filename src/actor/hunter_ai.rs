@@ -0,0 +1,336 @@
+//! Opt-in AI variant of [`Nateroid`] that hunts the player instead of drifting on a fixed
+//! velocity. A [`HunterBrain`] is a small hand-rolled feed-forward network (no autodiff, no ML
+//! crate - this repo has none) whose weights are either loaded from a genome trained offline by
+//! `hunter_evolve`'s headless evolve mode, or randomly initialized as a fallback.
+use std::fs;
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::path::PathBuf;
+
+use avian3d::prelude::*;
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+use dirs::config_dir;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Nateroid;
+use super::actor_template::NateroidConfig;
+use super::constants::HUNTER_HIDDEN_SIZE;
+use super::constants::HUNTER_SPAWN_CHANCE;
+use super::constants::HUNTER_TARGETS_K;
+use super::spaceship::Spaceship;
+use crate::camera::OffscreenIndicator;
+use crate::rollback::RollbackRng;
+use crate::schedule::InGameSet;
+
+const HUNTER_GENOME_FILENAME: &str = "hunter_genome.ron";
+
+pub struct HunterAiPlugin;
+
+impl Plugin for HunterAiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HunterConfig>()
+            .add_observer(load_or_init_hunter_brain)
+            .add_observer(mark_hunter_offscreen_threat)
+            .add_systems(
+                FixedUpdate,
+                drive_hunter_ai.in_set(InGameSet::EntityUpdates),
+            );
+    }
+}
+
+/// How many of the nearest targets feed a [`HunterBrain`]'s inputs. Kept as a resource (rather
+/// than baked into the network) so an evolved genome and the live game agree on network shape.
+/// Also holds `spawn_chance`, the periodic-spawn-level knob `spawn_nateroid` rolls against to
+/// decide whether a spawn comes in as a [`HunterNateroid`].
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource)]
+pub struct HunterConfig {
+    pub targets_k:    usize,
+    pub spawn_chance: f32,
+}
+
+impl Default for HunterConfig {
+    fn default() -> Self {
+        Self {
+            targets_k:    HUNTER_TARGETS_K,
+            spawn_chance: HUNTER_SPAWN_CHANCE,
+        }
+    }
+}
+
+impl HunterConfig {
+    pub fn layer_sizes(&self) -> Vec<usize> {
+        hunter_layer_sizes(self.targets_k)
+    }
+}
+
+/// `[inputs, hidden, outputs]`: `targets_k` targets x (dx, dy, vx, vy) plus one heading input,
+/// one hidden layer, and 4 outputs (thrust, turn-left, turn-right, behavior flag).
+pub(super) fn hunter_layer_sizes(targets_k: usize) -> Vec<usize> {
+    vec![targets_k * 4 + 1, HUNTER_HIDDEN_SIZE, 4]
+}
+
+/// A minimal feed-forward network: ReLU on every hidden layer, tanh on the output layer. Weights
+/// and biases for each layer are packed flat, in traversal order, so a genome can be shipped as a
+/// plain `Vec<f32>` for crossover/mutation in `hunter_evolve`.
+#[derive(Clone, Debug, Reflect, Serialize, Deserialize)]
+pub struct NeuralNet {
+    pub layer_sizes: Vec<usize>,
+    pub weights:     Vec<f32>,
+}
+
+impl NeuralNet {
+    pub fn weight_count(layer_sizes: &[usize]) -> usize {
+        layer_sizes
+            .windows(2)
+            .map(|pair| pair[0] * pair[1] + pair[1])
+            .sum()
+    }
+
+    pub fn random(layer_sizes: Vec<usize>, rng: &mut RollbackRng) -> Self {
+        let weights = (0..Self::weight_count(&layer_sizes))
+            .map(|_| rng.random_range_f32(-1.0, 1.0))
+            .collect();
+        Self {
+            layer_sizes,
+            weights,
+        }
+    }
+
+    pub fn forward(&self, inputs: &[f32]) -> Vec<f32> {
+        let layer_count = self.layer_sizes.len() - 1;
+        let mut activations = inputs.to_vec();
+        let mut offset = 0;
+
+        for (layer_index, pair) in self.layer_sizes.windows(2).enumerate() {
+            let (input_size, output_size) = (pair[0], pair[1]);
+            let weight_len = input_size * output_size;
+            let weights = &self.weights[offset..offset + weight_len];
+            let biases = &self.weights[offset + weight_len..offset + weight_len + output_size];
+            offset += weight_len + output_size;
+
+            let mut next = vec![0.0; output_size];
+            for (output_index, value) in next.iter_mut().enumerate() {
+                let mut sum = biases[output_index];
+                for (input_index, &input_value) in activations.iter().enumerate() {
+                    sum += input_value * weights[input_index * output_size + output_index];
+                }
+                *value = sum;
+            }
+
+            let is_output_layer = layer_index == layer_count - 1;
+            for value in &mut next {
+                *value = if is_output_layer {
+                    value.tanh()
+                } else {
+                    value.max(0.0)
+                };
+            }
+            activations = next;
+        }
+
+        activations
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct HunterBrain(pub NeuralNet);
+
+impl Deref for HunterBrain {
+    type Target = NeuralNet;
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl DerefMut for HunterBrain {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+}
+
+impl Default for HunterBrain {
+    /// Placeholder network, immediately replaced by `load_or_init_hunter_brain` on spawn - this
+    /// only exists to satisfy `#[require(HunterBrain)]` on [`HunterNateroid`].
+    fn default() -> Self {
+        let layer_sizes = hunter_layer_sizes(HUNTER_TARGETS_K);
+        let weight_count = NeuralNet::weight_count(&layer_sizes);
+        Self(NeuralNet {
+            layer_sizes,
+            weights: vec![0.0; weight_count],
+        })
+    }
+}
+
+/// Marks a [`Nateroid`] as AI-driven: each frame, [`drive_hunter_ai`] overwrites its
+/// `LinearVelocity`/`AngularVelocity` from the attached [`HunterBrain`]'s forward pass instead of
+/// the usual fixed drift nateroids get from `calculate_nateroid_velocity`. Everything else about
+/// spawning and initializing the entity is untouched.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+#[require(Nateroid, HunterBrain)]
+pub struct HunterNateroid;
+
+/// Loads the trained genome from disk if one exists and matches the configured network shape,
+/// otherwise falls back to a randomly initialized brain.
+fn load_or_init_hunter_brain(
+    hunter: On<Add, HunterNateroid>,
+    mut commands: Commands,
+    hunter_config: Res<HunterConfig>,
+    mut rng: ResMut<RollbackRng>,
+) {
+    let layer_sizes = hunter_config.layer_sizes();
+    let brain = load_best_genome()
+        .filter(|genome| genome.layer_sizes == layer_sizes)
+        .unwrap_or_else(|| NeuralNet::random(layer_sizes, &mut rng));
+
+    commands.entity(hunter.entity).insert(HunterBrain(brain));
+}
+
+/// Overrides the plain [`Nateroid`] off-screen marker color `Nateroid`'s own `#[require]`
+/// installed with a hotter one, so players can tell a hunting threat from an ordinary drifting
+/// asteroid before it's even back on screen.
+fn mark_hunter_offscreen_threat(hunter: On<Add, HunterNateroid>, mut commands: Commands) {
+    commands.entity(hunter.entity).insert(OffscreenIndicator {
+        icon_color: Color::from(tailwind::RED_500),
+    });
+}
+
+fn drive_hunter_ai(
+    mut hunters: Query<
+        (&Transform, &mut LinearVelocity, &mut AngularVelocity, &HunterBrain),
+        With<HunterNateroid>,
+    >,
+    targets: Query<(&Transform, &LinearVelocity), With<Spaceship>>,
+    config: Res<NateroidConfig>,
+    hunter_config: Res<HunterConfig>,
+    time: Res<Time>,
+) {
+    let target_list: Vec<(Vec3, Vec3)> = targets
+        .iter()
+        .map(|(transform, velocity)| (transform.translation, velocity.0))
+        .collect();
+
+    if target_list.is_empty() {
+        return;
+    }
+
+    let delta_secs = time.delta_secs();
+
+    for (transform, mut linear_velocity, mut angular_velocity, brain) in &mut hunters {
+        let inputs = local_frame_inputs(
+            transform,
+            linear_velocity.0,
+            &target_list,
+            hunter_config.targets_k,
+        );
+        let outputs = brain.forward(&inputs);
+
+        let thrust = outputs.first().copied().unwrap_or(0.0).max(0.0);
+        let turn_left = outputs.get(1).copied().unwrap_or(0.0);
+        let turn_right = outputs.get(2).copied().unwrap_or(0.0);
+        // outputs[3] is a reserved behavior flag (e.g. future aggressive/evasive toggle)
+
+        // Explicitly enforce 2D rotation, same as the player ship
+        angular_velocity.x = 0.0;
+        angular_velocity.y = 0.0;
+        angular_velocity.z = (turn_right - turn_left) * config.angular_velocity;
+
+        let proposed = linear_velocity.0
+            + transform.forward().as_vec3() * (thrust * config.linear_velocity * delta_secs);
+        let max_speed = config.max_linear_velocity;
+        linear_velocity.0 = if proposed.length() > max_speed {
+            proposed.normalize() * max_speed
+        } else {
+            proposed
+        };
+    }
+}
+
+/// Builds a hunter's network inputs: for the `targets_k` nearest targets, relative position and
+/// velocity in the hunter's local frame (missing targets pad with zeros), followed by the
+/// hunter's own heading.
+pub(super) fn local_frame_inputs(
+    own_transform: &Transform,
+    own_velocity: Vec3,
+    targets: &[(Vec3, Vec3)],
+    targets_k: usize,
+) -> Vec<f32> {
+    let inverse_rotation = own_transform.rotation.inverse();
+    let mut by_distance: Vec<(Vec3, Vec3, f32)> = targets
+        .iter()
+        .map(|&(position, velocity)| {
+            (
+                position,
+                velocity,
+                own_transform.translation.distance_squared(position),
+            )
+        })
+        .collect();
+    by_distance.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+    let mut inputs = Vec::with_capacity(targets_k * 4 + 1);
+    for slot in 0..targets_k {
+        let (local_position, local_velocity) = match by_distance.get(slot) {
+            Some(&(position, velocity, _)) => (
+                inverse_rotation * (position - own_transform.translation),
+                inverse_rotation * (velocity - own_velocity),
+            ),
+            None => (Vec3::ZERO, Vec3::ZERO),
+        };
+        inputs.push(local_position.x);
+        inputs.push(local_position.y);
+        inputs.push(local_velocity.x);
+        inputs.push(local_velocity.y);
+    }
+
+    let forward = own_transform.forward();
+    inputs.push(forward.y.atan2(forward.x));
+    inputs
+}
+
+fn get_app_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|p| p.file_stem().map(|s| s.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "bevy_app".to_string())
+}
+
+fn hunter_genome_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(get_app_name()).join(HUNTER_GENOME_FILENAME))
+}
+
+/// Loads the best trained genome from disk, returning `None` if it doesn't exist or is invalid.
+pub fn load_best_genome() -> Option<NeuralNet> {
+    let path = hunter_genome_path()?;
+    let contents = fs::read_to_string(&path).ok()?;
+    ron::from_str(&contents).ok()
+}
+
+/// Persists a trained genome to disk so normal play can load it on the next run.
+pub fn save_best_genome(genome: &NeuralNet) {
+    let Some(path) = hunter_genome_path() else {
+        warn!("Failed to get hunter genome path");
+        return;
+    };
+
+    if let Some(parent) = path.parent()
+        && let Err(e) = fs::create_dir_all(parent)
+    {
+        warn!("Failed to create config directory: {e}");
+        return;
+    }
+
+    match ron::ser::to_string_pretty(genome, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => {
+            if let Err(e) = fs::write(&path, contents) {
+                warn!("Failed to write hunter genome: {e}");
+            } else {
+                info!("Hunter genome saved to {}", path.display());
+            }
+        },
+        Err(e) => {
+            warn!("Failed to serialize hunter genome: {e}");
+        },
+    }
+}
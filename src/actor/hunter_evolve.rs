@@ -0,0 +1,221 @@
+//! Headless "evolve" mode for training a [`HunterBrain`](super::hunter_ai::HunterBrain): scores a
+//! population of genomes across short simulated episodes and breeds the next generation via
+//! tournament selection, per-weight crossover, and Gaussian mutation. Episodes are simulated with
+//! plain kinematics rather than the live `App`/ECS world, so a full training run doesn't need a
+//! window, physics step, or spawn pipeline - only [`run_evolution`] need be called from a
+//! standalone entry point (e.g. a dev tool invoked with an "evolve" argument).
+use bevy::prelude::*;
+
+use super::constants::HUNTER_ARENA_RADIUS;
+use super::constants::HUNTER_EPISODE_SECONDS;
+use super::constants::HUNTER_GENERATIONS;
+use super::constants::HUNTER_MUTATION_RATE;
+use super::constants::HUNTER_POPULATION_SIZE;
+use super::constants::HUNTER_TARGETS_K;
+use super::constants::HUNTER_TIMESTEP_SECONDS;
+use super::constants::HUNTER_TOURNAMENT_SIZE;
+use super::constants::MAX_NATEROID_LINEAR_VELOCITY;
+use super::constants::NATEROID_ANGULAR_VELOCITY;
+use super::constants::NATEROID_LINEAR_VELOCITY;
+use super::hunter_ai::NeuralNet;
+use super::hunter_ai::hunter_layer_sizes;
+use super::hunter_ai::local_frame_inputs;
+use super::hunter_ai::save_best_genome;
+use crate::rollback::RollbackRng;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EvolveConfig {
+    pub population_size:  usize,
+    pub generations:      usize,
+    pub episode_seconds:  f32,
+    pub timestep_seconds: f32,
+    pub mutation_rate:    f32,
+    pub tournament_size:  usize,
+    pub targets_k:        usize,
+    pub arena_radius:     f32,
+}
+
+impl Default for EvolveConfig {
+    fn default() -> Self {
+        Self {
+            population_size:  HUNTER_POPULATION_SIZE,
+            generations:      HUNTER_GENERATIONS,
+            episode_seconds:  HUNTER_EPISODE_SECONDS,
+            timestep_seconds: HUNTER_TIMESTEP_SECONDS,
+            mutation_rate:    HUNTER_MUTATION_RATE,
+            tournament_size:  HUNTER_TOURNAMENT_SIZE,
+            targets_k:        HUNTER_TARGETS_K,
+            arena_radius:     HUNTER_ARENA_RADIUS,
+        }
+    }
+}
+
+/// Runs the full evolve loop and returns the fittest genome found, persisting it to disk via
+/// [`save_best_genome`] so normal play can load a trained brain.
+pub fn run_evolution(evolve_config: &EvolveConfig, rng: &mut RollbackRng) -> NeuralNet {
+    let layer_sizes = hunter_layer_sizes(evolve_config.targets_k);
+    let mut population: Vec<NeuralNet> = (0..evolve_config.population_size)
+        .map(|_| NeuralNet::random(layer_sizes.clone(), rng))
+        .collect();
+
+    let mut best = population[0].clone();
+    let mut best_fitness = f32::MIN;
+
+    for generation in 0..evolve_config.generations {
+        let fitness: Vec<f32> = population
+            .iter()
+            .map(|genome| simulate_episode(genome, evolve_config, rng))
+            .collect();
+
+        if let Some((index, &score)) = fitness.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1))
+            && score > best_fitness
+        {
+            best_fitness = score;
+            best = population[index].clone();
+        }
+
+        info!("hunter evolve: generation {generation} best fitness {best_fitness:.1}");
+
+        population = breed_next_generation(&population, &fitness, evolve_config, rng);
+    }
+
+    save_best_genome(&best);
+    best
+}
+
+/// Simulates one hunter against a single wandering target and scores it by survival time plus a
+/// proximity reward, ending the episode early if the hunter flies out of the arena.
+fn simulate_episode(genome: &NeuralNet, evolve_config: &EvolveConfig, rng: &mut RollbackRng) -> f32 {
+    let half_arena = evolve_config.arena_radius * 0.5;
+    let mut hunter_transform = Transform::IDENTITY;
+    let mut hunter_velocity = Vec3::ZERO;
+
+    let mut target_position = Vec3::new(
+        rng.random_range_f32(-half_arena, half_arena),
+        rng.random_range_f32(-half_arena, half_arena),
+        0.0,
+    );
+    let wander_speed = NATEROID_LINEAR_VELOCITY * 0.3;
+    let target_velocity = Vec3::new(
+        rng.random_range_f32(-wander_speed, wander_speed),
+        rng.random_range_f32(-wander_speed, wander_speed),
+        0.0,
+    );
+
+    let dt = evolve_config.timestep_seconds;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let steps = (evolve_config.episode_seconds / dt) as u32;
+    let mut fitness = 0.0;
+
+    for _ in 0..steps {
+        target_position += target_velocity * dt;
+
+        let inputs = local_frame_inputs(
+            &hunter_transform,
+            hunter_velocity,
+            &[(target_position, target_velocity)],
+            evolve_config.targets_k,
+        );
+        let outputs = genome.forward(&inputs);
+        let thrust = outputs.first().copied().unwrap_or(0.0).max(0.0);
+        let turn_left = outputs.get(1).copied().unwrap_or(0.0);
+        let turn_right = outputs.get(2).copied().unwrap_or(0.0);
+
+        let turn = (turn_right - turn_left) * NATEROID_ANGULAR_VELOCITY;
+        hunter_transform.rotate_z(turn * dt);
+
+        let proposed = hunter_velocity
+            + hunter_transform.forward().as_vec3() * (thrust * NATEROID_LINEAR_VELOCITY * dt);
+        hunter_velocity = if proposed.length() > MAX_NATEROID_LINEAR_VELOCITY {
+            proposed.normalize() * MAX_NATEROID_LINEAR_VELOCITY
+        } else {
+            proposed
+        };
+        hunter_transform.translation += hunter_velocity * dt;
+
+        if hunter_transform.translation.length() > evolve_config.arena_radius {
+            break;
+        }
+
+        let distance = hunter_transform.translation.distance(target_position);
+        let proximity_reward = 1.0 - (distance / evolve_config.arena_radius).min(1.0);
+        fitness += dt + proximity_reward * dt;
+    }
+
+    fitness
+}
+
+fn breed_next_generation(
+    population: &[NeuralNet],
+    fitness: &[f32],
+    evolve_config: &EvolveConfig,
+    rng: &mut RollbackRng,
+) -> Vec<NeuralNet> {
+    let elite_index = fitness
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+
+    let mut next_generation = vec![population[elite_index].clone()];
+
+    while next_generation.len() < population.len() {
+        let parent_a = tournament_select(population, fitness, evolve_config.tournament_size, rng);
+        let parent_b = tournament_select(population, fitness, evolve_config.tournament_size, rng);
+        let mut child = crossover(parent_a, parent_b, rng);
+        mutate(&mut child, evolve_config.mutation_rate, rng);
+        next_generation.push(child);
+    }
+
+    next_generation
+}
+
+fn tournament_select<'a>(
+    population: &'a [NeuralNet],
+    fitness: &[f32],
+    tournament_size: usize,
+    rng: &mut RollbackRng,
+) -> &'a NeuralNet {
+    (0..tournament_size)
+        .map(|_| rng.random_range(population.len()))
+        .max_by(|&a, &b| fitness[a].total_cmp(&fitness[b]))
+        .map_or(&population[0], |index| &population[index])
+}
+
+/// Per-weight crossover: pick one parent's weight, occasionally averaging the two instead.
+fn crossover(parent_a: &NeuralNet, parent_b: &NeuralNet, rng: &mut RollbackRng) -> NeuralNet {
+    let weights = parent_a
+        .weights
+        .iter()
+        .zip(&parent_b.weights)
+        .map(|(&a, &b)| {
+            if rng.random_range(10) == 0 {
+                (a + b) * 0.5
+            } else if rng.random_bool() {
+                a
+            } else {
+                b
+            }
+        })
+        .collect();
+
+    NeuralNet {
+        layer_sizes: parent_a.layer_sizes.clone(),
+        weights,
+    }
+}
+
+/// Adds `N(0, mutation_rate)` Gaussian noise to each weight.
+fn mutate(genome: &mut NeuralNet, mutation_rate: f32, rng: &mut RollbackRng) {
+    for weight in &mut genome.weights {
+        *weight += gaussian_noise(rng) * mutation_rate;
+    }
+}
+
+/// Box-Muller transform - `RollbackRng` only exposes uniform sampling.
+fn gaussian_noise(rng: &mut RollbackRng) -> f32 {
+    let u1 = rng.random_range_f32(f32::EPSILON, 1.0);
+    let u2 = rng.random_range_f32(0.0, 1.0);
+    (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+}
@@ -0,0 +1,215 @@
+//! Modular ship upgrades. An installed [`Loadout`] of named [`Outfit`]s is folded onto the
+//! spaceship's base stats - mass, health, shield, thrust, steering, and weapon fire rate -
+//! whenever the loadout changes, so the ship's effective config becomes a function of what's
+//! installed rather than one fixed stat block. [`OutfitRegistry`] holds the catalog of outfits
+//! that can be installed, looked up by name.
+use std::collections::HashMap;
+
+use avian3d::prelude::Mass;
+use bevy::prelude::*;
+
+use super::Health;
+use super::actor_config::Shield;
+use super::actor_config::ShieldConfig;
+use super::actor_template::SpaceshipConfig;
+use super::constants::SPACESHIP_OUTFIT_SPACE;
+use super::spaceship::Spaceship;
+use super::spaceship_control::SpaceshipControlConfig;
+use super::weapon::WeaponLoadout;
+
+pub struct LoadoutPlugin;
+
+impl Plugin for LoadoutPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OutfitRegistry>()
+            .init_resource::<BaseShipStats>()
+            .add_systems(Update, apply_loadout);
+    }
+}
+
+/// A single installable part: its resource cost (`mass`, `space`) and what it contributes to the
+/// ship's effective stats.
+#[derive(Debug, Clone)]
+pub struct Outfit {
+    pub name:            String,
+    pub mass:            f32,
+    pub space:           f32,
+    pub thrust_bonus:    f32,
+    pub steering_bonus:  f32,
+    pub fire_rate_bonus: f32,
+    pub shield_bonus:    f32,
+    pub health_bonus:    f32,
+}
+
+/// Catalog of outfits installable by name.
+#[derive(Resource, Debug, Clone)]
+pub struct OutfitRegistry {
+    outfits: HashMap<String, Outfit>,
+}
+
+impl OutfitRegistry {
+    pub fn get(&self, name: &str) -> Option<&Outfit> { self.outfits.get(name) }
+}
+
+impl Default for OutfitRegistry {
+    fn default() -> Self {
+        let outfits = [
+            Outfit {
+                name:            "reinforced_hull".to_string(),
+                mass:            20.0,
+                space:           2.0,
+                thrust_bonus:    0.0,
+                steering_bonus:  0.0,
+                fire_rate_bonus: 0.0,
+                shield_bonus:    0.0,
+                health_bonus:    500.0,
+            },
+            Outfit {
+                name:            "afterburner".to_string(),
+                mass:            5.0,
+                space:           2.0,
+                thrust_bonus:    40.0,
+                steering_bonus:  0.0,
+                fire_rate_bonus: 0.0,
+                shield_bonus:    0.0,
+                health_bonus:    0.0,
+            },
+            Outfit {
+                name:            "maneuvering_thrusters".to_string(),
+                mass:            3.0,
+                space:           1.0,
+                thrust_bonus:    0.0,
+                steering_bonus:  2.0,
+                fire_rate_bonus: 0.0,
+                shield_bonus:    0.0,
+                health_bonus:    0.0,
+            },
+            Outfit {
+                name:            "rapid_loader".to_string(),
+                mass:            4.0,
+                space:           2.0,
+                thrust_bonus:    0.0,
+                steering_bonus:  0.0,
+                fire_rate_bonus: 0.03,
+                shield_bonus:    0.0,
+                health_bonus:    0.0,
+            },
+            Outfit {
+                name:            "shield_booster".to_string(),
+                mass:            8.0,
+                space:           2.0,
+                thrust_bonus:    0.0,
+                steering_bonus:  0.0,
+                fire_rate_bonus: 0.0,
+                shield_bonus:    150.0,
+                health_bonus:    0.0,
+            },
+        ];
+
+        Self {
+            outfits: outfits
+                .into_iter()
+                .map(|outfit| (outfit.name.clone(), outfit))
+                .collect(),
+        }
+    }
+}
+
+/// Outfits installed on an actor, in install order. Folded onto the actor's base stats by
+/// [`apply_loadout`] whenever this changes.
+#[derive(Component, Debug, Clone, Default)]
+pub struct Loadout(pub Vec<Outfit>);
+
+/// Snapshot of the spaceship's unmodified base stats, captured the first time a loadout is
+/// applied so later re-derivations fold onto the original numbers instead of compounding onto
+/// whatever the previous loadout already wrote into the shared config resources.
+#[derive(Resource, Default)]
+struct BaseShipStats(Option<ShipStats>);
+
+struct ShipStats {
+    mass:               f32,
+    health:             f32,
+    shield:             Option<ShieldConfig>,
+    acceleration:       f32,
+    rotation_speed:     f32,
+    fire_interval_secs: f32,
+    /// Bonuses folded in by the previously-applied loadout, so a re-derivation can adjust the
+    /// live entity's current health/shield by the *change* in bonus rather than resetting them.
+    applied_health_bonus: f32,
+    applied_shield_bonus: f32,
+}
+
+fn apply_loadout(
+    mut base_stats: ResMut<BaseShipStats>,
+    mut spaceship_config: ResMut<SpaceshipConfig>,
+    mut spaceship_control: ResMut<SpaceshipControlConfig>,
+    mut weapon_loadout: ResMut<WeaponLoadout>,
+    loadouts: Query<(Entity, &Loadout), (With<Spaceship>, Changed<Loadout>)>,
+    mut ships: Query<(&mut Mass, &mut Health, Option<&mut Shield>), With<Spaceship>>,
+) {
+    for (loadout_entity, loadout) in &loadouts {
+        if base_stats.0.is_none() {
+            base_stats.0 = Some(ShipStats {
+                mass:                 spaceship_config.mass,
+                health:               spaceship_config.health,
+                shield:               spaceship_config.shield,
+                acceleration:         spaceship_control.acceleration,
+                rotation_speed:       spaceship_control.rotation_speed,
+                fire_interval_secs:   weapon_loadout.active_slot().interval(),
+                applied_health_bonus: 0.0,
+                applied_shield_bonus: 0.0,
+            });
+        }
+        let base = base_stats.0.as_mut().expect("just inserted above");
+
+        let mut used_space = 0.0;
+        let mut extra_mass = 0.0;
+        let mut extra_thrust = 0.0;
+        let mut extra_steering = 0.0;
+        let mut extra_fire_rate = 0.0;
+        let mut extra_shield = 0.0;
+        let mut extra_health = 0.0;
+
+        for outfit in &loadout.0 {
+            if used_space + outfit.space > SPACESHIP_OUTFIT_SPACE {
+                // doesn't fit in remaining space - skip, keep checking later outfits
+                continue;
+            }
+            used_space += outfit.space;
+            extra_mass += outfit.mass;
+            extra_thrust += outfit.thrust_bonus;
+            extra_steering += outfit.steering_bonus;
+            extra_fire_rate += outfit.fire_rate_bonus;
+            extra_shield += outfit.shield_bonus;
+            extra_health += outfit.health_bonus;
+        }
+
+        let effective_mass = base.mass + extra_mass;
+        let effective_health = base.health + extra_health;
+        let effective_shield = base.shield.map(|shield| ShieldConfig {
+            max: shield.max + extra_shield,
+            ..shield
+        });
+        let effective_fire_interval = (base.fire_interval_secs - extra_fire_rate).max(0.01);
+
+        spaceship_config.mass = effective_mass;
+        spaceship_config.health = effective_health;
+        spaceship_config.shield = effective_shield;
+        spaceship_control.acceleration = base.acceleration + extra_thrust;
+        spaceship_control.rotation_speed = base.rotation_speed + extra_steering;
+        weapon_loadout.active_slot_mut().set_interval(effective_fire_interval);
+
+        if let Ok((mut mass, mut health, shield)) = ships.get_mut(loadout_entity) {
+            mass.0 = effective_mass;
+            health.0 += extra_health - base.applied_health_bonus;
+            if let (Some(mut shield), Some(effective_shield)) = (shield, effective_shield) {
+                shield.max = effective_shield.max;
+                shield.current =
+                    (shield.current + extra_shield - base.applied_shield_bonus).min(shield.max);
+            }
+        }
+
+        base.applied_health_bonus = extra_health;
+        base.applied_shield_bonus = extra_shield;
+    }
+}
@@ -1,37 +1,149 @@
+use std::time::Duration;
+
 use avian3d::prelude::*;
+use bevy::camera::visibility::RenderLayers;
+use bevy::color::palettes::tailwind;
 use bevy::prelude::*;
+use bevy_inspector_egui::inspector_options::std_options::NumberDisplay;
+use bevy_inspector_egui::prelude::*;
+use bevy_inspector_egui::quick::ResourceInspectorPlugin;
 use leafwing_input_manager::prelude::*;
 
+use super::constants::WEAPON_ENERGY_COST_PER_SHOT;
+use super::constants::WEAPON_ENERGY_MAX;
+use super::constants::WEAPON_ENERGY_REGEN_PER_SEC;
+use crate::actor::Nateroid;
+use crate::actor::PreviousPosition;
 use crate::actor::Teleporter;
-use crate::actor::actor_config::ActorConfig;
 use crate::actor::actor_config::LOCKED_AXES_2D;
 use crate::actor::actor_config::insert_configured_components;
 use crate::actor::actor_template::MissileConfig;
 use crate::actor::spaceship::ContinuousFire;
+use crate::actor::spaceship::RecoilBudget;
 use crate::actor::spaceship::Spaceship;
+use crate::actor::spaceship::SpaceshipRecoiled;
 use crate::actor::spaceship_control::SpaceshipControl;
+use crate::actor::weapon::FiredWeapon;
+use crate::actor::weapon::WeaponLoadout;
+use crate::actor::weapon::WeaponSlot;
+use crate::actor::weapon::cycle_weapon;
+use crate::actor::weapon::spread_offsets;
+use crate::camera::RenderLayer;
+use crate::game_input::GameAction;
+use crate::game_input::toggle_active;
 use crate::playfield::ActorPortals;
 use crate::playfield::Boundary;
+use crate::playfield::WraparoundGhosts;
 use crate::schedule::InGameSet;
 
 pub struct MissilePlugin;
 
 impl Plugin for MissilePlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(initialize_missile)
-            .add_systems(Update, fire_missile.in_set(InGameSet::UserInput))
+        app.init_resource::<WeaponEnergyConfig>()
+            .init_resource::<WeaponEnergy>()
+            .init_resource::<MissileLightConfig>()
+            .add_plugins(
+                ResourceInspectorPlugin::<MissileLightConfig>::default()
+                    .run_if(toggle_active(false, GameAction::MissileLightInspector)),
+            )
+            .add_observer(initialize_missile)
+            // the fire-rate timer is part of the deterministic simulation, so it
+            // ticks on FixedUpdate alongside Avian's physics step, not Update.
+            // Ordered after `cycle_weapon` (registered by `WeaponPlugin`) so switching
+            // weapons takes effect the same frame it's fired.
+            .add_systems(
+                FixedUpdate,
+                fire_missile.after(cycle_weapon).in_set(InGameSet::UserInput),
+            )
+            .add_systems(
+                FixedUpdate,
+                (missile_guidance, update_missile_lights).in_set(InGameSet::EntityUpdates),
+            )
             .add_systems(Update, missile_movement.in_set(InGameSet::EntityUpdates));
     }
 }
 
+/// Inspector-tunable appearance for the [`PointLight`] every [`Missile`] carries, mirroring
+/// `SpotLightConfig`'s shape. `intensity` is the light's value at full `total_distance` remaining;
+/// `update_missile_lights` fades it toward zero as the missile nears the end of its flight.
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct MissileLightConfig {
+    pub color:     Color,
+    #[inspector(min = 0.0, max = 50_000.0, display = NumberDisplay::Slider)]
+    pub intensity: f32,
+    #[inspector(min = 0.0, max = 500.0, display = NumberDisplay::Slider)]
+    pub range:     f32,
+}
+
+impl Default for MissileLightConfig {
+    fn default() -> Self {
+        Self {
+            color:     Color::from(tailwind::ORANGE_400),
+            intensity: 10_000.0,
+            range:     150.0,
+        }
+    }
+}
+
+/// Inspector-tunable energy budget for firing missiles, backing [`WeaponEnergy`]. Mirrors the
+/// shape of `ShieldConfig`'s regen-over-time numbers, but gates shots instead of absorbing
+/// damage.
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource)]
+pub struct WeaponEnergyConfig {
+    #[inspector(min = 0.0, max = 500.0, display = NumberDisplay::Slider)]
+    pub max:           f32,
+    #[inspector(min = 0.0, max = 100.0, display = NumberDisplay::Slider)]
+    pub cost_per_shot: f32,
+    #[inspector(min = 0.0, max = 100.0, display = NumberDisplay::Slider)]
+    pub regen_per_sec: f32,
+}
+
+impl Default for WeaponEnergyConfig {
+    fn default() -> Self {
+        Self {
+            max:           WEAPON_ENERGY_MAX,
+            cost_per_shot: WEAPON_ENERGY_COST_PER_SHOT,
+            regen_per_sec: WEAPON_ENERGY_REGEN_PER_SEC,
+        }
+    }
+}
+
+/// Energy available to fire missiles, regenerating continuously at
+/// `WeaponEnergyConfig::regen_per_sec`. `should_fire` refuses to spawn a missile - without
+/// ticking the spawn timer toward a shot - whenever `current` can't cover `cost_per_shot`, so
+/// continuous-fire mode is meaningfully limited rather than purely timer-gated.
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct WeaponEnergy {
+    pub current: f32,
+}
+
+impl Default for WeaponEnergy {
+    fn default() -> Self {
+        Self {
+            current: WEAPON_ENERGY_MAX,
+        }
+    }
+}
+
 // todo: #rustquestion - how can i make it so that new has to be used and
 // DrawDirection isn't constructed directly - i still need the fields visible
+/// Requiring `PreviousPosition` opts missiles into `collision_detection::sweep_for_tunneling`,
+/// which shape-casts from the previous frame's position to the current one - fast missiles
+/// already get continuous collision against asteroids for free, with no group-specific raycast
+/// needed here. `MissilePosition::traveled_distance` vs. `total_distance` is the existing
+/// max-range despawn.
 #[derive(Component, Reflect, Copy, Clone, Debug)]
 #[reflect(Component)]
 #[require(
     Teleporter,
     ActorPortals,
+    WraparoundGhosts,
     CollisionEventsEnabled,
+    PreviousPosition,
     RigidBody::Dynamic,
     LockedAxes = LOCKED_AXES_2D
 )]
@@ -59,27 +171,51 @@ impl MissilePosition {
     }
 }
 
+/// Homing state for a missile fired from a [`WeaponSlot`] with `guided` set. The locked target
+/// is re-acquired whenever it's `None` or has despawned, and left alone otherwise so guidance
+/// keeps tracking the same `Nateroid`. `acquisition_range`/`max_turn_rate` are copied from the
+/// firing slot at spawn time, so a homing torpedo and a (hypothetical) homing missile can behave
+/// differently even while both carry this component.
+#[derive(Component, Reflect, Copy, Clone, Debug)]
+#[reflect(Component)]
+pub struct MissileGuidance {
+    pub target:            Option<Entity>,
+    pub acquisition_range: f32,
+    pub max_turn_rate:     f32,
+}
+
+impl MissileGuidance {
+    fn new(acquisition_range: f32, max_turn_rate: f32) -> Self {
+        Self {
+            target: None,
+            acquisition_range,
+            max_turn_rate,
+        }
+    }
+}
+
 /// Logic to handle whether we're in continuous fire mode or just regular fire
 /// mode if continuous we want to make sure that enough time has passed and that
 /// we're holding down the fire button
 fn should_fire(
     continuous_fire: Option<&ContinuousFire>,
-    missile_config: &mut ActorConfig,
-    time: Res<Time>,
+    spawnable: bool,
+    slot: &mut WeaponSlot,
+    weapon_energy: &WeaponEnergy,
+    weapon_energy_config: &WeaponEnergyConfig,
+    delta: Duration,
     fire_button: Single<&ActionState<SpaceshipControl>>,
 ) -> bool {
-    if !missile_config.spawnable {
+    if !spawnable {
+        return false;
+    }
+
+    if weapon_energy.current < weapon_energy_config.cost_per_shot {
         return false;
     }
 
     if continuous_fire.is_some() {
-        // We know the timer exists, so we can safely unwrap it
-        let timer = missile_config
-            .spawn_timer
-            .as_mut()
-            .expect("configure missile spawn timer here: impl Default for InitialEnsembleConfig");
-        timer.tick(time.delta());
-        if !timer.just_finished() {
+        if !slot.tick_spawn_timer(delta) {
             return false;
         }
 
@@ -94,20 +230,28 @@ fn initialize_missile(
     mut commands: Commands,
     boundary: Res<Boundary>,
     mut config: ResMut<MissileConfig>,
+    missile_light_config: Res<MissileLightConfig>,
+    fired_weapons: Query<&FiredWeapon>,
     transform_and_linvel: Single<(&Transform, &LinearVelocity), With<Spaceship>>,
+    nateroids: Query<(&Transform, &LinearVelocity), (With<Nateroid>, Without<Spaceship>)>,
 ) {
+    let Ok(fired_weapon) = fired_weapons.get(missile.entity) else {
+        return;
+    };
+
     let missile_position = MissilePosition::new(boundary.max_missile_distance());
 
     let (spaceship_transform, spaceship_velocity) = *transform_and_linvel;
 
-    let transform = initialize_transform(spaceship_transform, &config);
+    let transform =
+        initialize_transform(spaceship_transform, fired_weapon, &config, &nateroids);
 
-    // Calculate velocity: forward direction * base_velocity + spaceship velocity
-    let (linear_velocity, angular_velocity) = calculate_missile_velocity(
-        spaceship_transform,
-        spaceship_velocity,
-        config.base_velocity,
-    );
+    // Calculate velocity: forward direction * base_velocity + spaceship velocity.
+    // Uses the missile's own spawn rotation (straight-ahead, or lead-aimed) rather
+    // than the spaceship's, so a lead-aimed missile actually flies toward the
+    // intercept point instead of just looking at it.
+    let (linear_velocity, angular_velocity) =
+        calculate_missile_velocity(&transform, spaceship_velocity, fired_weapon.base_velocity);
 
     commands
         .entity(missile.entity)
@@ -116,48 +260,206 @@ fn initialize_missile(
         .insert(linear_velocity)
         .insert(angular_velocity);
 
+    if fired_weapon.guided {
+        commands.entity(missile.entity).insert(MissileGuidance::new(
+            fired_weapon.acquisition_range,
+            fired_weapon.max_turn_rate,
+        ));
+    }
+
     insert_configured_components(&mut commands, &mut config.actor_config, missile.entity);
+
+    commands.spawn((
+        PointLight {
+            color: missile_light_config.color,
+            intensity: missile_light_config.intensity,
+            range: missile_light_config.range,
+            shadows_enabled: false,
+            ..default()
+        },
+        Transform::IDENTITY,
+        RenderLayers::from_layers(RenderLayer::Game.layers()),
+        ChildOf(missile.entity),
+        Name::new("MissileLight"),
+    ));
 }
 
 fn initialize_transform(
     spaceship_transform: &Transform,
+    fired_weapon: &FiredWeapon,
     missile_config: &MissileConfig,
+    nateroids: &Query<(&Transform, &LinearVelocity), (With<Nateroid>, Without<Spaceship>)>,
 ) -> Transform {
-    // Calculate transform and velocity from spaceship position
-    let forward = -spaceship_transform.forward();
+    // A spread weapon fires several missiles per shot, fanned out by yaw around the spaceship's
+    // own turn axis (Z - the only rotation axis spaceship isn't locked on), so compute each
+    // missile's spawn geometry against a copy of the spaceship transform pre-rotated by its
+    // share of the spread instead of threading the offset through every formula below.
+    let spread_transform = spaceship_transform.with_rotation(
+        Quat::from_rotation_z(fired_weapon.yaw_offset) * spaceship_transform.rotation,
+    );
+
+    let forward = -spread_transform.forward();
     let spawn_position =
-        spaceship_transform.translation + forward * missile_config.forward_distance_scalar;
+        spread_transform.translation + forward * fired_weapon.forward_distance_scalar;
 
     // Combine rotations: spaceship rotation * missile config rotation
-    let combined_rotation =
-        spaceship_transform.rotation * missile_config.actor_config.transform.rotation;
+    let lead_rotation = fired_weapon
+        .lead_targeting
+        .then(|| lead_aim_rotation(spawn_position, fired_weapon, nateroids))
+        .flatten();
+
+    let combined_rotation = lead_rotation.unwrap_or_else(|| {
+        spread_transform.rotation * missile_config.actor_config.transform.rotation
+    });
 
     Transform::from_translation(spawn_position)
         .with_rotation(combined_rotation)
         .with_scale(missile_config.actor_config.transform.scale)
 }
 
+/// Solves the classic fixed-gun lead-intercept problem against the nearest
+/// `Nateroid` in range: given shooter position `shooter_pos`, target position
+/// `p_t`, target velocity `v_t`, and projectile speed `s`, solves
+/// `(v_t·v_t − s²)·t² + 2(d·v_t)·t + d·d = 0` where `d = p_t − shooter_pos`
+/// for the smallest positive root `t`, then orients toward `p_t + v_t·t`.
+/// Returns `None` (falls back to straight-ahead firing) when there's no
+/// target in range or the target outruns the projectile.
+fn lead_aim_rotation(
+    shooter_pos: Vec3,
+    fired_weapon: &FiredWeapon,
+    nateroids: &Query<(&Transform, &LinearVelocity), (With<Nateroid>, Without<Spaceship>)>,
+) -> Option<Quat> {
+    let max_range_sq = fired_weapon.max_lock_range * fired_weapon.max_lock_range;
+
+    let (target_pos, target_vel) = nateroids
+        .iter()
+        .map(|(transform, velocity)| (transform.translation, velocity.0))
+        .filter(|(pos, _)| shooter_pos.distance_squared(*pos) <= max_range_sq)
+        .min_by(|(a, _), (b, _)| {
+            shooter_pos
+                .distance_squared(*a)
+                .total_cmp(&shooter_pos.distance_squared(*b))
+        })?;
+
+    let s = fired_weapon.base_velocity;
+    let d = target_pos - shooter_pos;
+
+    let a = target_vel.dot(target_vel) - s * s;
+    let b = 2.0 * d.dot(target_vel);
+    let c = d.dot(d);
+
+    let t = if a.abs() < f32::EPSILON {
+        // Degenerate (target speed == projectile speed): linear solve.
+        if b.abs() < f32::EPSILON {
+            return None;
+        }
+        -c / b
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrt_disc = discriminant.sqrt();
+        let t1 = (-b + sqrt_disc) / (2.0 * a);
+        let t2 = (-b - sqrt_disc) / (2.0 * a);
+        [t1, t2]
+            .into_iter()
+            .filter(|t| *t > 0.0)
+            .min_by(f32::total_cmp)?
+    };
+
+    if t <= 0.0 {
+        return None;
+    }
+
+    let aim_point = target_pos + target_vel * t;
+    let direction = (aim_point - shooter_pos).try_normalize()?;
+    Some(Transform::default().looking_to(-direction, Vec3::Y).rotation)
+}
+
+#[allow(clippy::type_complexity)]
 fn fire_missile(
     mut commands: Commands,
-    q_spaceship: Query<Option<&ContinuousFire>, With<Spaceship>>,
-    mut missile_config: ResMut<MissileConfig>,
+    mut q_spaceship: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&ContinuousFire>,
+            &mut ExternalImpulse,
+            &mut RecoilBudget,
+        ),
+        With<Spaceship>,
+    >,
+    missile_config: Res<MissileConfig>,
+    mut weapon_loadout: ResMut<WeaponLoadout>,
+    weapon_energy_config: Res<WeaponEnergyConfig>,
+    mut weapon_energy: ResMut<WeaponEnergy>,
     fire_button: Single<&ActionState<SpaceshipControl>>,
     time: Res<Time>,
 ) {
-    let Ok(continuous_fire_enabled) = q_spaceship.single() else {
+    let Ok((
+        spaceship_entity,
+        spaceship_transform,
+        continuous_fire_enabled,
+        mut impulse,
+        mut recoil_budget,
+    )) = q_spaceship.single_mut()
+    else {
         return;
     };
 
+    weapon_energy.current = (weapon_energy.current
+        + weapon_energy_config.regen_per_sec * time.delta_secs())
+    .min(weapon_energy_config.max);
+
+    recoil_budget.current =
+        (recoil_budget.current + recoil_budget.regen_per_sec * time.delta_secs())
+            .min(recoil_budget.max);
+
+    let spawnable = missile_config.spawnable;
+    let delta = time.delta();
+    let slot = weapon_loadout.active_slot_mut();
+
     if !should_fire(
         continuous_fire_enabled,
-        &mut missile_config,
-        time,
+        spawnable,
+        slot,
+        &weapon_energy,
+        &weapon_energy_config,
+        delta,
         fire_button,
     ) {
         return;
     }
 
-    commands.spawn((Missile, Name::new("Missile")));
+    weapon_energy.current -= weapon_energy_config.cost_per_shot;
+
+    for yaw_offset in spread_offsets(slot.spawn_count, slot.spread_angle) {
+        commands.spawn((
+            Missile,
+            Name::new(slot.name.clone()),
+            FiredWeapon::from_slot(slot, yaw_offset),
+        ));
+    }
+
+    // Recoil pushes the ship opposite the direction missiles were just fired in, scaled by the
+    // weapon's momentum (missile mass * its launch speed) and the number spawned this shot.
+    // Spent from `recoil_budget` rather than applied in full, so rapid continuous fire can't
+    // keep stacking impulses once the budget runs dry.
+    let momentum = missile_config.mass * slot.base_velocity * slot.spawn_count as f32;
+    let recoil_applied = momentum.min(recoil_budget.current);
+    recoil_budget.current -= recoil_applied;
+
+    if recoil_applied > 0.0 {
+        let mut kick = spaceship_transform.forward() * recoil_applied;
+        kick.z = 0.0; // stay in the plane spaceship's LOCKED_AXES_SPACESHIP allows
+        impulse.apply_impulse(kick);
+
+        commands.trigger(SpaceshipRecoiled {
+            entity:    spaceship_entity,
+            magnitude: recoil_applied,
+        });
+    }
 }
 
 /// we update missile movement so that it can be despawned after it has traveled
@@ -189,12 +491,108 @@ fn missile_movement(mut query: Query<(&Transform, &mut MissilePosition, &Telepor
     }
 }
 
+/// Curves each guided missile toward the nearest `Nateroid` within its
+/// [`MissileGuidance::acquisition_range`], re-acquiring a target whenever the locked one is
+/// `None` or has despawned. Rotation is clamped to `MissileGuidance::max_turn_rate` via
+/// `Quat::slerp` so a lock doesn't snap the missile onto its target in a single tick, and
+/// `linear_velocity` is re-oriented to the post-turn forward direction at its existing speed so
+/// `missile_movement`'s distance accounting (measured from actual frame-to-frame displacement)
+/// keeps working unchanged on the curved path. Missiles with no target in range fly straight,
+/// same as today.
+fn missile_guidance(
+    time: Res<Time>,
+    mut missiles: Query<(&Transform, &mut LinearVelocity, &mut AngularVelocity, &mut MissileGuidance)>,
+    nateroids: Query<(Entity, &Transform), With<Nateroid>>,
+) {
+    let delta_secs = time.delta_secs();
+
+    for (transform, mut linear_velocity, mut angular_velocity, mut guidance) in &mut missiles {
+        let acquisition_range_sq = guidance.acquisition_range * guidance.acquisition_range;
+
+        let target_still_alive = guidance
+            .target
+            .is_some_and(|target| nateroids.get(target).is_ok());
+
+        if !target_still_alive {
+            guidance.target = nateroids
+                .iter()
+                .map(|(entity, target_transform)| {
+                    (
+                        entity,
+                        transform
+                            .translation
+                            .distance_squared(target_transform.translation),
+                    )
+                })
+                .filter(|(_, distance_sq)| *distance_sq <= acquisition_range_sq)
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(entity, _)| entity);
+        }
+
+        let target_position = guidance
+            .target
+            .and_then(|target| nateroids.get(target).ok())
+            .map(|(_, target_transform)| target_transform.translation);
+
+        let Some(target_position) = target_position else {
+            angular_velocity.0 = Vec3::ZERO;
+            continue;
+        };
+
+        let current_forward = -transform.forward();
+        let Ok(desired_forward) = Dir3::new(target_position - transform.translation) else {
+            continue;
+        };
+
+        let angle_to_target = current_forward.angle_between(*desired_forward);
+        if angle_to_target < f32::EPSILON {
+            angular_velocity.0 = Vec3::ZERO;
+            continue;
+        }
+
+        let max_turn = guidance.max_turn_rate * delta_secs;
+        let t = (max_turn / angle_to_target).min(1.0);
+
+        let rotation_to_target = Quat::from_rotation_arc(*current_forward, *desired_forward);
+        let new_forward = Quat::IDENTITY.slerp(rotation_to_target, t) * *current_forward;
+
+        let rotation_axis = current_forward.cross(*desired_forward).normalize_or_zero();
+        angular_velocity.0 = rotation_axis * (t * angle_to_target / delta_secs.max(f32::EPSILON));
+
+        let speed = linear_velocity.0.length();
+        linear_velocity.0 = new_forward * speed;
+    }
+}
+
+/// Fades each missile's child [`PointLight`] from `MissileLightConfig::intensity` down to zero as
+/// it nears the end of its flight, so the trail visibly dims instead of cutting out abruptly when
+/// the missile despawns.
+fn update_missile_lights(
+    missile_light_config: Res<MissileLightConfig>,
+    missiles: Query<(&MissilePosition, &Children), With<Missile>>,
+    mut lights: Query<&mut PointLight>,
+) {
+    for (position, children) in &missiles {
+        let ratio = if position.total_distance > 0.0 {
+            (1.0 - position.traveled_distance / position.total_distance).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        for &child in children {
+            if let Ok(mut light) = lights.get_mut(child) {
+                light.intensity = missile_light_config.intensity * ratio;
+            }
+        }
+    }
+}
+
 fn calculate_missile_velocity(
-    spaceship_transform: &Transform,
+    missile_transform: &Transform,
     spaceship_velocity: &LinearVelocity,
     base_velocity: f32,
 ) -> (LinearVelocity, AngularVelocity) {
-    let forward = -spaceship_transform.forward();
+    let forward = -missile_transform.forward();
     let mut velocity = forward * base_velocity;
     velocity += **spaceship_velocity;
     (LinearVelocity(velocity), AngularVelocity::ZERO)
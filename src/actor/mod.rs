@@ -1,33 +1,85 @@
 mod aabb;
 mod actor_config;
+mod actor_content;
 mod actor_template;
+mod collapse;
 mod collision_detection;
 mod constants;
+mod effects;
+mod effects_content;
+mod g_force;
+mod hunter_ai;
+mod hunter_evolve;
+mod loadout;
 mod missile;
 mod nateroid;
 mod spaceship;
 mod spaceship_control;
+mod spaceship_diagnostics;
 mod teleport;
+mod thrust_particles;
+mod weapon;
 
 pub use aabb::Aabb;
 use aabb::AabbPlugin;
+pub use actor_config::ActorConfig;
 use actor_config::ActorConfigPlugin;
 pub use actor_config::Health;
+pub use actor_config::create_spawn_timer;
 pub use actor_template::DeathCorner;
+pub use actor_template::DeathEasing;
+pub use actor_template::MissileConfig;
 pub use actor_template::NateroidConfig;
 use bevy::prelude::*;
+pub use collapse::CollapseEvent;
+use collapse::CollapsePlugin;
+pub use collapse::CollapseSequence;
+pub use collapse::EffectSpec;
 use collision_detection::CollisionDetectionPlugin;
+pub use collision_detection::PreviousPosition;
+use effects::EffectPlugin;
+pub use g_force::GForce;
+use g_force::GForcePlugin;
+pub use hunter_ai::HunterBrain;
+pub use hunter_ai::HunterConfig;
+pub use hunter_ai::HunterNateroid;
+use hunter_ai::HunterAiPlugin;
+pub use hunter_evolve::EvolveConfig;
+pub use hunter_evolve::run_evolution;
+pub use loadout::Loadout;
+use loadout::LoadoutPlugin;
+pub use loadout::Outfit;
+pub use loadout::OutfitRegistry;
 use missile::MissilePlugin;
+pub use missile::MissileGuidance;
 pub use missile::MissilePosition;
 pub use nateroid::Deaderoid;
+pub use nateroid::Fragment;
 pub use nateroid::Nateroid;
+pub use nateroid::NateroidArea;
 pub use nateroid::NateroidDeathMaterials;
 use nateroid::NateroidPlugin;
+pub use nateroid::NateroidSize;
 pub use nateroid::NateroidSpawnStats;
+pub use nateroid::spawn_fragments;
+pub use spaceship::RecoilBudget;
+pub use spaceship::ShipState;
+pub use spaceship::Spaceship;
 use spaceship::SpaceshipPlugin;
+pub use spaceship::SpaceshipRecoiled;
+pub use spaceship::SpaceshipSpawnBuffer;
+pub use spaceship_control::SpaceshipControl;
+pub use spaceship_control::SpaceshipControlConfig;
 use spaceship_control::SpaceshipControlPlugin;
+use spaceship_diagnostics::SpaceshipDiagnosticsPlugin;
 use teleport::TeleportPlugin;
 pub use teleport::Teleporter;
+use thrust_particles::ThrustParticlePlugin;
+pub use weapon::FiredWeapon;
+pub use weapon::WeaponConfig;
+pub use weapon::WeaponLoadout;
+pub use weapon::WeaponSlot;
+use weapon::WeaponPlugin;
 
 pub struct ActorPlugin;
 
@@ -35,11 +87,19 @@ impl Plugin for ActorPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(AabbPlugin)
             .add_plugins(ActorConfigPlugin)
+            .add_plugins(CollapsePlugin)
             .add_plugins(CollisionDetectionPlugin)
+            .add_plugins(EffectPlugin)
+            .add_plugins(GForcePlugin)
+            .add_plugins(HunterAiPlugin)
+            .add_plugins(LoadoutPlugin)
             .add_plugins(MissilePlugin)
             .add_plugins(NateroidPlugin)
             .add_plugins(SpaceshipPlugin)
             .add_plugins(SpaceshipControlPlugin)
-            .add_plugins(TeleportPlugin);
+            .add_plugins(SpaceshipDiagnosticsPlugin)
+            .add_plugins(TeleportPlugin)
+            .add_plugins(ThrustParticlePlugin)
+            .add_plugins(WeaponPlugin);
     }
 }
@@ -1,24 +1,33 @@
+use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::ops::Range;
+use std::time::Duration;
 
 use avian3d::prelude::*;
 use bevy::camera::visibility::RenderLayers;
+use bevy::color::palettes::tailwind;
 use bevy::prelude::*;
-use rand::Rng;
 
+use super::HunterConfig;
+use super::HunterNateroid;
 use super::Teleporter;
 use super::actor_config::Health;
 use super::actor_config::LOCKED_AXES_2D;
 use super::actor_config::insert_configured_components;
 use super::actor_template::GameLayer;
 use super::actor_template::NateroidConfig;
+use crate::actor::PreviousPosition;
+use crate::anim_automaton::AnimAutomaton;
 use crate::asset_loader;
 use crate::asset_loader::SceneAssets;
+use crate::camera::OffscreenIndicator;
 use crate::despawn::despawn;
 use crate::game_input::GameAction;
 use crate::game_input::just_pressed;
 use crate::playfield::ActorPortals;
 use crate::playfield::Boundary;
+use crate::playfield::WraparoundGhosts;
+use crate::rollback::RollbackRng;
 use crate::schedule::InGameSet;
 use crate::traits::TransformExt;
 use crate::traits::UsizeExt;
@@ -26,6 +35,129 @@ use crate::traits::UsizeExt;
 // half the size of the boundary and only in the x,y plane
 const SPAWN_WINDOW: Vec3 = Vec3::new(0.5, 0.5, 0.0);
 
+/// Summed [`NateroidSize::area_weight`] of every live nateroid, kept current by
+/// `track_nateroid_area_added`/`track_nateroid_area_removed`. `spawn_nateroid` only requests a
+/// new spawn while this is below [`NateroidConfig::spawn_area_budget`], so the timer just paces
+/// how often the budget is rechecked rather than forcing a fixed spawn rate.
+#[derive(Resource, Default)]
+pub struct NateroidArea(pub f32);
+
+/// A nateroid's size class, chosen on spawn and weighted toward [`NateroidArea`] so a handful of
+/// large asteroids and a crowd of small ones both count toward the same budget sensibly.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component)]
+pub enum NateroidSize {
+    Large,
+    Medium,
+    Small,
+}
+
+impl NateroidSize {
+    const ALL: [NateroidSize; 3] = [NateroidSize::Large, NateroidSize::Medium, NateroidSize::Small];
+
+    /// Contribution to [`NateroidArea`] - large=4, medium=2, small=1.
+    pub fn area_weight(self) -> f32 {
+        match self {
+            NateroidSize::Large => 4.0,
+            NateroidSize::Medium => 2.0,
+            NateroidSize::Small => 1.0,
+        }
+    }
+
+    /// Scale multiplier applied on top of [`NateroidConfig`]'s base scale. Derived from
+    /// `area_weight` (scale ~ sqrt(area)) so a large nateroid actually looks bigger, not just
+    /// heavier on the budget.
+    fn scale_multiplier(self) -> f32 { self.area_weight().sqrt() * 0.5 }
+
+    /// Picks a size class, weighted by `area_weight` (so larger classes are more common).
+    fn random(rng: &mut RollbackRng) -> Self {
+        let total: f32 = Self::ALL.iter().map(|size| size.area_weight()).sum();
+        let mut roll = rng.random_range_f32(0.0, total);
+
+        for size in Self::ALL {
+            roll -= size.area_weight();
+            if roll <= 0.0 {
+                return size;
+            }
+        }
+
+        NateroidSize::Small
+    }
+
+    /// One size class down, or `None` if this is already the smallest class - the minimum a
+    /// nateroid can fragment into.
+    fn smaller(self) -> Option<NateroidSize> {
+        match self {
+            NateroidSize::Large => Some(NateroidSize::Medium),
+            NateroidSize::Medium => Some(NateroidSize::Small),
+            NateroidSize::Small => None,
+        }
+    }
+}
+
+/// Marks an entity as a fragment spawned by [`spawn_fragments`] rather than a normal timer/budget
+/// spawn, so `initialize_nateroid` can place it at the parent's death position with an inherited
+/// velocity instead of picking a fresh spot through the usual collision-avoidance search.
+#[derive(Component, Debug)]
+pub struct Fragment {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub size:     NateroidSize,
+}
+
+/// Quake-style mass-proportional fragment counts (one large fragment per 100 mass capped at 8,
+/// one small fragment per 25 mass capped at 16), with `mass` already scaled down by
+/// [`NateroidConfig::fragment_mass_scale`] to keep the result in the low single/double digits
+/// rather than Quake's gib-storm numbers.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn fragment_counts(mass: f32) -> (u32, u32) {
+    let large = (mass / 100.0).min(8.0).round() as u32;
+    let small = (mass / 25.0).min(16.0).round() as u32;
+    (large, small)
+}
+
+/// Spawns a dying nateroid's fragments: one size class below `parent_size` for the "large"
+/// share, [`NateroidSize::Small`] for the rest, each inheriting `parent_velocity` plus an
+/// outward radial kick and the same [`calculate_nateroid_velocity`] jitter a normal spawn gets.
+/// No-ops if `parent_size` is already the minimum size class.
+pub fn spawn_fragments(
+    commands: &mut Commands,
+    config: &NateroidConfig,
+    rng: &mut RollbackRng,
+    parent_position: Vec3,
+    parent_velocity: Vec3,
+    parent_scale: Vec3,
+    parent_size: NateroidSize,
+) {
+    let Some(fragment_size) = parent_size.smaller() else {
+        return;
+    };
+
+    let mass = parent_scale.x * parent_scale.y * parent_scale.z * config.fragment_mass_scale;
+    let (large_fragments, small_fragments) = fragment_counts(mass);
+
+    for (count, size) in [
+        (large_fragments, fragment_size),
+        (small_fragments, NateroidSize::Small),
+    ] {
+        for _ in 0..count {
+            let angle = rng.random_range_f32(0.0, std::f32::consts::TAU);
+            let direction = Vec3::new(angle.cos(), angle.sin(), 0.0);
+            let velocity = parent_velocity + direction * config.fragment_burst_speed;
+
+            commands.spawn((
+                Nateroid,
+                Name::new("Nateroid"),
+                Fragment {
+                    position: parent_position,
+                    velocity,
+                    size,
+                },
+            ));
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct NateroidSpawnStats {
     /// Ring buffer tracking last N spawn attempts (true = success, false = failure)
@@ -73,17 +205,20 @@ pub struct NateroidPlugin;
 impl Plugin for NateroidPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<NateroidSpawnStats>()
+            .init_resource::<NateroidArea>()
+            .init_resource::<SpawnPlacementGrid>()
             .add_systems(
                 OnEnter(asset_loader::AssetsState::Loaded),
                 precompute_death_materials.after(super::actor_config::initialize_actor_configs),
             )
             .add_observer(initialize_nateroid)
+            .add_observer(track_nateroid_area_added)
+            .add_observer(track_nateroid_area_removed)
             .add_systems(
                 Update,
                 (
                     apply_nateroid_materials_to_children,
                     debug_mesh_components.after(apply_nateroid_materials_to_children),
-                    spawn_nateroid.in_set(InGameSet::EntityUpdates),
                     despawn_testaroid_on_teleport.in_set(InGameSet::EntityUpdates),
                     spawn_testaroid
                         .in_set(InGameSet::EntityUpdates)
@@ -93,6 +228,17 @@ impl Plugin for NateroidPlugin {
                         .run_if(just_pressed(GameAction::SpawnTestMissile)),
                     despawn_test_missiles.in_set(InGameSet::EntityUpdates),
                 ),
+            )
+            // the spawn timer drives gameplay-affecting randomness (DeathCorner
+            // draws aside), so it runs on the deterministic fixed schedule
+            // alongside Avian's physics step rather than the variable-rate Update
+            // schedule - a prerequisite for rollback resimulation to agree frame
+            // for frame with the original run.
+            .add_systems(
+                FixedUpdate,
+                (adapt_spawn_rate, spawn_nateroid)
+                    .chain()
+                    .in_set(InGameSet::EntityUpdates),
             );
     }
 }
@@ -102,20 +248,33 @@ impl Plugin for NateroidPlugin {
 #[require(
     Teleporter,
     ActorPortals,
+    WraparoundGhosts,
     CollisionEventsEnabled,
+    PreviousPosition,
     RigidBody::Dynamic,
-    LockedAxes = LOCKED_AXES_2D
+    LockedAxes = LOCKED_AXES_2D,
+    OffscreenIndicator = OffscreenIndicator { icon_color: Color::from(tailwind::ORANGE_400) }
 )]
 pub struct Nateroid;
 
 #[derive(Component, Debug)]
 pub struct Deaderoid {
-    pub initial_scale:          Vec3,
-    pub target_shrink:          f32,
-    pub shrink_duration:        f32,
-    pub elapsed_time:           f32,
-    pub current_shrink:         f32,
-    pub current_material_index: usize,
+    pub initial_scale:   Vec3,
+    pub target_shrink:   f32,
+    pub shrink_duration: f32,
+    pub elapsed_time:    f32,
+    pub current_shrink:  f32,
+    /// Drives the material-swap keyframe (see `despawn::animate_dying_nateroids`): one section
+    /// spanning `NateroidDeathMaterials`'s transparency levels, played forward by `set_progress`
+    /// with the same inverse-cubic ease the shrink tween used to compute inline.
+    pub automaton:       AnimAutomaton,
+    /// Straight-line path `animate_dying_nateroids` derives the launch velocity from - the
+    /// position at death and the chosen boundary corner.
+    pub launch_start:    Vec3,
+    pub launch_target:   Vec3,
+    /// Velocity at the moment of death, blended from over the first fraction of
+    /// `shrink_duration` so the launch doesn't snap straight to the eased-curve velocity.
+    pub launch_velocity: Vec3,
 }
 
 /// Precomputed materials for nateroid death animation at different transparency levels
@@ -132,7 +291,28 @@ pub struct Testaroid {
     pub velocity: Vec3,
 }
 
-fn spawn_nateroid(mut commands: Commands, mut config: ResMut<NateroidConfig>, time: Res<Time>) {
+/// Closes the loop on [`NateroidSpawnStats::success_rate`]: shortens the spawn timer toward
+/// `spawn_interval_min` while placements keep succeeding (field is sparse), and lengthens it
+/// toward `spawn_interval_max` while they keep failing (field is crowded), so a packed field
+/// backs off the spawn rate instead of burning CPU on doomed `initialize_transform` retries.
+fn adapt_spawn_rate(mut config: ResMut<NateroidConfig>, spawn_stats: Res<NateroidSpawnStats>) {
+    let success_rate = spawn_stats.success_rate().max(f32::EPSILON);
+    let new_interval = (config.spawn_interval_base / success_rate)
+        .clamp(config.spawn_interval_min, config.spawn_interval_max);
+
+    if let Some(spawn_timer) = config.spawn_timer.as_mut() {
+        spawn_timer.set_duration(Duration::from_secs_f32(new_interval));
+    }
+}
+
+fn spawn_nateroid(
+    mut commands: Commands,
+    mut config: ResMut<NateroidConfig>,
+    hunter_config: Res<HunterConfig>,
+    time: Res<Time>,
+    area: Res<NateroidArea>,
+    mut rng: ResMut<RollbackRng>,
+) {
     if !config.spawnable {
         return;
     }
@@ -146,7 +326,36 @@ fn spawn_nateroid(mut commands: Commands, mut config: ResMut<NateroidConfig>, ti
         return;
     }
 
-    commands.spawn((Nateroid, Name::new("Nateroid")));
+    if area.0 >= config.spawn_area_budget {
+        return;
+    }
+
+    // occasionally spawn an AI-driven hunter instead of a plain drifting nateroid
+    if rng.random_range_f32(0.0, 1.0) < hunter_config.spawn_chance {
+        commands.spawn((Nateroid, HunterNateroid, Name::new("HunterNateroid")));
+    } else {
+        commands.spawn((Nateroid, Name::new("Nateroid")));
+    }
+}
+
+fn track_nateroid_area_added(
+    trigger: On<Add, NateroidSize>,
+    query: Query<&NateroidSize>,
+    mut area: ResMut<NateroidArea>,
+) {
+    if let Ok(size) = query.get(trigger.entity) {
+        area.0 += size.area_weight();
+    }
+}
+
+fn track_nateroid_area_removed(
+    trigger: On<Remove, NateroidSize>,
+    query: Query<&NateroidSize>,
+    mut area: ResMut<NateroidArea>,
+) {
+    if let Ok(size) = query.get(trigger.entity) {
+        area.0 -= size.area_weight();
+    }
 }
 
 fn despawn_testaroid_on_teleport(
@@ -238,10 +447,10 @@ fn apply_nateroid_materials_to_children(
     children_query: Query<&Children>,
     scene_assets: Res<SceneAssets>,
 ) {
-    let Some(donut_material) = &scene_assets.nateroid_donut_material else {
+    let Some(donut_material) = scene_assets.material("nateroid_donut") else {
         return;
     };
-    let Some(icing_material) = &scene_assets.nateroid_icing_material else {
+    let Some(icing_material) = scene_assets.material("nateroid_icing") else {
         return;
     };
 
@@ -357,6 +566,7 @@ fn debug_mesh_components(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn initialize_nateroid(
     nateroid: On<Add, Nateroid>,
     mut commands: Commands,
@@ -364,9 +574,32 @@ fn initialize_nateroid(
     mut config: ResMut<NateroidConfig>,
     spatial_query: SpatialQuery,
     mut spawn_stats: ResMut<NateroidSpawnStats>,
+    mut rng: ResMut<RollbackRng>,
     time: Res<Time>,
     test_query: Query<&Testaroid>,
+    fragment_query: Query<&Fragment>,
+    mut spawn_grid: ResMut<SpawnPlacementGrid>,
 ) {
+    // Fragment of a nateroid that just died: place it at the parent's death position with an
+    // inherited velocity instead of running the usual random-placement search.
+    if let Ok(fragment) = fragment_query.get(nateroid.entity) {
+        let scale = config.actor_config.transform.scale * fragment.size.scale_multiplier();
+        let transform = Transform::from_translation(fragment.position).with_scale(scale);
+        let (jitter_velocity, angular_velocity) =
+            calculate_nateroid_velocity(config.linear_velocity, config.angular_velocity, &mut rng);
+
+        commands
+            .entity(nateroid.entity)
+            .insert(transform)
+            .insert(LinearVelocity(fragment.velocity + jitter_velocity.0))
+            .insert(angular_velocity)
+            .insert(fragment.size);
+
+        insert_configured_components(&mut commands, &mut config.actor_config, nateroid.entity);
+
+        return;
+    }
+
     // Check if this is a testaroid
     if let Ok(testaroid) = test_query.get(nateroid.entity) {
         // Testaroid: spawn with configured position and velocity
@@ -392,7 +625,25 @@ fn initialize_nateroid(
     // Normal nateroid initialization
     let current_time = time.elapsed_secs();
 
-    let Some(transform) = initialize_transform(&boundary, &config, &spatial_query) else {
+    // Rebuild the placement grid once per tick - later spawns in the same batch reuse it and
+    // claim their own cell, rather than every spawn re-querying the whole field.
+    if spawn_grid.0.as_ref().is_none_or(|grid| grid.built_at != current_time) {
+        let cell_size = config.actor_config.aabb.max_dimension().max(1.0);
+        spawn_grid.0 = Some(SpawnGrid::build(
+            &boundary,
+            &spatial_query,
+            cell_size,
+            current_time,
+        ));
+    }
+    let grid = spawn_grid.0.as_mut().expect("just built above");
+
+    let size = NateroidSize::random(&mut rng);
+    let scale = config.actor_config.transform.scale * size.scale_multiplier();
+
+    let Some(transform) =
+        initialize_transform(&boundary, &config, &spatial_query, &mut rng, grid, scale)
+    else {
         spawn_stats.record_attempt(false);
         commands.entity(nateroid.entity).despawn();
 
@@ -431,24 +682,126 @@ fn initialize_nateroid(
 
     // Calculate random velocities for nateroid
     let (linear_velocity, angular_velocity) =
-        calculate_nateroid_velocity(config.linear_velocity, config.angular_velocity);
+        calculate_nateroid_velocity(config.linear_velocity, config.angular_velocity, &mut rng);
 
     commands
         .entity(nateroid.entity)
         .insert(transform)
         .insert(linear_velocity)
-        .insert(angular_velocity);
+        .insert(angular_velocity)
+        .insert(size);
 
     insert_configured_components(&mut commands, &mut config.actor_config, nateroid.entity);
 
     // Material will be applied by apply_nateroid_materials_to_children system
 }
 
+/// Caches the [`SpawnGrid`] built for the current tick so every nateroid placed within the same
+/// batch (e.g. several budget top-ups, or a death's fragments) shares one query of the field
+/// instead of re-querying physics per placement attempt.
+#[derive(Resource, Default)]
+struct SpawnPlacementGrid(Option<SpawnGrid>);
+
+/// A uniform grid over the spawn window, cell size taken from the nateroid's `ActorConfig::aabb`
+/// max dimension. Built once per batch by querying existing Spaceship/Asteroid colliders
+/// against each cell; free cells are then claimed (and marked occupied) as each nateroid in the
+/// batch picks its spot, so no two placements in a batch share a cell - a cheap stand-in for
+/// Poisson-disk sampling without the O(n^2) cost of checking every newly-placed nateroid against
+/// every other. The position is jittered anywhere within its claimed cell, so nateroids in
+/// neighbouring cells can still end up close together; no minimum separation is guaranteed.
+struct SpawnGrid {
+    origin:    Vec3,
+    cell_size: f32,
+    dims:      (u32, u32),
+    occupied:  HashSet<(u32, u32)>,
+    built_at:  f32,
+}
+
+impl SpawnGrid {
+    fn build(boundary: &Boundary, spatial_query: &SpatialQuery, cell_size: f32, built_at: f32) -> Self {
+        let half_extent = (boundary.transform.scale * SPAWN_WINDOW).abs() / 2.0;
+        let origin = boundary.transform.translation - half_extent;
+        let dims_x = (half_extent.x * 2.0 / cell_size).ceil().max(1.0) as u32;
+        let dims_y = (half_extent.y * 2.0 / cell_size).ceil().max(1.0) as u32;
+
+        let filter =
+            SpatialQueryFilter::from_mask(LayerMask::from([GameLayer::Spaceship, GameLayer::Asteroid]));
+        let cell_collider = Collider::sphere(cell_size / 2.0);
+
+        let mut occupied = HashSet::new();
+        for grid_x in 0..dims_x {
+            for grid_y in 0..dims_y {
+                let center = origin
+                    + Vec3::new(
+                        (grid_x as f32 + 0.5) * cell_size,
+                        (grid_y as f32 + 0.5) * cell_size,
+                        0.0,
+                    );
+
+                if !spatial_query
+                    .shape_intersections(&cell_collider, center, Quat::IDENTITY, &filter)
+                    .is_empty()
+                {
+                    occupied.insert((grid_x, grid_y));
+                }
+            }
+        }
+
+        Self {
+            origin,
+            cell_size,
+            dims: (dims_x, dims_y),
+            occupied,
+            built_at,
+        }
+    }
+
+    fn cell_center(&self, cell: (u32, u32)) -> Vec3 {
+        self.origin
+            + Vec3::new(
+                (cell.0 as f32 + 0.5) * self.cell_size,
+                (cell.1 as f32 + 0.5) * self.cell_size,
+                0.0,
+            )
+    }
+
+    /// Claims a random free cell and returns a world-space position jittered within it, or
+    /// `None` once every cell is occupied.
+    fn sample_free_cell(&mut self, rng: &mut RollbackRng) -> Option<Vec3> {
+        let free: Vec<(u32, u32)> = (0..self.dims.0)
+            .flat_map(|x| (0..self.dims.1).map(move |y| (x, y)))
+            .filter(|cell| !self.occupied.contains(cell))
+            .collect();
+
+        let cell = *free.get(rng.random_range(free.len().max(1)))?;
+        self.occupied.insert(cell);
+
+        let half_cell = self.cell_size / 2.0;
+        let jitter = Vec3::new(
+            get_random_component(-half_cell, half_cell, rng),
+            get_random_component(-half_cell, half_cell, rng),
+            0.0,
+        );
+
+        Some(self.cell_center(cell) + jitter)
+    }
+}
+
 fn initialize_transform(
     boundary: &Boundary,
     nateroid_config: &NateroidConfig,
     spatial_query: &SpatialQuery,
+    rng: &mut RollbackRng,
+    spawn_grid: &mut SpawnGrid,
+    scale: Vec3,
 ) -> Option<Transform> {
+    let rotation = get_random_rotation(rng);
+
+    if let Some(position) = spawn_grid.sample_free_cell(rng) {
+        return Some(Transform::from_trs(position, rotation, scale));
+    }
+
+    // Every grid cell is occupied - fall back to uniform-random rejection sampling.
     const MAX_ATTEMPTS: u32 = 20;
 
     let bounds = Transform {
@@ -457,13 +810,12 @@ fn initialize_transform(
         ..default()
     };
 
-    let scale = nateroid_config.actor_config.transform.scale;
     let filter =
         SpatialQueryFilter::from_mask(LayerMask::from([GameLayer::Spaceship, GameLayer::Asteroid]));
 
     for _ in 0..MAX_ATTEMPTS {
-        let position = get_random_position_within_bounds(&bounds);
-        let rotation = get_random_rotation();
+        let position = get_random_position_within_bounds(&bounds, rng);
+        let rotation = get_random_rotation(rng);
 
         let intersections = spatial_query.shape_intersections(
             &nateroid_config.actor_config.collider,
@@ -489,7 +841,7 @@ fn precompute_death_materials(
     nateroid_config: Res<NateroidConfig>,
 ) {
     // Get the nateroid scene
-    let Some(nateroid_scene) = scenes.get(&scene_assets.nateroid) else {
+    let Some(nateroid_scene) = scenes.get(&scene_assets.scene("nateroid")) else {
         warn!("Nateroid scene not loaded yet");
         return;
     };
@@ -553,51 +905,49 @@ fn precompute_death_materials(
     );
 }
 
-fn get_random_position_within_bounds(bounds: &Transform) -> Vec3 {
-    let mut rng = rand::rng();
+fn get_random_position_within_bounds(bounds: &Transform, rng: &mut RollbackRng) -> Vec3 {
     let half_scale = bounds.scale.abs() / 2.0; // Use absolute value to ensure positive scale
     let min = bounds.translation - half_scale;
     let max = bounds.translation + half_scale;
 
     Vec3::new(
-        get_random_component(min.x, max.x, &mut rng),
-        get_random_component(min.y, max.y, &mut rng),
-        get_random_component(min.z, max.z, &mut rng),
+        get_random_component(min.x, max.x, rng),
+        get_random_component(min.y, max.y, rng),
+        get_random_component(min.z, max.z, rng),
     )
 }
 
-fn get_random_component(min: f32, max: f32, rng: &mut impl Rng) -> f32 {
-    if (max - min).abs() < f32::EPSILON {
-        min // If the range is effectively zero, just return the min value
-    } else {
-        rng.random_range(min.min(max)..=min.max(max)) // Ensure min is always less than max
-    }
+fn get_random_component(min: f32, max: f32, rng: &mut RollbackRng) -> f32 {
+    rng.random_range_f32(min.min(max), min.max(max))
 }
 
-fn get_random_rotation() -> Quat {
-    let mut rng = rand::rng();
+fn get_random_rotation(rng: &mut RollbackRng) -> Quat {
     Quat::from_euler(
         EulerRot::XYZ,
-        rng.random_range(-std::f32::consts::PI..std::f32::consts::PI),
-        rng.random_range(-std::f32::consts::PI..std::f32::consts::PI),
-        rng.random_range(-std::f32::consts::PI..std::f32::consts::PI),
+        rng.random_range_f32(-std::f32::consts::PI, std::f32::consts::PI),
+        rng.random_range_f32(-std::f32::consts::PI, std::f32::consts::PI),
+        rng.random_range_f32(-std::f32::consts::PI, std::f32::consts::PI),
     )
 }
 
-fn random_vec3(range_x: Range<f32>, range_y: Range<f32>, range_z: Range<f32>) -> Vec3 {
-    let mut rng = rand::rng();
+fn random_vec3(
+    range_x: Range<f32>,
+    range_y: Range<f32>,
+    range_z: Range<f32>,
+    rng: &mut RollbackRng,
+) -> Vec3 {
     let x = if range_x.start < range_x.end {
-        rng.random_range(range_x)
+        rng.random_range_f32(range_x.start, range_x.end)
     } else {
         0.0
     };
     let y = if range_y.start < range_y.end {
-        rng.random_range(range_y)
+        rng.random_range_f32(range_y.start, range_y.end)
     } else {
         0.0
     };
     let z = if range_z.start < range_z.end {
-        rng.random_range(range_z)
+        rng.random_range_f32(range_z.start, range_z.end)
     } else {
         0.0
     };
@@ -605,13 +955,18 @@ fn random_vec3(range_x: Range<f32>, range_y: Range<f32>, range_z: Range<f32>) ->
     Vec3::new(x, y, z)
 }
 
-fn calculate_nateroid_velocity(linvel: f32, angvel: f32) -> (LinearVelocity, AngularVelocity) {
+fn calculate_nateroid_velocity(
+    linvel: f32,
+    angvel: f32,
+    rng: &mut RollbackRng,
+) -> (LinearVelocity, AngularVelocity) {
     (
-        LinearVelocity(random_vec3(-linvel..linvel, -linvel..linvel, 0.0..0.0)),
+        LinearVelocity(random_vec3(-linvel..linvel, -linvel..linvel, 0.0..0.0, rng)),
         AngularVelocity(random_vec3(
             -angvel..angvel,
             -angvel..angvel,
             -angvel..angvel,
+            rng,
         )),
     )
 }
@@ -1,17 +1,31 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
+use leafwing_input_manager::prelude::ActionState;
 
+use super::Aabb;
+use super::Loadout;
+use super::OutfitRegistry;
 use super::Teleporter;
 use super::actor_config::GLTF_ROTATION_X;
 use super::actor_config::LOCKED_AXES_SPACESHIP;
 use super::actor_config::insert_configured_components;
 use super::actor_template::SpaceshipConfig;
+use super::constants::RECOIL_BUDGET_MAX;
+use super::constants::RECOIL_BUDGET_REGEN_PER_SEC;
+use super::constants::SPACESHIP_SPAWN_BUFFER_HALF_EXTENT;
 use super::spaceship_control::SpaceshipControl;
+use crate::game_input::GameAction;
 use crate::playfield::ActorPortals;
+use crate::playfield::Boundary;
+use crate::playfield::WraparoundGhosts;
 use crate::schedule::InGameSet;
 use crate::splash::SplashText;
 use crate::state::GameState;
 
+/// Ship crosses into "approaching the boundary" territory once it's within
+/// this fraction of the boundary's half-extent from its nearest face.
+const DOCK_APPROACH_FRACTION: f32 = 0.1;
+
 /// Returns the default spaceship rotation: model correction (90° around X)
 fn default_spaceship_rotation() -> Quat { Quat::from_rotation_x(GLTF_ROTATION_X) }
 
@@ -27,11 +41,18 @@ impl Plugin for SpaceshipPlugin {
                 OnEnter(GameState::InGame {
                     paused:     false,
                     inspecting: false,
+                    turbo:      false,
                 }),
                 spawn_spaceship_if_needed,
             )
             // check if spaceship is destroyed...this will change the GameState
             .add_systems(Update, spaceship_destroyed.in_set(InGameSet::EntityUpdates))
+            .add_systems(
+                Update,
+                (dock_or_undock_spaceship, animate_docking)
+                    .chain()
+                    .in_set(InGameSet::EntityUpdates),
+            )
             .add_systems(
                 FixedUpdate,
                 enforce_spaceship_2d_rotation
@@ -41,6 +62,32 @@ impl Plugin for SpaceshipPlugin {
     }
 }
 
+/// Lifecycle of a single ship, modeled on classic flight-state machines
+/// (Galactica's system-sim, Pioneer's flight states): the ship is either
+/// flying free, tweening into or out of a dock, sitting landed, or gone.
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(Component)]
+pub enum ShipState {
+    #[default]
+    Flying,
+    Landing {
+        /// Translation/scale captured once at the moment this tween started, so `animate_docking`
+        /// always interpolates from a fixed start rather than re-lerping from whatever the
+        /// transform happens to already be mutated to this frame.
+        start:       Vec3,
+        start_scale: Vec3,
+        target:      Vec3,
+        t:           f32,
+        /// `true` while tweening toward the target (landing); `false` while
+        /// tweening back out (undocking).
+        landing:     bool,
+    },
+    Landed {
+        target: Vec3,
+    },
+    Dead,
+}
+
 #[derive(Component, Default)]
 pub struct ContinuousFire;
 
@@ -49,14 +96,57 @@ pub struct ContinuousFire;
 #[require(
     Teleporter,
     ActorPortals,
+    WraparoundGhosts,
     CollisionEventsEnabled,
     RigidBody::Dynamic,
     LockedAxes = LOCKED_AXES_SPACESHIP,
     LinearVelocity::ZERO,
     AngularVelocity::ZERO,
+    ShipState,
+    Loadout,
+    ExternalImpulse,
+    RecoilBudget,
 )]
 pub struct Spaceship;
 
+/// Marker for a child clearance zone around the spaceship that nateroids must not spawn inside -
+/// checked by `spaceship_diagnostics::detect_close_nateroid_spawn`, which relocates any nateroid
+/// whose AABB overlaps one.
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component)]
+pub struct SpaceshipSpawnBuffer;
+
+/// How much further firing recoil may push the spaceship before it's spent, regenerating over
+/// time like `WeaponEnergy`. `fire_missile` spends from this (clamped to what's left) when it
+/// applies a firing impulse, so rapid continuous fire can't keep stacking recoil impulses and
+/// fling the ship across the playfield.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct RecoilBudget {
+    pub current:       f32,
+    pub max:           f32,
+    pub regen_per_sec: f32,
+}
+
+impl Default for RecoilBudget {
+    fn default() -> Self {
+        Self {
+            current:       RECOIL_BUDGET_MAX,
+            max:           RECOIL_BUDGET_MAX,
+            regen_per_sec: RECOIL_BUDGET_REGEN_PER_SEC,
+        }
+    }
+}
+
+/// Triggered on the spaceship whenever firing recoil pushes it, carrying the impulse magnitude
+/// actually applied (after the `RecoilBudget` clamp) - lets camera shake react to recoil without
+/// this module depending on the camera crate.
+#[derive(EntityEvent)]
+pub struct SpaceshipRecoiled {
+    pub entity:    Entity,
+    pub magnitude: f32,
+}
+
 /// Observer that spawns the spaceship when splash text is removed
 fn spawn_after_splash_text_removed(
     _trigger: On<Remove, SplashText>,
@@ -86,32 +176,57 @@ fn spawn_spaceship(mut commands: Commands, spaceship_config: Res<SpaceshipConfig
     commands.spawn((Spaceship, ContinuousFire, Name::new("Spaceship")));
 }
 
+/// Outfits a freshly spawned spaceship starts with, installed by name from the
+/// [`OutfitRegistry`] so new pilots aren't flying a bare hull.
+const STARTER_OUTFITS: [&str; 2] = ["reinforced_hull", "maneuvering_thrusters"];
+
 fn initialize_spaceship(
     spaceship: On<Add, Spaceship>,
     mut commands: Commands,
     mut spaceship_config: ResMut<SpaceshipConfig>,
+    outfit_registry: Res<OutfitRegistry>,
 ) {
     commands
         .entity(spaceship.entity)
         .insert(spaceship_config.transform)
-        .insert(SpaceshipControl::generate_input_map());
+        .insert(SpaceshipControl::generate_input_map())
+        .insert(Loadout(
+            STARTER_OUTFITS
+                .iter()
+                .filter_map(|name| outfit_registry.get(name).cloned())
+                .collect(),
+        ));
 
     insert_configured_components(
         &mut commands,
         &mut spaceship_config.actor_config,
         spaceship.entity,
     );
+
+    commands.spawn((
+        SpaceshipSpawnBuffer,
+        Aabb {
+            min: Vec3::splat(-SPACESHIP_SPAWN_BUFFER_HALF_EXTENT),
+            max: Vec3::splat(SPACESHIP_SPAWN_BUFFER_HALF_EXTENT),
+        },
+        Transform::IDENTITY,
+        Visibility::Hidden,
+        ChildOf(spaceship.entity),
+        Name::new("SpaceshipSpawnBuffer"),
+    ));
 }
 
-// check if spaceship exists or not - query if get_single()
-// there should only be one - if it returns an error then the
-// spaceship doesn't exist
+// check if the spaceship is gone, or has transitioned to ShipState::Dead
+// (set by despawn.rs before the entity itself is actually removed)
 fn spaceship_destroyed(
     mut next_state: ResMut<NextState<GameState>>,
-    query: Query<Entity, With<Spaceship>>,
+    query: Query<&ShipState, With<Spaceship>>,
     state: Res<State<GameState>>,
 ) {
-    if query.single().is_err() {
+    let destroyed = query.single().is_ok_and(|ship_state| matches!(ship_state, ShipState::Dead))
+        || query.is_empty();
+
+    if destroyed {
         info!(
             "spaceship destroyed: {:?}, count {:?}",
             state,
@@ -124,9 +239,16 @@ fn spaceship_destroyed(
 /// Enforce strict 2D rotation by zeroing X/Y angular velocity and correcting transform if tilted
 /// Keeps the spaceship flat in the XY plane (up vector should point in +Z)
 fn enforce_spaceship_2d_rotation(
-    mut spaceship: Query<(&mut Transform, &mut AngularVelocity), With<Spaceship>>,
+    mut spaceship: Query<(&mut Transform, &mut AngularVelocity, &ShipState), With<Spaceship>>,
 ) {
-    if let Ok((mut transform, mut angular_velocity)) = spaceship.single_mut() {
+    if let Ok((mut transform, mut angular_velocity, ship_state)) = spaceship.single_mut() {
+        // `animate_docking` owns the tween's translation/scale and never touches rotation, but
+        // skip this too while docking/undocking so scripted animation rotations (should any be
+        // added to the tween later) aren't fought.
+        if matches!(ship_state, ShipState::Landing { .. }) {
+            return;
+        }
+
         // Always zero angular velocity on X/Y axes to prevent future off-axis rotation
         angular_velocity.x = 0.0;
         angular_velocity.y = 0.0;
@@ -186,3 +308,154 @@ fn enforce_spaceship_2d_rotation(
         }
     }
 }
+
+/// Finds the point on the boundary surface nearest to `position`, and how far
+/// away it is. Mirrors the corner-distance approach `despawn.rs` uses for
+/// `DeathCorner::Nearest`, but projects onto the nearest face instead of a
+/// corner.
+fn nearest_boundary_point(position: Vec3, boundary: &Boundary) -> (Vec3, f32) {
+    let half_size = boundary.transform.scale / 2.0;
+    let center = boundary.transform.translation;
+    let local = position - center;
+
+    let clamped = local.clamp(-half_size, half_size);
+    // distance to each face along every axis; the smallest wins
+    let face_distances = [
+        (half_size.x - local.x.abs(), Vec3::X * local.x.signum()),
+        (half_size.y - local.y.abs(), Vec3::Y * local.y.signum()),
+        (half_size.z - local.z.abs(), Vec3::Z * local.z.signum()),
+    ];
+    let (distance, axis) = face_distances
+        .into_iter()
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .unwrap_or((0.0, Vec3::X));
+
+    let mut target = clamped;
+    if axis.x != 0.0 {
+        target.x = half_size.x * axis.x.signum();
+    } else if axis.y != 0.0 {
+        target.y = half_size.y * axis.y.signum();
+    } else {
+        target.z = half_size.z * axis.z.signum();
+    }
+
+    (center + target, distance)
+}
+
+/// Watches for the ship approaching a boundary face and toggles docking on
+/// `GameAction::Dock`. `Flying` → `Landing { landing: true }` when close
+/// enough to the boundary; `Landed` → `Landing { landing: false }` reverses
+/// the tween back out.
+fn dock_or_undock_spaceship(
+    user_input: Res<ActionState<GameAction>>,
+    boundary: Res<Boundary>,
+    config: Res<SpaceshipConfig>,
+    mut query: Query<(&Transform, &mut ShipState), With<Spaceship>>,
+) {
+    if !user_input.just_pressed(&GameAction::Dock) {
+        return;
+    }
+
+    let Ok((transform, mut ship_state)) = query.single_mut() else {
+        return;
+    };
+
+    let half_size = boundary.transform.scale / 2.0;
+    let approach_distance = half_size.min_element() * DOCK_APPROACH_FRACTION;
+    let full_scale = config.actor_config.transform.scale;
+    let docked_scale = full_scale * config.docking_shrink_pct;
+
+    match *ship_state {
+        ShipState::Flying => {
+            let (target, distance) = nearest_boundary_point(transform.translation, &boundary);
+            if distance <= approach_distance {
+                *ship_state = ShipState::Landing {
+                    start:       transform.translation,
+                    start_scale: full_scale,
+                    target,
+                    t:           0.0,
+                    landing:     true,
+                };
+            }
+        },
+        ShipState::Landed { target } => {
+            // push off from the wall along its inward normal so undocking is actually visible,
+            // rather than tweening straight back to the point the ship is already sitting at
+            let inward = (boundary.transform.translation - target).normalize_or_zero();
+            *ship_state = ShipState::Landing {
+                start:       transform.translation,
+                start_scale: docked_scale,
+                target:      target + inward * approach_distance,
+                t:           0.0,
+                landing:     false,
+            };
+        },
+        ShipState::Landing { .. } | ShipState::Dead => {},
+    }
+}
+
+/// Drives the `Landing` tween, interpolating translation/scale toward (or
+/// away from) the dock target, then settles into `Landed`/`Flying`.
+fn animate_docking(
+    time: Res<Time>,
+    config: Res<SpaceshipConfig>,
+    mut query: Query<
+        (
+            &mut Transform,
+            &mut ShipState,
+            &mut LinearVelocity,
+            &mut AngularVelocity,
+            &mut RigidBody,
+        ),
+        With<Spaceship>,
+    >,
+) {
+    let Ok((mut transform, mut ship_state, mut linear_velocity, mut angular_velocity, mut body)) =
+        query.single_mut()
+    else {
+        return;
+    };
+
+    let ShipState::Landing {
+        start,
+        start_scale,
+        target,
+        t,
+        landing,
+    } = *ship_state
+    else {
+        return;
+    };
+
+    let new_t = (t + time.delta_secs() / config.docking_duration_secs).min(1.0);
+
+    let full_scale = config.actor_config.transform.scale;
+    let docked_scale = full_scale * config.docking_shrink_pct;
+    let target_scale = if landing { docked_scale } else { full_scale };
+
+    // always lerp from the fixed start captured when the tween began, not from whatever
+    // `transform` already got mutated to last frame - matches `despawn.rs`'s `initial_scale`
+    // shrink tween
+    transform.translation = start.lerp(target, new_t);
+    transform.scale = start_scale.lerp(target_scale, new_t);
+
+    if new_t >= 1.0 {
+        if landing {
+            linear_velocity.0 = Vec3::ZERO;
+            angular_velocity.0 = Vec3::ZERO;
+            *body = RigidBody::Kinematic;
+            *ship_state = ShipState::Landed { target };
+        } else {
+            *body = RigidBody::Dynamic;
+            *ship_state = ShipState::Flying;
+        }
+    } else {
+        *ship_state = ShipState::Landing {
+            start,
+            start_scale,
+            target,
+            t: new_t,
+            landing,
+        };
+    }
+}
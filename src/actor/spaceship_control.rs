@@ -13,6 +13,7 @@ use strum::IntoEnumIterator;
 
 use super::actor_template::SpaceshipConfig;
 use super::spaceship::ContinuousFire;
+use super::spaceship::ShipState;
 use super::spaceship::Spaceship;
 use crate::game_input::GameAction;
 use crate::game_input::toggle_active;
@@ -68,8 +69,12 @@ impl Default for SpaceshipControlConfig {
 #[derive(Actionlike, EnumIter, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 pub enum SpaceshipControl {
     Accelerate,
+    AccelDown,
+    AccelUp,
     ContinuousFire,
     Fire,
+    NextWeapon,
+    PrevWeapon,
     TurnLeft,
     TurnRight,
 }
@@ -89,13 +94,17 @@ impl SpaceshipControl {
                 .with(action, KeyCode::ArrowRight),
             Self::Fire => input_map.with(action, KeyCode::Space),
             Self::ContinuousFire => input_map.with(action, KeyCode::KeyF),
+            Self::NextWeapon => input_map.with(action, KeyCode::Tab),
+            Self::PrevWeapon => input_map.with(action, KeyCode::Backquote),
+            Self::AccelUp => input_map.with(action, KeyCode::Period),
+            Self::AccelDown => input_map.with(action, KeyCode::Comma),
         })
     }
 }
 
 fn spaceship_movement_controls(
     mut q_spaceship: Query<
-        (&mut Transform, &mut LinearVelocity, &mut AngularVelocity),
+        (&mut Transform, &mut LinearVelocity, &mut AngularVelocity, &ShipState),
         With<Spaceship>,
     >,
     camera_transform: Single<&Transform, (With<PanOrbitCamera>, Without<Spaceship>)>,
@@ -107,9 +116,15 @@ fn spaceship_movement_controls(
 ) {
     // we can use this because there is only exactly one spaceship - so we're not
     // looping over the query
-    if let Ok((mut spaceship_transform, mut linear_velocity, mut angular_velocity)) =
+    if let Ok((mut spaceship_transform, mut linear_velocity, mut angular_velocity, ship_state)) =
         q_spaceship.single_mut()
     {
+        // Docking/undocking and docked ships don't respond to thruster input - the landing tween
+        // in `animate_docking` owns the transform for the duration.
+        if !matches!(ship_state, ShipState::Flying) {
+            return;
+        }
+
         // dynamically update from inspector while game is running to change size
         spaceship_transform.scale = spaceship_config.transform.scale;
 
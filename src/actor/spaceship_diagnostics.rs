@@ -4,6 +4,7 @@ use super::Aabb;
 use super::Nateroid;
 use super::SpaceshipSpawnBuffer;
 use super::actor_template::NateroidConfig;
+use super::constants::NATEROID_SPAWN_RELOCATION_ATTEMPTS;
 use super::spaceship::Spaceship;
 use crate::schedule::InGameSet;
 
@@ -13,6 +14,9 @@ impl Plugin for SpaceshipDiagnosticsPlugin {
     fn build(&self, app: &mut App) { app.add_observer(detect_close_nateroid_spawn); }
 }
 
+// kept around as a manual debugging aid for visibility-propagation issues on the
+// spaceship/spawn-buffer hierarchy - not wired into the plugin, call it by hand when needed
+#[allow(dead_code)]
 fn debug_spaceship_visibility(
     spaceship_query: Query<
         (
@@ -75,8 +79,15 @@ fn debug_spaceship_visibility(
     }
 }
 
+/// Relocates a freshly spawned [`Nateroid`] out of any [`SpaceshipSpawnBuffer`] its AABB lands
+/// in, so a nateroid never appears on top of the player. Each attempt pushes the nateroid out of
+/// the buffer it overlaps by the shallowest-axis minimum-translation vector (see
+/// [`Aabb::penetration_vector`]), then re-checks against every buffer again, since a push out of
+/// one buffer can land inside another; `NATEROID_SPAWN_RELOCATION_ATTEMPTS` bounds that loop so
+/// an unresolvable overlap (e.g. a nateroid larger than the gap between buffers) doesn't hang.
 fn detect_close_nateroid_spawn(
     nateroid: On<Add, Nateroid>,
+    mut commands: Commands,
     nateroid_query: Query<&Transform>,
     nateroid_config: Res<NateroidConfig>,
     spawn_buffers: Query<(&GlobalTransform, &Aabb), With<SpaceshipSpawnBuffer>>,
@@ -85,26 +96,29 @@ fn detect_close_nateroid_spawn(
         return; // Shouldn't happen, but guard against it
     };
 
-    // Get nateroid's AABB in world space
     let nateroid_aabb = &nateroid_config.actor_config.aabb;
-    let nateroid_world_aabb =
-        nateroid_aabb.transform(nateroid_transform.translation, nateroid_transform.scale);
+    let mut position = nateroid_transform.translation;
 
-    // Check if nateroid intersects with any spawn buffer
-    for (buffer_global_transform, buffer_aabb) in spawn_buffers.iter() {
-        let buffer_world_aabb = buffer_aabb.transform(
-            buffer_global_transform.translation(),
-            buffer_global_transform.scale(),
-        );
+    for _ in 0..NATEROID_SPAWN_RELOCATION_ATTEMPTS {
+        let nateroid_world_aabb = nateroid_aabb.transform(position, nateroid_transform.scale);
 
-        if nateroid_world_aabb.intersects(&buffer_world_aabb) {
-            error!(
-                "🚨 NATEROID SPAWNED INSIDE SPAWN BUFFER 🚨\n\
-                 Nateroid position: {:.2?}\n\
-                 Buffer center: {:.2?}",
-                nateroid_transform.translation,
-                buffer_global_transform.translation()
-            );
-        }
+        let overlap = spawn_buffers.iter().find_map(|(buffer_transform, buffer_aabb)| {
+            let buffer_world_aabb =
+                buffer_aabb.transform(buffer_transform.translation(), buffer_transform.scale());
+            nateroid_world_aabb.penetration_vector(&buffer_world_aabb)
+        });
+
+        let Some(push_out) = overlap else {
+            break;
+        };
+
+        position += push_out;
+    }
+
+    if position != nateroid_transform.translation {
+        commands.entity(nateroid.entity).insert(Transform {
+            translation: position,
+            ..*nateroid_transform
+        });
     }
 }
@@ -10,17 +10,29 @@ use super::actor_template::NateroidConfig;
 use super::spaceship::Spaceship;
 use crate::despawn::despawn;
 use crate::playfield::Boundary;
+use crate::playfield::BoundaryCrossing;
+use crate::playfield::BoundaryFace;
+use crate::playfield::PortalConfig;
 use crate::schedule::InGameSet;
 
+// Deterministic ordering (see `resolve_teleport_overlaps`) is as far as this module goes toward
+// rollback-compatibility on its own - actually scheduling it inside a rollback schedule and
+// snapshotting `Teleporter`/`Health`/`CollisionLayers`/`TeleportCollisionState` for rewind needs
+// `bevy_ggrs`, which isn't a dependency of this crate.
 pub struct TeleportPlugin;
 
 impl Plugin for TeleportPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<TeleportCollisionState>()
-            .add_observer(on_teleported)
+            .init_resource::<PendingTeleportOverlaps>()
             .add_systems(
                 FixedUpdate,
-                teleport_at_boundary.in_set(InGameSet::EntityUpdates),
+                (
+                    teleport_at_boundary,
+                    resolve_teleport_overlaps.after(teleport_at_boundary),
+                    apply_teleport_kick.after(teleport_at_boundary),
+                )
+                    .in_set(InGameSet::EntityUpdates),
             );
     }
 }
@@ -35,19 +47,32 @@ pub struct Teleporter {
     pub just_teleported:          bool,
     pub last_teleported_position: Option<Vec3>,
     pub last_teleported_normal:   Option<Dir3>,
+    /// Rotation the actor's velocity was carried through on the last teleport - identity for a
+    /// straight mirror wrap, non-identity when [`PortalConfig`] pairs the entry face with a
+    /// different exit face.
+    pub last_exit_rotation:       Quat,
 }
 
-#[derive(EntityEvent)]
-struct Teleported {
+/// A teleport that just happened this tick and still needs its post-teleport overlaps resolved.
+/// `teleport_at_boundary` pushes one of these per wrap instead of triggering an observer, so
+/// overlap resolution runs as a plain, explicitly-ordered system (`resolve_teleport_overlaps`)
+/// rather than at whatever point the command buffer happens to flush. Observers fire at a point
+/// in the schedule that isn't itself part of `InGameSet` ordering, so two peers running the same
+/// tick under a rollback schedule aren't guaranteed to evaluate `SpatialQuery` against the same
+/// frame's state - an explicit `.after(teleport_at_boundary)` system removes that ambiguity.
+struct PendingTeleportOverlap {
     entity:   Entity,
     position: Vec3,
     rotation: Quat,
     collider: Collider,
 }
 
+#[derive(Resource, Default)]
+struct PendingTeleportOverlaps(Vec<PendingTeleportOverlap>);
+
 #[allow(clippy::type_complexity)]
-fn on_teleported(
-    event: On<Teleported>,
+fn resolve_teleport_overlaps(
+    mut pending: ResMut<PendingTeleportOverlaps>,
     mut params: ParamSet<(
         SpatialQuery,
         Query<(&mut CollisionLayers, &mut Health), With<Nateroid>>,
@@ -56,141 +81,315 @@ fn on_teleported(
     config: Res<NateroidConfig>,
     mut collision_state: ResMut<TeleportCollisionState>,
 ) {
-    // First, do all spatial queries (collect results before mutating)
-    let asteroid_filter = SpatialQueryFilter::from_mask(LayerMask::from([GameLayer::Asteroid]));
-    let spaceship_filter = SpatialQueryFilter::from_mask(LayerMask::from([GameLayer::Spaceship]));
-
-    let overlapping_asteroids = params.p0().shape_intersections(
-        &event.collider,
-        event.position,
-        event.rotation,
-        &asteroid_filter,
-    );
-
-    let overlapping_spaceship = params.p0().shape_intersections(
-        &event.collider,
-        event.position,
-        event.rotation,
-        &spaceship_filter,
-    );
-
-    // Then, mutate nateroid health/collision layers
-    let mut nateroid_query = params.p1();
-
-    // Check if we should be aggressive based on spawn success rate
-    // Lower spawn success rate = field is crowded = be more aggressive
-    let spawn_success_rate = spawn_stats.success_rate();
-    let field_is_crowded = spawn_success_rate < config.density_culling_threshold;
-
-    // Kill overlapping asteroids (but not the teleporting entity)
-    // Only kill nateroid-on-nateroid overlaps if field is crowded
-    let is_teleporting_nateroid = nateroid_query.get(event.entity).is_ok();
-
-    // Debug logging - only log when crowded state changes
-    if (!overlapping_asteroids.is_empty() || !overlapping_spaceship.is_empty())
-        && collision_state.last_field_crowded != Some(field_is_crowded)
-    {
-        info!(
-            "🔍 Teleport collision detected - attempts: {}, successes: {}, rate: {:.1}%, threshold: {:.1}%, crowded: {}, is_nateroid: {}",
-            spawn_stats.attempts_count(),
-            spawn_stats.successes_count(),
-            spawn_success_rate * 100.0,
-            config.density_culling_threshold * 100.0,
-            field_is_crowded,
-            is_teleporting_nateroid
+    for teleport in pending.0.drain(..) {
+        // First, do all spatial queries (collect results before mutating)
+        let asteroid_filter =
+            SpatialQueryFilter::from_mask(LayerMask::from([GameLayer::Asteroid]));
+        let spaceship_filter =
+            SpatialQueryFilter::from_mask(LayerMask::from([GameLayer::Spaceship]));
+
+        let mut overlapping_asteroids = params.p0().shape_intersections(
+            &teleport.collider,
+            teleport.position,
+            teleport.rotation,
+            &asteroid_filter,
+        );
+        // Sorted so the kill loop below visits overlapping nateroids in the same order on every
+        // peer - `shape_intersections` makes no ordering guarantee, and that's the one piece of
+        // this resolution that otherwise depends on arbitrary query/broadphase order.
+        overlapping_asteroids.sort();
+
+        let overlapping_spaceship = params.p0().shape_intersections(
+            &teleport.collider,
+            teleport.position,
+            teleport.rotation,
+            &spaceship_filter,
         );
-        collision_state.last_field_crowded = Some(field_is_crowded);
+
+        // Then, mutate nateroid health/collision layers
+        let mut nateroid_query = params.p1();
+
+        // Check if we should be aggressive based on spawn success rate
+        // Lower spawn success rate = field is crowded = be more aggressive
+        let spawn_success_rate = spawn_stats.success_rate();
+        let field_is_crowded = spawn_success_rate < config.density_culling_threshold;
+
+        // Kill overlapping asteroids (but not the teleporting entity)
+        // Only kill nateroid-on-nateroid overlaps if field is crowded
+        let is_teleporting_nateroid = nateroid_query.get(teleport.entity).is_ok();
+
+        // Debug logging - only log when crowded state changes
+        if (!overlapping_asteroids.is_empty() || !overlapping_spaceship.is_empty())
+            && collision_state.last_field_crowded != Some(field_is_crowded)
+        {
+            info!(
+                "🔍 Teleport collision detected - attempts: {}, successes: {}, rate: {:.1}%, threshold: {:.1}%, crowded: {}, is_nateroid: {}",
+                spawn_stats.attempts_count(),
+                spawn_stats.successes_count(),
+                spawn_success_rate * 100.0,
+                config.density_culling_threshold * 100.0,
+                field_is_crowded,
+                is_teleporting_nateroid
+            );
+            collision_state.last_field_crowded = Some(field_is_crowded);
+        }
+
+        for entity in overlapping_asteroids {
+            if entity == teleport.entity {
+                continue;
+            }
+
+            if let Ok((mut collision_layers, mut health)) = nateroid_query.get_mut(entity) {
+                // Always kill if spaceship teleported, or if field is crowded
+                if !is_teleporting_nateroid || field_is_crowded {
+                    info!(
+                        "💀 Killing overlapping nateroid - spaceship_teleported: {}, field_crowded: {}",
+                        !is_teleporting_nateroid, field_is_crowded
+                    );
+                    *collision_layers = CollisionLayers::NONE;
+                    health.0 = -1.0;
+                }
+            }
+        }
+
+        // If a nateroid teleported onto the spaceship, always kill the nateroid
+        if is_teleporting_nateroid
+            && !overlapping_spaceship.is_empty()
+            && let Ok((mut collision_layers, mut health)) = nateroid_query.get_mut(teleport.entity)
+        {
+            info!("💀 Nateroid teleported onto spaceship - killing nateroid");
+            *collision_layers = CollisionLayers::NONE;
+            health.0 = -1.0;
+        }
     }
+}
 
-    for entity in overlapping_asteroids {
-        if entity == event.entity {
+/// Runs right after `teleport_at_boundary`, as its own explicit pass rather than folded into that
+/// system, so the velocity carry-through is a distinct, readable step: re-reads
+/// `Teleporter::just_teleported`/`last_teleported_normal` on whatever just wrapped and reflects
+/// the component of its `LinearVelocity` along that normal by
+/// `Boundary::wrap_velocity_kick_factor` - `0.0` (the default) leaves velocity fully continuous
+/// across the wrap, `1.0` gives a full elastic bounce, anything between is a partial-bounce
+/// "kick". `AngularVelocity` needs no rewriting here - `teleport_at_boundary` never touches it, so
+/// it already carries through the wrap unchanged and nateroids keep spinning continuously - but
+/// this is the natural hook point for a future camera-shake/g-force spike on player-ship wraps.
+fn apply_teleport_kick(
+    boundary: Res<Boundary>,
+    mut teleported: Query<(&Teleporter, &mut LinearVelocity)>,
+) {
+    for (teleporter, mut velocity) in &mut teleported {
+        if !teleporter.just_teleported {
             continue;
         }
+        let Some(normal) = teleporter.last_teleported_normal else {
+            continue;
+        };
 
-        if let Ok((mut collision_layers, mut health)) = nateroid_query.get_mut(entity) {
-            // Always kill if spaceship teleported, or if field is crowded
-            if !is_teleporting_nateroid || field_is_crowded {
-                info!(
-                    "💀 Killing overlapping nateroid - spaceship_teleported: {}, field_crowded: {}",
-                    !is_teleporting_nateroid, field_is_crowded
-                );
-                *collision_layers = CollisionLayers::NONE;
-                health.0 = -1.0;
+        let normal = normal.as_vec3();
+        let normal_component = velocity.0.dot(normal);
+        velocity.0 -= boundary.wrap_velocity_kick_factor * 2.0 * normal_component * normal;
+    }
+}
+
+/// Number of widening steps tried between [`PortalConfig::nudge_base_distance`] and
+/// [`PortalConfig::nudge_max_distance`] before an emergence is suppressed as unsafe.
+const SAFENUDGE_STEPS: u32 = 6;
+
+/// Lateral offsets (in units of the current step's distance, along the exit face's tangent
+/// plane) tried at each step, inward-first - modeled on Xonotic's `SAFENUDGE`/`SAFERNUDGE`.
+const SAFENUDGE_LATERAL_OFFSETS: [Vec2; 5] =
+    [Vec2::ZERO, Vec2::X, Vec2::NEG_X, Vec2::Y, Vec2::NEG_Y];
+
+/// Finds a spot near `position` where `collider` doesn't overlap another body, widening the
+/// search inward along `exit_face`'s normal (and laterally along its tangent plane) in
+/// increasing steps. Returns `None` if nothing within `portal_config`'s configured bound is
+/// clear, so the caller can suppress the teleport rather than let it telefrag.
+fn find_safe_emergence_position(
+    spatial_query: &SpatialQuery,
+    portal_config: &PortalConfig,
+    collider: &Collider,
+    rotation: Quat,
+    excluded_entity: Entity,
+    position: Vec3,
+    exit_face: BoundaryFace,
+) -> Option<Vec3> {
+    let filter = SpatialQueryFilter::default().with_excluded_entities([excluded_entity]);
+    let is_clear = |candidate: Vec3| {
+        spatial_query
+            .shape_intersections(collider, candidate, rotation, &filter)
+            .is_empty()
+    };
+
+    if is_clear(position) {
+        return Some(position);
+    }
+
+    let normal = exit_face.get_normal();
+    let tangent_u = exit_face.tangent_u();
+    let tangent_v = normal.cross(tangent_u);
+
+    for step in 1..=SAFENUDGE_STEPS {
+        let t = step as f32 / SAFENUDGE_STEPS as f32;
+        let distance = portal_config.nudge_base_distance
+            + (portal_config.nudge_max_distance - portal_config.nudge_base_distance) * t;
+
+        for lateral in SAFENUDGE_LATERAL_OFFSETS {
+            let candidate = position - normal * distance
+                + tangent_u * (lateral.x * distance)
+                + tangent_v * (lateral.y * distance);
+
+            if is_clear(candidate) {
+                return Some(candidate);
             }
         }
     }
 
-    // If a nateroid teleported onto the spaceship, always kill the nateroid
-    if is_teleporting_nateroid
-        && !overlapping_spaceship.is_empty()
-        && let Ok((mut collision_layers, mut health)) = nateroid_query.get_mut(event.entity)
-    {
-        info!("💀 Nateroid teleported onto spaceship - killing nateroid");
-        *collision_layers = CollisionLayers::NONE;
-        health.0 = -1.0;
-    }
+    None
 }
 
 #[allow(clippy::type_complexity)]
 pub fn teleport_at_boundary(
     boundary: Res<Boundary>,
+    portal_config: Res<PortalConfig>,
+    spatial_query: SpatialQuery,
+    time: Res<Time>,
     mut commands: Commands,
+    mut pending_overlaps: ResMut<PendingTeleportOverlaps>,
     mut teleporting_entities: Query<(
         Entity,
         &mut Transform,
         &mut Teleporter,
         &Collider,
+        Option<&mut LinearVelocity>,
         Option<&Name>,
         Option<&Spaceship>,
         Option<&Deaderoid>,
     )>,
 ) {
-    for (entity, mut transform, mut teleporter, collider, name, is_spaceship, is_deaderoid) in
-        teleporting_entities.iter_mut()
+    for (
+        entity,
+        mut transform,
+        mut teleporter,
+        collider,
+        mut linear_velocity,
+        name,
+        is_spaceship,
+        is_deaderoid,
+    ) in teleporting_entities.iter_mut()
     {
         let original_position = transform.translation;
 
-        let teleported_position = boundary.calculate_teleport_position(original_position);
+        match boundary.resolve_boundary_crossing(original_position) {
+            BoundaryCrossing::None => {
+                teleporter.just_teleported = false;
+                teleporter.last_teleported_position = None;
+                teleporter.last_teleported_normal = None;
+            },
+            BoundaryCrossing::Stopped(position) => {
+                transform.translation = position;
+                if let Some(ref mut velocity) = linear_velocity {
+                    velocity.0 = Vec3::ZERO;
+                }
+                teleporter.just_teleported = false;
+                teleporter.last_teleported_position = None;
+                teleporter.last_teleported_normal = None;
+            },
+            BoundaryCrossing::Reflected { position, normal } => {
+                transform.translation = position;
+                if let Some(ref mut velocity) = linear_velocity {
+                    let normal = normal.as_vec3();
+                    velocity.0 -= 2.0 * velocity.0.dot(normal) * normal;
+                }
+                teleporter.just_teleported = false;
+                teleporter.last_teleported_position = None;
+                teleporter.last_teleported_normal = None;
+            },
+            BoundaryCrossing::Wrapped(teleported_position) => {
+                // If this is a dying nateroid, despawn it instead of teleporting
+                if is_deaderoid.is_some() {
+                    despawn(&mut commands, entity);
+                    continue;
+                }
 
-        if teleported_position != original_position {
-            // If this is a dying nateroid, despawn it instead of teleporting
-            if is_deaderoid.is_some() {
-                despawn(&mut commands, entity);
-                continue;
-            }
+                // Prefer the true swept exit point/face over the axis-independent wrap above, so
+                // a fast body that tunnels clean through the boundary in one frame still wraps at
+                // the exact point/face it crossed rather than wherever physics left it this frame.
+                let delta_secs = time.delta_secs();
+                let swept_crossing = linear_velocity.as_ref().and_then(|velocity| {
+                    let velocity_delta = velocity.0 * delta_secs;
+                    boundary.crossing(original_position - velocity_delta, velocity_delta)
+                });
 
-            // Only log spaceship teleports
-            if is_spaceship.is_some() {
-                let entity_name = name.map(|n| (*n).as_str()).unwrap_or("Spaceship");
-                debug!(
-                    "🔄 {} teleporting: from ({:.1}, {:.1}, {:.1}) to ({:.1}, {:.1}, {:.1})",
-                    entity_name,
-                    original_position.x,
-                    original_position.y,
-                    original_position.z,
-                    teleported_position.x,
-                    teleported_position.y,
-                    teleported_position.z
+                let (teleported_position, exit_face) = match swept_crossing {
+                    Some((exit_point, exit_face, _t)) => (exit_point, exit_face),
+                    None => {
+                        let exit_normal = boundary.get_normal_for_position(teleported_position);
+                        let Some(exit_face) = BoundaryFace::from_normal(exit_normal) else {
+                            continue;
+                        };
+                        (teleported_position, exit_face)
+                    },
+                };
+
+                let Some(safe_position) = find_safe_emergence_position(
+                    &spatial_query,
+                    &portal_config,
+                    collider,
+                    transform.rotation,
+                    entity,
+                    teleported_position,
+                    exit_face,
+                ) else {
+                    // No clear emergence spot within the configured bound - stay put and try
+                    // again next tick rather than telefragging whatever is hugging the wall.
+                    continue;
+                };
+
+                // Only log spaceship teleports
+                if is_spaceship.is_some() {
+                    let entity_name = name.map(|n| (*n).as_str()).unwrap_or("Spaceship");
+                    debug!(
+                        "🔄 {} teleporting: from ({:.1}, {:.1}, {:.1}) to ({:.1}, {:.1}, {:.1})",
+                        entity_name,
+                        original_position.x,
+                        original_position.y,
+                        original_position.z,
+                        safe_position.x,
+                        safe_position.y,
+                        safe_position.z
+                    );
+                }
+
+                transform.translation = safe_position;
+                teleporter.just_teleported = true;
+                teleporter.last_teleported_position = Some(safe_position);
+                teleporter.last_teleported_normal =
+                    Some(boundary.get_normal_for_position(safe_position));
+
+                // Rotate the actor's velocity by the entry/exit pair's portal transform - identity
+                // for the default straight wrap, a real turn for a configured cross-axis pairing
+                let entry_face = BoundaryFace::from_normal(
+                    boundary.get_normal_for_position(original_position),
                 );
-            }
+                let exit_rotation = entry_face
+                    .map(|entry| portal_config.transform_for(entry).rotation())
+                    .unwrap_or(Quat::IDENTITY);
+                teleporter.last_exit_rotation = exit_rotation;
+
+                if let Some(ref mut velocity) = linear_velocity {
+                    velocity.0 = exit_rotation * velocity.0;
+                }
 
-            transform.translation = teleported_position;
-            teleporter.just_teleported = true;
-            teleporter.last_teleported_position = Some(teleported_position);
-            teleporter.last_teleported_normal =
-                Some(boundary.get_normal_for_position(teleported_position));
-
-            // Trigger event to handle overlapping entities
-            commands.trigger(Teleported {
-                entity,
-                position: teleported_position,
-                rotation: transform.rotation,
-                collider: collider.clone(),
-            });
-        } else {
-            teleporter.just_teleported = false;
-            teleporter.last_teleported_position = None;
-            teleporter.last_teleported_normal = None;
+                // Queue this teleport for `resolve_teleport_overlaps`, which runs right after this
+                // system in the same set, instead of triggering an observer whose flush timing
+                // isn't part of that ordering.
+                pending_overlaps.0.push(PendingTeleportOverlap {
+                    entity,
+                    position: safe_position,
+                    rotation: transform.rotation,
+                    collider: collider.clone(),
+                });
+            },
         }
     }
 }
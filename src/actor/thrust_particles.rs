@@ -0,0 +1,244 @@
+use avian3d::prelude::LinearVelocity;
+use bevy::camera::visibility::RenderLayers;
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+use leafwing_input_manager::action_state::ActionState;
+use rand::Rng;
+
+use super::constants::THRUST_PARTICLE_CONE_HALF_ANGLE;
+use super::constants::THRUST_PARTICLE_END_SIZE;
+use super::constants::THRUST_PARTICLE_JITTER;
+use super::constants::THRUST_PARTICLE_LIFETIME_MAX;
+use super::constants::THRUST_PARTICLE_LIFETIME_MIN;
+use super::constants::THRUST_PARTICLE_OFFSET;
+use super::constants::THRUST_PARTICLE_POOL_CAPACITY;
+use super::constants::THRUST_PARTICLE_SPAWN_RATE_MAX;
+use super::constants::THRUST_PARTICLE_SPAWN_RATE_MIN;
+use super::constants::THRUST_PARTICLE_SPEED_MAX;
+use super::constants::THRUST_PARTICLE_SPEED_MIN;
+use super::constants::THRUST_PARTICLE_START_SIZE;
+use super::constants::THRUST_PARTICLE_VELOCITY_FOR_MAX_DENSITY;
+use super::spaceship::Spaceship;
+use super::spaceship_control::SpaceshipControl;
+use crate::camera::RenderLayer;
+
+pub struct ThrustParticlePlugin;
+
+impl Plugin for ThrustParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_particle_pool).add_systems(
+            Update,
+            (emit_thrust_particles, update_thrust_particles).chain(),
+        );
+    }
+}
+
+/// One pooled particle's simulation state. `start_*`/`end_*` are lerped by `age / lifetime` each
+/// frame in [`update_thrust_particles`]; the pool never allocates a new entity to spawn a
+/// particle or despawns one when it dies - only this component's fields and the entity's own
+/// `Visibility` change.
+#[derive(Component, Default)]
+struct ThrustParticle {
+    velocity:    Vec3,
+    age:         f32,
+    lifetime:    f32,
+    start_color: Color,
+    end_color:   Color,
+    start_size:  f32,
+    end_size:    f32,
+}
+
+/// Tags a pooled particle entity still carrying its `MeshMaterial3d` handle, so
+/// [`update_thrust_particles`] can mutate its color in place instead of allocating a new material
+/// per frame.
+#[derive(Component)]
+struct ThrustParticleMaterial(Handle<StandardMaterial>);
+
+/// Fixed-capacity pool of pre-spawned particle entities - `free`/`active` index lists, same
+/// claim/release shape as `flame_gizmo::TempEffects`, except the "slot" here is a real rendered
+/// `Entity` rather than gizmo-draw state.
+#[derive(Resource, Default)]
+struct ThrustParticlePool {
+    entities: Vec<Entity>,
+    free:     Vec<usize>,
+    active:   Vec<usize>,
+}
+
+fn spawn_particle_pool(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.add(Sphere::new(0.5));
+
+    let mut pool = ThrustParticlePool {
+        entities: Vec::with_capacity(THRUST_PARTICLE_POOL_CAPACITY),
+        free:     (0..THRUST_PARTICLE_POOL_CAPACITY).rev().collect(),
+        active:   Vec::with_capacity(THRUST_PARTICLE_POOL_CAPACITY),
+    };
+
+    for _ in 0..THRUST_PARTICLE_POOL_CAPACITY {
+        let material = materials.add(StandardMaterial {
+            base_color: Color::from(tailwind::YELLOW_400),
+            emissive: LinearRgba::from(Color::from(tailwind::YELLOW_400)),
+            unlit: true,
+            ..default()
+        });
+
+        let entity = commands
+            .spawn((
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::IDENTITY,
+                Visibility::Hidden,
+                RenderLayers::from_layers(RenderLayer::Game.layers()),
+                ThrustParticle::default(),
+                ThrustParticleMaterial(material),
+                Name::new("ThrustParticle"),
+            ))
+            .id();
+
+        pool.entities.push(entity);
+    }
+
+    commands.insert_resource(pool);
+}
+
+/// While `SpaceshipControl::Accelerate` is held, claims free pool slots at a rate and initial
+/// speed that scale with the spaceship's own `LinearVelocity` - harder acceleration means a
+/// denser, faster plume, up to `THRUST_PARTICLE_VELOCITY_FOR_MAX_DENSITY`.
+fn emit_thrust_particles(
+    time: Res<Time>,
+    mut pool: ResMut<ThrustParticlePool>,
+    mut spawn_accumulator: Local<f32>,
+    spaceship_query: Query<
+        (&Transform, &LinearVelocity, &ActionState<SpaceshipControl>),
+        With<Spaceship>,
+    >,
+    mut particles: Query<(&mut ThrustParticle, &mut Transform, &mut Visibility), Without<Spaceship>>,
+) {
+    let Ok((transform, velocity, controls)) = spaceship_query.single() else {
+        return;
+    };
+
+    if !controls.pressed(&SpaceshipControl::Accelerate) {
+        return;
+    }
+
+    let speed_factor = (velocity.length() / THRUST_PARTICLE_VELOCITY_FOR_MAX_DENSITY).clamp(0.0, 1.0);
+    let spawn_rate = THRUST_PARTICLE_SPAWN_RATE_MIN
+        + (THRUST_PARTICLE_SPAWN_RATE_MAX - THRUST_PARTICLE_SPAWN_RATE_MIN) * speed_factor;
+
+    *spawn_accumulator += spawn_rate * time.delta_secs();
+
+    let back_direction = -transform.forward().as_vec3();
+    let right = transform.right().as_vec3();
+    let up = transform.up().as_vec3();
+    let base_position = transform.translation + back_direction * THRUST_PARTICLE_OFFSET;
+
+    let mut rng = rand::rng();
+
+    while *spawn_accumulator >= 1.0 {
+        *spawn_accumulator -= 1.0;
+
+        let Some(index) = pool.free.pop() else {
+            break;
+        };
+
+        let speed = THRUST_PARTICLE_SPEED_MIN
+            + (THRUST_PARTICLE_SPEED_MAX - THRUST_PARTICLE_SPEED_MIN) * speed_factor;
+
+        let cone_angle = rng.random_range(-THRUST_PARTICLE_CONE_HALF_ANGLE..THRUST_PARTICLE_CONE_HALF_ANGLE);
+        let cone_roll = rng.random_range(0.0..std::f32::consts::TAU);
+        let spread_direction =
+            (back_direction + (right * cone_roll.cos() + up * cone_roll.sin()) * cone_angle.sin())
+                .normalize();
+
+        let jitter = Vec3::new(
+            rng.random_range(-1.0..1.0),
+            rng.random_range(-1.0..1.0),
+            rng.random_range(-1.0..1.0),
+        ) * THRUST_PARTICLE_JITTER
+            * speed;
+
+        let entity = pool.entities[index];
+        let Ok((mut particle, mut particle_transform, mut visibility)) = particles.get_mut(entity)
+        else {
+            continue;
+        };
+
+        *particle = ThrustParticle {
+            velocity: spread_direction * speed + jitter,
+            age: 0.0,
+            lifetime: rng.random_range(THRUST_PARTICLE_LIFETIME_MIN..THRUST_PARTICLE_LIFETIME_MAX),
+            start_color: Color::from(tailwind::YELLOW_400),
+            end_color: Color::from(tailwind::RED_600),
+            start_size: THRUST_PARTICLE_START_SIZE,
+            end_size: THRUST_PARTICLE_END_SIZE,
+        };
+        particle_transform.translation = base_position;
+        particle_transform.scale = Vec3::splat(particle.start_size);
+        *visibility = Visibility::Visible;
+
+        pool.active.push(index);
+    }
+}
+
+/// Ages every active particle, integrates `position += velocity * dt`, lerps color and size from
+/// `start_*` to `end_*` over `age / lifetime`, and releases the slot back to the pool once
+/// `age >= lifetime` - no entity is ever spawned or despawned after `spawn_particle_pool` runs.
+fn update_thrust_particles(
+    time: Res<Time>,
+    mut pool: ResMut<ThrustParticlePool>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particles: Query<(
+        &mut ThrustParticle,
+        &mut Transform,
+        &mut Visibility,
+        &ThrustParticleMaterial,
+    )>,
+) {
+    let dt = time.delta_secs();
+    let mut expired = Vec::new();
+
+    for &index in &pool.active {
+        let entity = pool.entities[index];
+        let Ok((mut particle, mut transform, mut visibility, material)) = particles.get_mut(entity)
+        else {
+            continue;
+        };
+
+        particle.age += dt;
+        if particle.age >= particle.lifetime {
+            *visibility = Visibility::Hidden;
+            expired.push(index);
+            continue;
+        }
+
+        transform.translation += particle.velocity * dt;
+
+        let progress = (particle.age / particle.lifetime).clamp(0.0, 1.0);
+        let size = particle.start_size + (particle.end_size - particle.start_size) * progress;
+        transform.scale = Vec3::splat(size);
+
+        if let Some(material) = materials.get_mut(&material.0) {
+            let color = lerp_color(particle.start_color, particle.end_color, progress);
+            let fade = 1.0 - progress;
+            let linear = color.to_linear();
+            material.base_color = color;
+            material.emissive = LinearRgba::new(
+                linear.red * fade,
+                linear.green * fade,
+                linear.blue * fade,
+                linear.alpha,
+            );
+        }
+    }
+
+    pool.active.retain(|index| !expired.contains(index));
+    pool.free.extend(expired);
+}
+
+fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    Color::from(a.to_linear().mix(&b.to_linear(), t.clamp(0.0, 1.0)))
+}
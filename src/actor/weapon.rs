@@ -0,0 +1,311 @@
+//! Weapon-manager subsystem: the catalog of installed [`WeaponSlot`]s a spaceship can fire from
+//! (tracked by [`WeaponLoadout`]), which one is currently active, and the data-driven
+//! [`WeaponConfig`] that defines them - inspector-tunable live, same as other `*Config` resources,
+//! so a new weapon can be prototyped without a recompile. `missile.rs` reads the active slot's
+//! stats to decide what actually gets spawned when the player fires.
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_inspector_egui::prelude::*;
+use bevy_inspector_egui::quick::ResourceInspectorPlugin;
+use leafwing_input_manager::prelude::*;
+
+use super::constants::MISSILE_ACQUISITION_RANGE;
+use super::constants::MISSILE_BASE_VELOCITY;
+use super::constants::MISSILE_FORWARD_DISTANCE_SCALAR;
+use super::constants::MISSILE_MAX_TURN_RATE;
+use super::constants::MISSILE_SPAWN_TIMER_SECONDS;
+use super::spaceship_control::SpaceshipControl;
+use crate::game_input::GameAction;
+use crate::game_input::toggle_active;
+use crate::schedule::InGameSet;
+
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WeaponConfig>()
+            .add_plugins(
+                ResourceInspectorPlugin::<WeaponConfig>::default()
+                    .run_if(toggle_active(false, GameAction::WeaponConfigInspector)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (sync_weapon_config, cycle_weapon)
+                    .chain()
+                    .in_set(InGameSet::UserInput),
+            );
+    }
+}
+
+/// One data-driven weapon definition - everything [`sync_weapon_config`] needs to (re)build or
+/// update a [`WeaponSlot`]. Lives in [`WeaponConfig`] rather than directly in [`WeaponSlot`] so the
+/// inspector can add/remove/retune entries without touching the running `spawn_timer`.
+#[derive(Reflect, Clone, Debug)]
+pub struct WeaponDef {
+    pub name:                    String,
+    pub forward_distance_scalar: f32,
+    pub base_velocity:           f32,
+    /// When true, missiles solve a lead-intercept aim point against the nearest `Nateroid`
+    /// instead of firing straight ahead.
+    pub lead_targeting:          bool,
+    /// Targets farther than this are ignored by lead targeting.
+    pub max_lock_range:          f32,
+    /// When true, missiles curve toward the nearest `Nateroid` in range for their whole flight
+    /// instead of flying the straight line they were launched on.
+    pub guided:                  bool,
+    /// Targets farther than this are ignored by homing guidance.
+    pub acquisition_range:       f32,
+    /// Maximum rotation rate (radians/sec) guidance may apply.
+    pub max_turn_rate:           f32,
+    /// Missiles fired per shot, spread evenly across `spread_angle` around forward.
+    pub spawn_count:             u32,
+    /// Total yaw arc (radians) the `spawn_count` missiles fan across; ignored when `spawn_count`
+    /// is 1.
+    pub spread_angle:            f32,
+    pub interval_secs:           f32,
+}
+
+/// Catalog of weapon definitions a spaceship can carry, registered with the inspector so new
+/// weapons can be prototyped and tuned live instead of only through `constants.rs`. Modeled on the
+/// weapon-manager/multiple-gun structure (test_gun, turret, cannon) from the orxonox spaceship
+/// code.
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource)]
+pub struct WeaponConfig {
+    pub defs: Vec<WeaponDef>,
+}
+
+impl Default for WeaponConfig {
+    fn default() -> Self {
+        Self {
+            defs: vec![
+                WeaponDef {
+                    name:                    "missile".to_string(),
+                    forward_distance_scalar: MISSILE_FORWARD_DISTANCE_SCALAR,
+                    base_velocity:           MISSILE_BASE_VELOCITY,
+                    lead_targeting:          false,
+                    max_lock_range:          MISSILE_ACQUISITION_RANGE,
+                    guided:                  false,
+                    acquisition_range:       MISSILE_ACQUISITION_RANGE,
+                    max_turn_rate:           MISSILE_MAX_TURN_RATE,
+                    spawn_count:             1,
+                    spread_angle:            0.0,
+                    interval_secs:           MISSILE_SPAWN_TIMER_SECONDS,
+                },
+                WeaponDef {
+                    name:                    "spread_cannon".to_string(),
+                    forward_distance_scalar: MISSILE_FORWARD_DISTANCE_SCALAR,
+                    base_velocity:           MISSILE_BASE_VELOCITY * 0.8,
+                    lead_targeting:          false,
+                    max_lock_range:          MISSILE_ACQUISITION_RANGE,
+                    guided:                  false,
+                    acquisition_range:       MISSILE_ACQUISITION_RANGE,
+                    max_turn_rate:           MISSILE_MAX_TURN_RATE,
+                    spawn_count:             5,
+                    spread_angle:            std::f32::consts::FRAC_PI_4,
+                    interval_secs:           MISSILE_SPAWN_TIMER_SECONDS * 3.0,
+                },
+                WeaponDef {
+                    name:                    "heavy_torpedo".to_string(),
+                    forward_distance_scalar: MISSILE_FORWARD_DISTANCE_SCALAR,
+                    base_velocity:           MISSILE_BASE_VELOCITY * 0.4,
+                    lead_targeting:          false,
+                    max_lock_range:          MISSILE_ACQUISITION_RANGE,
+                    guided:                  true,
+                    acquisition_range:       MISSILE_ACQUISITION_RANGE,
+                    max_turn_rate:           MISSILE_MAX_TURN_RATE,
+                    spawn_count:             1,
+                    spread_angle:            0.0,
+                    interval_secs:           MISSILE_SPAWN_TIMER_SECONDS * 8.0,
+                },
+            ],
+        }
+    }
+}
+
+impl WeaponConfig {
+    fn build_slots(&self) -> Vec<WeaponSlot> {
+        self.defs.iter().map(WeaponSlot::from_def).collect()
+    }
+}
+
+/// One configured weapon: its own flight speed, spawn distance, targeting behavior, and spawn
+/// cadence/spread. All slots share the same physical `Missile` actor (collider, mass, scene - see
+/// `MissileConfig::actor_config`); what differs per slot is how firing it behaves.
+#[derive(Reflect, Clone, Debug)]
+pub struct WeaponSlot {
+    pub name:                    String,
+    pub forward_distance_scalar: f32,
+    pub base_velocity:           f32,
+    pub lead_targeting:          bool,
+    pub max_lock_range:          f32,
+    pub guided:                  bool,
+    pub acquisition_range:       f32,
+    pub max_turn_rate:           f32,
+    pub spawn_count:             u32,
+    pub spread_angle:            f32,
+    #[reflect(ignore)]
+    spawn_timer:                 Timer,
+}
+
+impl WeaponSlot {
+    fn from_def(def: &WeaponDef) -> Self {
+        Self {
+            name:                    def.name.clone(),
+            forward_distance_scalar: def.forward_distance_scalar,
+            base_velocity:           def.base_velocity,
+            lead_targeting:          def.lead_targeting,
+            max_lock_range:          def.max_lock_range,
+            guided:                  def.guided,
+            acquisition_range:       def.acquisition_range,
+            max_turn_rate:           def.max_turn_rate,
+            spawn_count:             def.spawn_count,
+            spread_angle:            def.spread_angle,
+            spawn_timer:             Timer::from_seconds(def.interval_secs.max(0.01), TimerMode::Repeating),
+        }
+    }
+
+    /// Copies every field from `def` except the running `spawn_timer`, whose duration is
+    /// re-derived through `set_interval` so a rate change takes effect immediately rather than
+    /// waiting for the timer to next finish on the old interval.
+    fn apply_def(&mut self, def: &WeaponDef) {
+        self.name = def.name.clone();
+        self.forward_distance_scalar = def.forward_distance_scalar;
+        self.base_velocity = def.base_velocity;
+        self.lead_targeting = def.lead_targeting;
+        self.max_lock_range = def.max_lock_range;
+        self.guided = def.guided;
+        self.acquisition_range = def.acquisition_range;
+        self.max_turn_rate = def.max_turn_rate;
+        self.spawn_count = def.spawn_count;
+        self.spread_angle = def.spread_angle;
+        self.set_interval(def.interval_secs);
+    }
+
+    /// Changes this slot's firing cadence, re-deriving the running timer's duration so
+    /// [`super::loadout::apply_loadout`]'s fire-rate bonus takes effect immediately rather than
+    /// waiting for the timer to next finish on the old interval.
+    pub fn set_interval(&mut self, seconds: f32) {
+        self.spawn_timer
+            .set_duration(Duration::from_secs_f32(seconds.max(0.01)));
+    }
+
+    pub fn interval(&self) -> f32 { self.spawn_timer.duration().as_secs_f32() }
+
+    /// Advances the spawn timer by `delta` and reports whether it just completed a cycle -
+    /// i.e. whether this slot is ready to fire again.
+    pub fn tick_spawn_timer(&mut self, delta: Duration) -> bool {
+        self.spawn_timer.tick(delta);
+        self.spawn_timer.just_finished()
+    }
+}
+
+/// Installed weapon slots and which one is currently firing, cycled by
+/// `SpaceshipControl::NextWeapon`/`PrevWeapon`. Lets `fire_missile` read per-weapon flight
+/// characteristics instead of a single hardcoded missile config.
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct WeaponLoadout {
+    pub slots:  Vec<WeaponSlot>,
+    pub active: usize,
+}
+
+impl WeaponLoadout {
+    pub fn active_slot(&self) -> &WeaponSlot { &self.slots[self.active] }
+
+    pub fn active_slot_mut(&mut self) -> &mut WeaponSlot { &mut self.slots[self.active] }
+
+    pub fn cycle_next(&mut self) { self.active = (self.active + 1) % self.slots.len(); }
+
+    pub fn cycle_prev(&mut self) {
+        self.active = (self.active + self.slots.len() - 1) % self.slots.len();
+    }
+}
+
+impl Default for WeaponLoadout {
+    fn default() -> Self {
+        let slots = WeaponConfig::default().build_slots();
+        Self { slots, active: 0 }
+    }
+}
+
+/// Snapshot of the firing weapon slot's flight parameters, captured at spawn time so
+/// `initialize_missile` doesn't need to guess which slot (or spread offset) produced this
+/// particular missile.
+#[derive(Component, Reflect, Copy, Clone, Debug)]
+#[reflect(Component)]
+pub struct FiredWeapon {
+    pub forward_distance_scalar: f32,
+    pub base_velocity:           f32,
+    pub lead_targeting:          bool,
+    pub max_lock_range:          f32,
+    pub guided:                  bool,
+    pub acquisition_range:       f32,
+    pub max_turn_rate:           f32,
+    /// Yaw offset (radians), around the spaceship's turn axis, from its forward direction -
+    /// nonzero only for a spread weapon's fan of missiles.
+    pub yaw_offset:              f32,
+}
+
+impl FiredWeapon {
+    pub fn from_slot(slot: &WeaponSlot, yaw_offset: f32) -> Self {
+        Self {
+            forward_distance_scalar: slot.forward_distance_scalar,
+            base_velocity: slot.base_velocity,
+            lead_targeting: slot.lead_targeting,
+            max_lock_range: slot.max_lock_range,
+            guided: slot.guided,
+            acquisition_range: slot.acquisition_range,
+            max_turn_rate: slot.max_turn_rate,
+            yaw_offset,
+        }
+    }
+}
+
+/// Evenly spaced yaw offsets (radians) for `spawn_count` missiles fanned across `spread_angle`,
+/// centered on zero. A single-missile weapon always yields one offset of `0.0`.
+pub fn spread_offsets(spawn_count: u32, spread_angle: f32) -> impl Iterator<Item = f32> {
+    let count = spawn_count.max(1);
+    (0..count).map(move |i| {
+        if count == 1 {
+            0.0
+        } else {
+            let t = i as f32 / (count - 1) as f32;
+            (t - 0.5) * spread_angle
+        }
+    })
+}
+
+/// Keeps `WeaponLoadout::slots` in lockstep with `WeaponConfig::defs`: grows/shrinks the slot list
+/// to match (clamping `active` so it never points past the end), and otherwise folds each def's
+/// tunable fields onto its slot so inspector edits to an existing weapon apply live.
+fn sync_weapon_config(
+    weapon_config: Res<WeaponConfig>,
+    mut weapon_loadout: ResMut<WeaponLoadout>,
+) {
+    if !weapon_config.is_changed() {
+        return;
+    }
+
+    if weapon_loadout.slots.len() != weapon_config.defs.len() {
+        weapon_loadout.slots = weapon_config.build_slots();
+        weapon_loadout.active = weapon_loadout.active.min(weapon_loadout.slots.len() - 1);
+        return;
+    }
+
+    for (slot, def) in weapon_loadout.slots.iter_mut().zip(&weapon_config.defs) {
+        slot.apply_def(def);
+    }
+}
+
+pub(crate) fn cycle_weapon(
+    controls: Single<&ActionState<SpaceshipControl>>,
+    mut weapon_loadout: ResMut<WeaponLoadout>,
+) {
+    if controls.just_pressed(&SpaceshipControl::NextWeapon) {
+        weapon_loadout.cycle_next();
+    } else if controls.just_pressed(&SpaceshipControl::PrevWeapon) {
+        weapon_loadout.cycle_prev();
+    }
+}
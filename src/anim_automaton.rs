@@ -0,0 +1,155 @@
+//! Generic keyframe animation automaton: a sequence of frame-range "sections" a playhead steps
+//! through, cross-fading toward the next frame as it goes. Extracted from the nateroid death
+//! animation's hardcoded linear-shrink/inverse-cubic-material-index logic (`despawn.rs`) so the
+//! same stepping/cross-fade code can drive other keyframed effects - a reversible fade, a looping
+//! idle shimmer, or chaining one section into another - without each one reinventing it.
+use bevy::prelude::*;
+
+/// A contiguous run of frame indices, e.g. the frames a "crumble" or "dissolve" effect occupies
+/// in a shared keyframe list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnimSection {
+    pub start_frame: usize,
+    pub end_frame:   usize,
+}
+
+impl AnimSection {
+    pub const fn frame_count(&self) -> usize { self.end_frame - self.start_frame + 1 }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnimDirection {
+    #[default]
+    Forward,
+    Reverse,
+}
+
+impl AnimDirection {
+    const fn flipped(self) -> Self {
+        match self {
+            Self::Forward => Self::Reverse,
+            Self::Reverse => Self::Forward,
+        }
+    }
+}
+
+/// Where playback hands off once a section plays out to its last frame in the current
+/// direction - an arbitrary section/direction rather than always advancing linearly to the next
+/// index, so e.g. a "crumble" section can chain into a "dissolve" section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionEdge {
+    pub target_section: usize,
+    pub direction:       AnimDirection,
+}
+
+/// Playhead over a list of [`AnimSection`]s. `current_frame` is the keyframe currently showing;
+/// `current_fade` (0..1) is how far playback has progressed toward `next_frame` - callers
+/// cross-fade or blend between the two however suits their effect.
+#[derive(Component, Debug, Clone)]
+pub struct AnimAutomaton {
+    pub sections:           Vec<AnimSection>,
+    pub current_section:    usize,
+    pub current_frame:      usize,
+    pub current_fade:       f32,
+    pub direction:          AnimDirection,
+    /// One-shot override consumed the next time playback reaches a section boundary; `None`
+    /// means "hold at the boundary frame" once the section runs out.
+    pub next_edge_override: Option<SectionEdge>,
+}
+
+impl AnimAutomaton {
+    /// Starts at the first frame of `sections[0]`, playing forward.
+    pub fn new(sections: Vec<AnimSection>) -> Self {
+        let current_frame = sections[0].start_frame;
+        Self {
+            sections,
+            current_section: 0,
+            current_frame,
+            current_fade: 0.0,
+            direction: AnimDirection::Forward,
+            next_edge_override: None,
+        }
+    }
+
+    /// Flips playback direction in place, without resetting position - the "revive" use case:
+    /// a death fade-out can reverse mid-flight into a fade-in along the same frames.
+    pub fn reverse(&mut self) { self.direction = self.direction.flipped(); }
+
+    /// Jumps directly to the start frame of `section`, discarding any in-progress cross-fade.
+    pub fn jump_to(&mut self, section: usize) {
+        self.current_section = section;
+        self.current_frame = self.sections[section].start_frame;
+        self.current_fade = 0.0;
+    }
+
+    /// The frame `current_fade` is easing toward - one step further in the current direction,
+    /// clamped to the current section (a looping idle shimmer should use [`Self::jump_to`] or
+    /// `next_edge_override` rather than reading past the section's own end).
+    pub fn next_frame(&self) -> usize {
+        let section = self.sections[self.current_section];
+        match self.direction {
+            AnimDirection::Forward => self.current_frame.min(section.end_frame.saturating_sub(1)) + 1,
+            AnimDirection::Reverse => self.current_frame.max(section.start_frame + 1) - 1,
+        }
+    }
+
+    /// Sets the playhead to an absolute position within the current section, where `progress`
+    /// (0..1) is measured in the current [`AnimDirection`] - for effects driven by an external
+    /// eased/time-based curve (like the nateroid death shrink) rather than per-frame stepping.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn set_progress(&mut self, progress: f32) {
+        let section = self.sections[self.current_section];
+        #[allow(clippy::cast_precision_loss)]
+        let span = (section.frame_count() - 1).max(1) as f32;
+        let progress = progress.clamp(0.0, 1.0);
+        let offset = progress * span;
+        let frame_offset = offset.floor() as usize;
+
+        self.current_fade = offset - frame_offset as f32;
+        self.current_frame = match self.direction {
+            AnimDirection::Forward => section.start_frame + frame_offset,
+            AnimDirection::Reverse => section.end_frame - frame_offset,
+        };
+    }
+
+    /// Advances the cross-fade by `delta` (a fraction of one frame), stepping to the next frame
+    /// - and across a section edge - each time it overflows past `1.0`.
+    pub fn advance(&mut self, delta: f32) {
+        self.current_fade += delta;
+        while self.current_fade >= 1.0 {
+            self.current_fade -= 1.0;
+            self.step_frame();
+        }
+    }
+
+    /// Whether playback has reached the last frame of the current section in its current
+    /// direction, with no `next_edge_override` queued to carry it further.
+    pub fn is_at_section_end(&self) -> bool {
+        self.next_edge_override.is_none() && self.at_boundary()
+    }
+
+    fn at_boundary(&self) -> bool {
+        let section = self.sections[self.current_section];
+        match self.direction {
+            AnimDirection::Forward => self.current_frame >= section.end_frame,
+            AnimDirection::Reverse => self.current_frame <= section.start_frame,
+        }
+    }
+
+    fn step_frame(&mut self) {
+        if self.at_boundary() {
+            let Some(edge) = self.next_edge_override.take() else {
+                return;
+            };
+            self.current_section = edge.target_section;
+            self.direction = edge.direction;
+            self.current_frame = self.sections[edge.target_section].start_frame;
+            return;
+        }
+
+        match self.direction {
+            AnimDirection::Forward => self.current_frame += 1,
+            AnimDirection::Reverse => self.current_frame -= 1,
+        }
+    }
+}
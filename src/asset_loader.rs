@@ -1,6 +1,15 @@
+use std::collections::HashMap;
+
+use bevy::asset::AssetLoader;
+use bevy::asset::LoadContext;
 use bevy::asset::LoadState;
+use bevy::asset::io::Reader;
 /// let's use just load assets once, amigos
 use bevy::prelude::*;
+use bevy::render::render_resource::Face;
+use serde::Deserialize;
+
+const ASSET_MANIFEST_PATH: &str = "content/asset_manifest.ron";
 
 pub struct AssetLoaderPlugin;
 
@@ -8,12 +17,18 @@ impl Plugin for AssetLoaderPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<AssetsState>() // necessary to tell if they've finished loading
             .init_resource::<SceneAssets>()
+            .init_resource::<LoadingProgress>()
+            .init_asset::<AssetManifest>()
+            .init_asset_loader::<AssetManifestLoader>()
             // make sure this loads before the spaceship uses it - right now that is
             // handled by running this PreStartup and spaceship in Startup
-            .add_systems(PreStartup, load_assets)
+            .add_systems(PreStartup, load_asset_manifest)
+            .add_systems(OnEnter(AssetsState::Loading), spawn_loading_screen)
+            .add_systems(OnExit(AssetsState::Loading), despawn_loading_screen)
             .add_systems(
                 Update,
-                (create_nateroid_material, check_asset_loading)
+                (populate_scene_assets, check_asset_loading, update_loading_screen)
+                    .chain()
                     .run_if(in_state(AssetsState::Loading)),
             );
     }
@@ -26,108 +41,344 @@ pub enum AssetsState {
     Loaded,
 }
 
-// all the models are loaded via SceneBundle - the models
-// can have multiple elements and scene makes all that possible
+/// All the models and baked materials used in the game, keyed by the same name the
+/// manifest uses for them. Populated by [`populate_scene_assets`] from whatever
+/// `assets/content/asset_manifest.ron` lists, so new actors/materials don't need a
+/// recompile to show up here.
 #[derive(Resource, Clone, Debug, Default)]
 pub struct SceneAssets {
-    pub missile:                 Handle<Scene>,
-    pub nateroid:                Handle<Scene>,
-    pub nateroid_donut_material: Option<Handle<StandardMaterial>>,
-    pub nateroid_icing_material: Option<Handle<StandardMaterial>>,
-    pub spaceship:               Handle<Scene>, // pub sphere: Handle<Scene>,
+    pub scenes:    HashMap<String, Handle<Scene>>,
+    pub materials: HashMap<String, Handle<StandardMaterial>>,
 }
 
-pub fn load_assets(
-    //    mut commands: Commands,
-    mut scene_assets: ResMut<SceneAssets>,
-    asset_server: Res<AssetServer>,
-) {
-    *scene_assets = SceneAssets {
-        missile:                 asset_server.load("models/Bullets Pickup.glb#Scene0"),
-        nateroid:                asset_server.load("nateroid/nateroid.glb#Scene0"),
-        nateroid_donut_material: None,
-        nateroid_icing_material: None,
-        spaceship:               asset_server.load("models/Spaceship.glb#Scene0"),
-    };
+impl SceneAssets {
+    /// The scene handle for `name`, or a default (not-yet-loaded) handle if the manifest
+    /// doesn't list it.
+    pub fn scene(&self, name: &str) -> Handle<Scene> {
+        self.scenes.get(name).cloned().unwrap_or_default()
+    }
+
+    /// The baked material handle for `name`, if the manifest lists one.
+    pub fn material(&self, name: &str) -> Option<Handle<StandardMaterial>> {
+        self.materials.get(name).cloned()
+    }
 }
 
-/// Create custom PBR materials with baked textures for nateroid (donut and icing)
-fn create_nateroid_material(
-    mut materials: ResMut<Assets<StandardMaterial>>,
+/// Root shape of `asset_manifest.ron`: scene/gltf paths keyed by logical name, plus baked
+/// PBR materials keyed the same way.
+#[derive(Asset, TypePath, Deserialize, Default, Debug, Clone)]
+pub struct AssetManifest {
+    #[serde(default)]
+    pub scenes:    HashMap<String, String>,
+    #[serde(default)]
+    pub materials: HashMap<String, MaterialManifestEntry>,
+}
+
+/// A PBR material baked from texture files. Only `albedo` is required - the rest default to
+/// `None` so a manifest entry can list just what it needs.
+#[derive(Deserialize, Debug, Clone)]
+pub struct MaterialManifestEntry {
+    pub albedo:             String,
+    #[serde(default)]
+    pub normal:             Option<String>,
+    #[serde(default)]
+    pub metallic_roughness: Option<String>,
+    #[serde(default)]
+    pub ao:                 Option<String>,
+    #[serde(default)]
+    pub cull_mode:          Option<CullModeContent>,
+}
+
+/// Mirrors [`Face`] so the manifest can stay decoupled from render-resource types.
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CullModeContent {
+    Front,
+    Back,
+}
+
+impl From<CullModeContent> for Face {
+    fn from(content: CullModeContent) -> Self {
+        match content {
+            CullModeContent::Front => Self::Front,
+            CullModeContent::Back => Self::Back,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AssetManifestLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl std::fmt::Display for AssetManifestLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read asset manifest file: {err}"),
+            Self::Ron(err) => write!(f, "could not parse asset manifest file: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for AssetManifestLoaderError {}
+
+impl From<std::io::Error> for AssetManifestLoaderError {
+    fn from(err: std::io::Error) -> Self { Self::Io(err) }
+}
+
+impl From<ron::error::SpannedError> for AssetManifestLoaderError {
+    fn from(err: ron::error::SpannedError) -> Self { Self::Ron(err) }
+}
+
+#[derive(Default)]
+pub struct AssetManifestLoader;
+
+impl AssetLoader for AssetManifestLoader {
+    type Asset = AssetManifest;
+    type Error = AssetManifestLoaderError;
+    type Settings = ();
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] { &["ron"] }
+}
+
+/// Handle to the loaded `assets/content/asset_manifest.ron` asset. Kept around (rather than
+/// dropped after the initial load) so the asset stays loaded while [`populate_scene_assets`]
+/// still needs it.
+#[derive(Resource)]
+struct AssetManifestHandle(Handle<AssetManifest>);
+
+fn load_asset_manifest(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(AssetManifestHandle(asset_server.load(ASSET_MANIFEST_PATH)));
+}
+
+/// Reads the manifest once it's loaded and kicks off the actual `AssetServer` loads for
+/// every scene/material it lists, populating [`SceneAssets`]. Guarded on the `scenes` map
+/// still being empty, so this runs once for any manifest that lists at least one scene.
+fn populate_scene_assets(
     mut scene_assets: ResMut<SceneAssets>,
+    manifest_handle: Res<AssetManifestHandle>,
+    manifest_assets: Res<Assets<AssetManifest>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
     asset_server: Res<AssetServer>,
 ) {
-    if scene_assets.nateroid_donut_material.is_some() {
+    if !scene_assets.scenes.is_empty() {
+        return;
+    }
+
+    let Some(manifest) = manifest_assets.get(&manifest_handle.0) else {
         return;
+    };
+
+    for (name, path) in &manifest.scenes {
+        scene_assets
+            .scenes
+            .insert(name.clone(), asset_server.load(path));
+    }
+
+    for (name, entry) in &manifest.materials {
+        let material = materials.add(StandardMaterial {
+            base_color_texture: Some(asset_server.load(&entry.albedo)),
+            normal_map_texture: entry
+                .normal
+                .as_ref()
+                .map(|path| asset_server.load(path)),
+            metallic_roughness_texture: entry
+                .metallic_roughness
+                .as_ref()
+                .map(|path| asset_server.load(path)),
+            occlusion_texture: entry.ao.as_ref().map(|path| asset_server.load(path)),
+            cull_mode: entry.cull_mode.map(Into::into),
+            ..default()
+        });
+        scene_assets.materials.insert(name.clone(), material);
     }
 
-    info!("Loading baked PBR textures for nateroid (donut and icing)...");
-
-    // Load the donut texture files
-    let donut_albedo: Handle<Image> =
-        asset_server.load("nateroid/textures/nateroid_donut_albedo.png");
-    let donut_normal: Handle<Image> =
-        asset_server.load("nateroid/textures/nateroid_donut_normal.png");
-    let donut_metallic_roughness: Handle<Image> =
-        asset_server.load("nateroid/textures/nateroid_donut_metallic_roughness.png");
-    let donut_ao: Handle<Image> = asset_server.load("nateroid/textures/nateroid_donut_ao.png");
-
-    // Load the icing texture files
-    let icing_albedo: Handle<Image> =
-        asset_server.load("nateroid/textures/nateroid_icing_albedo.png");
-    let icing_normal: Handle<Image> =
-        asset_server.load("nateroid/textures/nateroid_icing_normal.png");
-    let icing_metallic_roughness: Handle<Image> =
-        asset_server.load("nateroid/textures/nateroid_icing_metallic_roughness.png");
-    let icing_ao: Handle<Image> = asset_server.load("nateroid/textures/nateroid_icing_ao.png");
-
-    // Create donut PBR material
-    let donut_material = materials.add(StandardMaterial {
-        base_color_texture:        Some(donut_albedo),
-        normal_map_texture:        Some(donut_normal),
-        metallic_roughness_texture: Some(donut_metallic_roughness),
-        occlusion_texture:         Some(donut_ao),
-        cull_mode:                 None,
-        ..default()
-    });
-
-    // Create icing PBR material
-    let icing_material = materials.add(StandardMaterial {
-        base_color_texture:        Some(icing_albedo),
-        normal_map_texture:        Some(icing_normal),
-        metallic_roughness_texture: Some(icing_metallic_roughness),
-        occlusion_texture:         Some(icing_ao),
-        cull_mode:                 None,
-        ..default()
-    });
-
-    scene_assets.nateroid_donut_material = Some(donut_material);
-    scene_assets.nateroid_icing_material = Some(icing_material);
-    info!("Nateroid PBR materials created for donut and icing with baked textures");
+    info!("Populated SceneAssets from {ASSET_MANIFEST_PATH}");
 }
 
+/// Load status of a single manifest entry, keyed the same way as [`SceneAssets`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetLoadStatus {
+    Pending,
+    Loading,
+    Loaded,
+    Failed,
+}
+
+/// Per-asset load status for everything the manifest lists, so a loading screen can show a
+/// real percentage and surface a failure instead of hanging forever in [`AssetsState::Loading`].
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LoadingProgress {
+    pub scenes:    HashMap<String, AssetLoadStatus>,
+    pub materials: HashMap<String, AssetLoadStatus>,
+}
+
+impl LoadingProgress {
+    /// Fraction of tracked assets that have finished loading, in `0.0..=1.0`.
+    pub fn fraction(&self) -> f32 {
+        let total = self.scenes.len() + self.materials.len();
+        if total == 0 {
+            return 0.0;
+        }
+
+        let done = self
+            .scenes
+            .values()
+            .chain(self.materials.values())
+            .filter(|status| matches!(status, AssetLoadStatus::Loaded))
+            .count();
+
+        done as f32 / total as f32
+    }
+
+    /// True if any tracked asset reported [`AssetLoadStatus::Failed`].
+    pub fn has_failure(&self) -> bool {
+        self.scenes
+            .values()
+            .chain(self.materials.values())
+            .any(|status| matches!(status, AssetLoadStatus::Failed))
+    }
+}
 
 pub fn check_asset_loading(
     mut next_state: ResMut<NextState<AssetsState>>,
+    mut loading_progress: ResMut<LoadingProgress>,
     asset_server: Res<AssetServer>,
+    manifest_handle: Res<AssetManifestHandle>,
+    manifest_assets: Res<Assets<AssetManifest>>,
     scene_assets: Res<SceneAssets>,
 ) {
-    // Collect all asset IDs to check their load states
-    let all_assets_loaded = [
-        scene_assets.missile.id(),
-        scene_assets.nateroid.id(),
-        scene_assets.spaceship.id(),
-    ]
-    .iter()
-    .all(|&id| matches!(asset_server.get_load_state(id), Some(LoadState::Loaded)));
-
-    // Check that both nateroid materials have been created
-    let materials_ready = scene_assets.nateroid_donut_material.is_some()
-        && scene_assets.nateroid_icing_material.is_some();
-
-    // Transition to the Loaded state if all assets are loaded
-    if all_assets_loaded && materials_ready {
+    let Some(manifest) = manifest_assets.get(&manifest_handle.0) else {
+        return;
+    };
+
+    for name in manifest.scenes.keys() {
+        let status = match scene_assets.scenes.get(name) {
+            None => AssetLoadStatus::Pending,
+            Some(handle) => match asset_server.get_load_state(handle.id()) {
+                Some(LoadState::Loaded) => AssetLoadStatus::Loaded,
+                Some(LoadState::Failed(_)) => AssetLoadStatus::Failed,
+                _ => AssetLoadStatus::Loading,
+            },
+        };
+        loading_progress.scenes.insert(name.clone(), status);
+    }
+
+    // Materials have no separate load state of their own - `populate_scene_assets` creates
+    // the handle synchronously once the manifest is read, so "created" is "loaded" for these.
+    for name in manifest.materials.keys() {
+        let status = if scene_assets.materials.contains_key(name) {
+            AssetLoadStatus::Loaded
+        } else {
+            AssetLoadStatus::Pending
+        };
+        loading_progress.materials.insert(name.clone(), status);
+    }
+
+    if loading_progress.has_failure() {
+        error!("One or more assets failed to load: {loading_progress:?}");
+        return;
+    }
+
+    if loading_progress.fraction() >= 1.0 {
         info!("All assets loaded!");
         next_state.set(AssetsState::Loaded);
     }
 }
+
+#[derive(Component)]
+struct LoadingScreenRoot;
+
+#[derive(Component)]
+struct LoadingProgressBarFill;
+
+#[derive(Component)]
+struct LoadingStatusText;
+
+fn spawn_loading_screen(mut commands: Commands) {
+    commands
+        .spawn((
+            LoadingScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    width:  Val::Px(400.0),
+                    height: Val::Px(24.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+            ))
+            .with_children(|bar| {
+                bar.spawn((
+                    LoadingProgressBarFill,
+                    Node {
+                        width:  Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.2, 0.8, 0.3)),
+                ));
+            });
+
+            root.spawn((
+                LoadingStatusText,
+                Text::new("Loading assets..."),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+fn despawn_loading_screen(mut commands: Commands, root: Query<Entity, With<LoadingScreenRoot>>) {
+    for entity in &root {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn update_loading_screen(
+    loading_progress: Res<LoadingProgress>,
+    mut bar_fill: Query<&mut Node, With<LoadingProgressBarFill>>,
+    mut status_text: Query<&mut Text, With<LoadingStatusText>>,
+) {
+    let fraction = loading_progress.fraction();
+
+    if let Ok(mut node) = bar_fill.single_mut() {
+        node.width = Val::Percent(fraction * 100.0);
+    }
+
+    if let Ok(mut text) = status_text.single_mut() {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let percent = (fraction * 100.0) as u32;
+        **text = if loading_progress.has_failure() {
+            "Failed to load some assets - check logs".to_string()
+        } else {
+            format!("Loading assets... {percent}%")
+        };
+    }
+}
@@ -0,0 +1,170 @@
+//! Opt-in auto-framing subsystem: while [`GameAction::AutoFrame`] is held active, continuously
+//! servos the `PanOrbitCamera`/projection toward centered, balanced margins, using the same
+//! critically-damped spring `zoom`'s zoom-to-fit animation uses. Unlike zoom-to-fit, this isn't a
+//! one-shot convergence loop started by a keypress - it just runs every frame the toggle is on,
+//! turning `screen_boundary`'s green/red balance diagnostic into a standing auto-focus feature.
+use bevy::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::Edge;
+use super::ScreenSpaceBoundary;
+use super::ZoomConfig;
+use super::ZoomFitMode;
+use super::zoom::spring_step;
+use crate::game_input::GameAction;
+use crate::game_input::toggle_active;
+use crate::playfield::Boundary;
+
+pub struct AutoFramePlugin;
+
+impl Plugin for AutoFramePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AutoFrameState>().add_systems(
+            Update,
+            drive_auto_frame.run_if(toggle_active(false, GameAction::AutoFrame)),
+        );
+    }
+}
+
+/// Critically-damped spring velocities carried between frames while auto-framing is active.
+#[derive(Resource, Default)]
+struct AutoFrameState {
+    focus_velocity: Vec3,
+    value_velocity: f32,
+}
+
+/// Each frame: computes the signed horizontal/vertical margin error (difference of opposing
+/// `margin_percentage` pairs) and the overall margin slack (how far the boundary's span is from
+/// its target framing), then eases focus and zoom toward closing both with a critically-damped
+/// spring.
+fn drive_auto_frame(
+    zoom_config: Res<ZoomConfig>,
+    time: Res<Time>,
+    boundary: Res<Boundary>,
+    mut state: ResMut<AutoFrameState>,
+    mut camera_query: Query<
+        (&GlobalTransform, &mut PanOrbitCamera, &mut Projection, &Camera),
+        With<PanOrbitCamera>,
+    >,
+) {
+    let Ok((cam_global, mut pan_orbit, mut projection, camera)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let aspect_ratio = camera
+        .logical_viewport_size()
+        .map(|viewport_size| viewport_size.x / viewport_size.y);
+
+    let corners = boundary.corners();
+
+    // The two projection kinds measure the boundary differently (tangent-space vs.
+    // world-space), but converge through the same margin/spring machinery below.
+    let margins = match &*projection {
+        Projection::Perspective(perspective) => ScreenSpaceBoundary::from_camera_view(
+            &corners,
+            cam_global,
+            perspective,
+            aspect_ratio.unwrap_or(perspective.aspect_ratio),
+            zoom_config.zoom_margin_multiplier(),
+        ),
+        Projection::Orthographic(orthographic) => {
+            ScreenSpaceBoundary::from_camera_view_orthographic(
+                &corners,
+                cam_global,
+                orthographic,
+                zoom_config.zoom_margin_multiplier(),
+            )
+        },
+        _ => return,
+    };
+
+    let Some(margins) = margins else {
+        // Boundary behind camera - don't carry stale velocity into whatever re-engages next.
+        *state = AutoFrameState::default();
+        return;
+    };
+
+    let dt = time.delta_secs();
+    let omega = 2.0 / zoom_config.settle_time_secs;
+
+    let screen_width = 2.0 * margins.half_tan_hfov;
+    let screen_height = 2.0 * margins.half_tan_vfov;
+    let h_error = margins.margin_percentage(Edge::Left) - margins.margin_percentage(Edge::Right);
+    let v_error = margins.margin_percentage(Edge::Bottom) - margins.margin_percentage(Edge::Top);
+
+    // Undo `margin_percentage`'s own scaling to get back to a world-space nudge along the
+    // camera's screen axes.
+    let offset_x = (h_error / 100.0) * screen_width * 0.5 * margins.avg_depth;
+    let offset_y = (v_error / 100.0) * screen_height * 0.5 * margins.avg_depth;
+
+    let cam_rot = cam_global.rotation();
+    let cam_right = cam_rot * Vec3::X;
+    let cam_up = cam_rot * Vec3::Y;
+    let target_focus = pan_orbit.target_focus + cam_right * offset_x + cam_up * offset_y;
+
+    pan_orbit.target_focus = Vec3::new(
+        spring_step(
+            pan_orbit.target_focus.x,
+            target_focus.x,
+            &mut state.focus_velocity.x,
+            omega,
+            dt,
+        ),
+        spring_step(
+            pan_orbit.target_focus.y,
+            target_focus.y,
+            &mut state.focus_velocity.y,
+            omega,
+            dt,
+        ),
+        spring_step(
+            pan_orbit.target_focus.z,
+            target_focus.z,
+            &mut state.focus_velocity.z,
+            omega,
+            dt,
+        ),
+    );
+
+    // Margin slack: how far the boundary's span is from its target framing (> 1 = too tight,
+    // needs to zoom out; < 1 = too loose, can zoom in).
+    let (span_x, span_y) = margins.span();
+    let target_span_x = 2.0 * margins.half_tan_hfov / zoom_config.zoom_margin_multiplier();
+    let target_span_y = 2.0 * margins.half_tan_vfov / zoom_config.zoom_margin_multiplier();
+    let slack_ratio = (span_x / target_span_x).max(span_y / target_span_y);
+
+    let current_radius = pan_orbit.target_radius;
+    match &mut *projection {
+        Projection::Perspective(perspective) if zoom_config.fit_mode == ZoomFitMode::Fov => {
+            let current_half_vfov = perspective.fov * 0.5;
+            let target_half_vfov = (current_half_vfov.tan() * slack_ratio).atan();
+            let target_fov =
+                (2.0 * target_half_vfov).clamp(zoom_config.min_fov, zoom_config.max_fov);
+            perspective.fov =
+                spring_step(perspective.fov, target_fov, &mut state.value_velocity, omega, dt);
+        },
+        Projection::Perspective(_) => {
+            let target_radius = current_radius * slack_ratio;
+            pan_orbit.target_radius = spring_step(
+                current_radius,
+                target_radius,
+                &mut state.value_velocity,
+                omega,
+                dt,
+            );
+        },
+        Projection::Orthographic(orthographic) => {
+            let target_scale = orthographic.scale * slack_ratio;
+            orthographic.scale = spring_step(
+                orthographic.scale,
+                target_scale,
+                &mut state.value_velocity,
+                omega,
+                dt,
+            );
+        },
+        Projection::Custom(_) => {},
+    }
+
+    pan_orbit.force_update = true;
+}
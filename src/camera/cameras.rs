@@ -157,11 +157,13 @@ pub struct ScreenSpaceBoundary {
 }
 
 impl ScreenSpaceBoundary {
-    /// Creates screen space margins from a camera's view of a boundary.
-    /// Returns `None` if any boundary corner is behind the camera.
+    /// Creates screen space margins from a camera's view of a set of world-space corners -
+    /// the playfield boundary's corners for a full zoom-to-fit, or an arbitrary selection's
+    /// bounding-box corners for fit-to-selection.
+    /// Returns `None` if any corner is behind the camera.
     #[allow(clippy::similar_names)] // half_tan_hfov vs half_tan_vfov are standard FOV terms
     pub fn from_camera_view(
-        boundary: &Boundary,
+        corners: &[Vec3],
         cam_global: &GlobalTransform,
         perspective: &PerspectiveProjection,
         viewport_aspect: f32,
@@ -170,9 +172,6 @@ impl ScreenSpaceBoundary {
         let half_tan_vfov = (perspective.fov * 0.5).tan();
         let half_tan_hfov = half_tan_vfov * viewport_aspect;
 
-        // Get boundary corners
-        let boundary_corners = boundary.corners();
-
         // Get camera basis vectors from global transform (world position, not local)
         let cam_pos = cam_global.translation();
         let cam_rot = cam_global.rotation();
@@ -187,7 +186,7 @@ impl ScreenSpaceBoundary {
         let mut max_norm_y = f32::NEG_INFINITY;
         let mut avg_depth = 0.0;
 
-        for corner in &boundary_corners {
+        for corner in corners {
             let relative = *corner - cam_pos;
             let depth = relative.dot(cam_forward);
 
@@ -208,7 +207,7 @@ impl ScreenSpaceBoundary {
             max_norm_y = max_norm_y.max(norm_y);
             avg_depth += depth;
         }
-        avg_depth /= boundary_corners.len().to_f32();
+        avg_depth /= corners.len().to_f32();
 
         // Screen edges are at ±half_tan_hfov and ±half_tan_vfov
         // Target edges (with margin) are at ±(half_tan_hfov / zoom_multiplier)
@@ -379,6 +378,113 @@ impl ScreenSpaceBoundary {
             Edge::Bottom => (self.bottom_margin / screen_height) * 100.0,
         }
     }
+
+    /// Creates screen space margins from an orthographic camera's view of a set of
+    /// world-space corners (the playfield boundary, or a fit-to-selection bounding box).
+    ///
+    /// There's no perspective divide for an orthographic projection, so corners project to
+    /// world-space offsets along the camera's right/up axes directly, rather than normalized
+    /// tangent-space coordinates. `avg_depth` is fixed at `1.0` so the screen-to-world
+    /// conversions that multiply by it (`normalized_to_world`, `auto_frame`'s focus nudge) leave
+    /// these offsets unscaled, matching the fact that an orthographic offset needs no depth
+    /// scaling.
+    /// Returns `None` if any corner is behind the camera.
+    pub fn from_camera_view_orthographic(
+        corners: &[Vec3],
+        cam_global: &GlobalTransform,
+        orthographic: &OrthographicProjection,
+        zoom_multiplier: f32,
+    ) -> Option<Self> {
+        let half_width = orthographic.area.width() * 0.5;
+        let half_height = orthographic.area.height() * 0.5;
+
+        let cam_pos = cam_global.translation();
+        let cam_rot = cam_global.rotation();
+        let cam_forward = cam_rot * Vec3::NEG_Z;
+        let cam_right = cam_rot * Vec3::X;
+        let cam_up = cam_rot * Vec3::Y;
+
+        let mut min_norm_x = f32::INFINITY;
+        let mut max_norm_x = f32::NEG_INFINITY;
+        let mut min_norm_y = f32::INFINITY;
+        let mut max_norm_y = f32::NEG_INFINITY;
+
+        for corner in corners {
+            let relative = *corner - cam_pos;
+            if relative.dot(cam_forward) <= 0.1 {
+                return None;
+            }
+
+            let x = relative.dot(cam_right);
+            let y = relative.dot(cam_up);
+
+            min_norm_x = min_norm_x.min(x);
+            max_norm_x = max_norm_x.max(x);
+            min_norm_y = min_norm_y.min(y);
+            max_norm_y = max_norm_y.max(y);
+        }
+
+        let target_edge_x = half_width / zoom_multiplier;
+        let target_edge_y = half_height / zoom_multiplier;
+
+        let left_margin = min_norm_x - (-half_width);
+        let right_margin = half_width - max_norm_x;
+        let bottom_margin = min_norm_y - (-half_height);
+        let top_margin = half_height - max_norm_y;
+
+        let target_margin_x = half_width - target_edge_x;
+        let target_margin_y = half_height - target_edge_y;
+
+        Some(Self {
+            left_margin,
+            right_margin,
+            top_margin,
+            bottom_margin,
+            target_margin_x,
+            target_margin_y,
+            min_norm_x,
+            max_norm_x,
+            min_norm_y,
+            max_norm_y,
+            avg_depth: 1.0,
+            half_tan_vfov: half_height,
+            half_tan_hfov: half_width,
+        })
+    }
+
+    /// Projects a single world-space point into the same normalized coordinates as
+    /// `min_norm_x`/`max_norm_x`/etc., mirroring the per-corner projection in
+    /// [`Self::from_camera_view`]. Returns `None` if the point is behind the camera.
+    pub fn project_perspective(
+        point: Vec3,
+        cam_pos: Vec3,
+        cam_right: Vec3,
+        cam_up: Vec3,
+        cam_forward: Vec3,
+    ) -> Option<(f32, f32)> {
+        let relative = point - cam_pos;
+        let depth = relative.dot(cam_forward);
+        if depth <= 0.1 {
+            return None;
+        }
+        Some((relative.dot(cam_right) / depth, relative.dot(cam_up) / depth))
+    }
+
+    /// As [`Self::project_perspective`], but without the perspective divide - mirrors the
+    /// per-corner projection in [`Self::from_camera_view_orthographic`].
+    pub fn project_orthographic(
+        point: Vec3,
+        cam_pos: Vec3,
+        cam_right: Vec3,
+        cam_up: Vec3,
+        cam_forward: Vec3,
+    ) -> Option<(f32, f32)> {
+        let relative = point - cam_pos;
+        if relative.dot(cam_forward) <= 0.1 {
+            return None;
+        }
+        Some((relative.dot(cam_right), relative.dot(cam_up)))
+    }
 }
 
 /// Boundary box edges
@@ -598,42 +704,82 @@ fn cleanup_focus_labels(
     }
 }
 
+/// Calculates the camera distance (or, for orthographic, a sane dolly distance - see
+/// [`calculate_home_orthographic_scale`]) that frames `grid_size` with `margin`.
+///
+/// Guards against a degenerate result: bails with `None` if `grid_size` has a zero/near-zero
+/// component (boundary momentarily unset during scene load) or the computed radius isn't finite
+/// and positive, rather than handing a `NaN`/infinite radius to `set_home_position`. Otherwise
+/// clamps up to `min_radius` as a final sanity floor.
 #[allow(clippy::similar_names)] // x_distance, y_distance, xy_distance are intentionally similar
 pub fn calculate_home_radius(
     grid_size: Vec3,
     margin: f32,
     projection: &Projection,
     camera: &Camera,
+    min_radius: f32,
 ) -> Option<f32> {
-    let Projection::Perspective(perspective) = projection else {
+    if !grid_size.is_finite() || grid_size.min_element().abs() < f32::EPSILON {
         return None;
-    };
+    }
 
-    // Get actual viewport aspect ratio
-    let aspect_ratio = if let Some(viewport_size) = camera.logical_viewport_size() {
-        viewport_size.x / viewport_size.y
-    } else {
-        perspective.aspect_ratio
-    };
+    let target_radius = match projection {
+        Projection::Perspective(perspective) => {
+            // Get actual viewport aspect ratio
+            let aspect_ratio = if let Some(viewport_size) = camera.logical_viewport_size() {
+                viewport_size.x / viewport_size.y
+            } else {
+                perspective.aspect_ratio
+            };
+
+            let fov = perspective.fov;
+
+            // Calculate horizontal FOV based on aspect ratio
+            let horizontal_fov = 2.0 * ((fov / 2.0).tan() * aspect_ratio).atan();
+
+            // Calculate distances required for X and Y dimensions to fit in viewport
+            let x_distance = (grid_size.x / 2.0) / (horizontal_fov / 2.0).tan();
+            let y_distance = (grid_size.y / 2.0) / (fov / 2.0).tan();
+
+            // Take the max of X and Y distances
+            let xy_distance = x_distance.max(y_distance);
+
+            // For Z dimension (depth)
+            let z_half_depth = grid_size.z / 2.0;
 
-    let fov = perspective.fov;
+            // Add Z depth to XY distance, then apply margin to the total
+            // This ensures the entire 3D boundary fits with proper margin
+            (xy_distance + z_half_depth) * margin
+        },
+        // Orthographic framing is driven by `Projection::Orthographic`'s `scale` (see
+        // `calculate_home_orthographic_scale`), not camera distance, so `radius` here only needs
+        // to be a sane dolly/orbit distance - enough to clear the boundary's own depth.
+        Projection::Orthographic(_) => {
+            grid_size.z.mul_add(0.5, grid_size.x.max(grid_size.y) * 0.5) * margin
+        },
+        Projection::Custom(_) => return None,
+    };
 
-    // Calculate horizontal FOV based on aspect ratio
-    let horizontal_fov = 2.0 * ((fov / 2.0).tan() * aspect_ratio).atan();
+    if !target_radius.is_finite() || target_radius <= 0.0 {
+        return None;
+    }
 
-    // Calculate distances required for X and Y dimensions to fit in viewport
-    let x_distance = (grid_size.x / 2.0) / (horizontal_fov / 2.0).tan();
-    let y_distance = (grid_size.y / 2.0) / (fov / 2.0).tan();
+    Some(target_radius.max(min_radius))
+}
 
-    // Take the max of X and Y distances
-    let xy_distance = x_distance.max(y_distance);
+/// Orthographic counterpart to [`calculate_home_radius`]'s perspective math: instead of solving
+/// for a camera distance from FOV, sizes the projection's `scale` directly so the boundary's XY
+/// extent fits the viewport, aspect-corrected and margined the same way.
+fn calculate_home_orthographic_scale(grid_size: Vec3, margin: f32, camera: &Camera) -> Option<f32> {
+    let viewport_size = camera.logical_viewport_size()?;
+    let viewport_aspect = viewport_size.x / viewport_size.y;
 
-    // For Z dimension (depth)
-    let z_half_depth = grid_size.z / 2.0;
+    // Orthographic scale is world-units-per-half-viewport-height; fit whichever of width/height
+    // is the binding constraint.
+    let scale_for_height = grid_size.y / 2.0;
+    let scale_for_width = (grid_size.x / 2.0) / viewport_aspect;
 
-    // Add Z depth to XY distance, then apply margin to the total
-    // This ensures the entire 3D boundary fits with proper margin
-    Some((xy_distance + z_half_depth) * margin)
+    Some(scale_for_height.max(scale_for_width) * margin)
 }
 
 /// take us back to the splash screen start position
@@ -641,19 +787,30 @@ pub fn home_camera(
     boundary: Res<Boundary>,
     zoom_config: Res<ZoomConfig>,
     camera_config: Res<CameraConfig>,
-    camera_query: Single<(&mut PanOrbitCamera, &Projection, &Camera)>,
+    camera_query: Single<(&mut PanOrbitCamera, &mut Projection, &Camera)>,
 ) {
-    let (mut pan_orbit, projection, camera) = camera_query.into_inner();
+    let (mut pan_orbit, mut projection, camera) = camera_query.into_inner();
 
     let Some(target_radius) = calculate_home_radius(
         boundary.scale(),
         zoom_config.zoom_margin_multiplier(),
-        projection,
+        &projection,
         camera,
+        zoom_config.min_home_radius,
     ) else {
         return;
     };
 
+    if let Projection::Orthographic(orthographic) = &mut *projection {
+        if let Some(target_scale) = calculate_home_orthographic_scale(
+            boundary.scale(),
+            zoom_config.zoom_margin_multiplier(),
+            camera,
+        ) {
+            orthographic.scale = target_scale;
+        }
+    }
+
     // Set the camera's orbit parameters
     pan_orbit.set_home_position(&camera_config, target_radius);
 }
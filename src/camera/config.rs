@@ -39,6 +39,9 @@ impl Plugin for CameraConfigPlugin {
 #[reflect(Resource, InspectorOptions)]
 pub struct CameraConfig {
     pub clear_color:               Color,
+    /// Base background tint `starfield_shader`'s procedural skybox blends beneath its stars -
+    /// the deep-space color a cell reads as when `StarfieldMaterial`'s hash finds no star there.
+    pub nebula_tint:               Color,
     #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
     pub darkening_factor:          f32,
     #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
@@ -64,12 +67,42 @@ pub struct CameraConfig {
     /// Camera starting yaw angle for splash screen animation
     #[inspector(min = -std::f32::consts::PI, max = std::f32::consts::PI, display = NumberDisplay::Slider)]
     pub splash_start_yaw:          f32,
+    /// Orbit distance the follow camera (`FollowCameraPlugin`) holds behind the tracked
+    /// `Spaceship`.
+    #[inspector(min = 10.0, max = 2000.0, display = NumberDisplay::Slider)]
+    pub follow_distance:           f32,
+    /// Orbit pitch (radians) for the follow camera. No system reads it: `drive_follow_camera`
+    /// orients the `Chase`/`Cockpit` camera from the `Spaceship`'s own transform.
+    #[inspector(min = -std::f32::consts::FRAC_PI_2, max = std::f32::consts::FRAC_PI_2, display = NumberDisplay::Slider)]
+    pub follow_pitch:              f32,
+    /// World-space height above the tracked `Spaceship` the `Chase` camera mode holds -
+    /// `ship.translation + ship.back() * follow_distance + ship.up() * follow_height`.
+    #[inspector(min = 0.0, max = 1000.0, display = NumberDisplay::Slider)]
+    pub follow_height:             f32,
+    /// Settle time (seconds) for a follow-camera focus spring. No system reads it:
+    /// `drive_follow_camera` writes the camera `Transform` directly each frame, with no spring.
+    #[inspector(min = 0.05, max = 2.0, display = NumberDisplay::Slider)]
+    pub follow_settle_time_secs:   f32,
+    /// Perspective FOV (radians) the follow camera eases toward while the spaceship is roughly
+    /// stationary.
+    #[inspector(min = 0.2, max = 1.5, display = NumberDisplay::Slider)]
+    pub fov_rest:                  f32,
+    /// Perspective FOV (radians) the follow camera eases toward at `SpaceshipControlConfig::max_speed`
+    /// - wider than `fov_rest`, for the subtle speed-sensation zoom-out under acceleration.
+    #[inspector(min = 0.2, max = 1.5, display = NumberDisplay::Slider)]
+    pub fov_boost:                 f32,
+    /// Exponential smoothing rate `update_speed_fov` eases the current FOV toward its speed-driven
+    /// target with - `current = lerp(current, target, 1 - exp(-fov_smoothing * dt))`. Higher is
+    /// snappier.
+    #[inspector(min = 0.5, max = 20.0, display = NumberDisplay::Slider)]
+    pub fov_smoothing:             f32,
 }
 
 impl Default for CameraConfig {
     fn default() -> Self {
         Self {
             clear_color:               Color::from(tailwind::SLATE_900),
+            nebula_tint:               Color::from(tailwind::INDIGO_950),
             darkening_factor:          0.002,
             bloom_intensity:           0.5,
             bloom_low_frequency_boost: 0.5,
@@ -81,6 +114,13 @@ impl Default for CameraConfig {
             splash_start_focus:        CAMERA_SPLASH_START_FOCUS,
             splash_start_pitch:        CAMERA_SPLASH_START_PITCH,
             splash_start_yaw:          CAMERA_SPLASH_START_YAW,
+            follow_distance:           400.0,
+            follow_pitch:              -0.4,
+            follow_height:             150.0,
+            follow_settle_time_secs:   0.25,
+            fov_rest:                  0.523_6, // 30 degrees
+            fov_boost:                 0.698_1, // 40 degrees
+            fov_smoothing:             3.0,
         }
     }
 }
@@ -100,8 +140,33 @@ pub struct StarConfig {
     pub star_count:                    usize,
     pub star_radius_max:               f32,
     pub star_radius_min:               f32,
-    pub star_field_inner_diameter:     f32,
-    pub star_field_outer_diameter:     f32,
+    /// Seeds both `spawn_stars`' Perlin density field and its color-temperature field, so a given
+    /// seed reproduces the same clustering and hue bias every time the field is (re)spawned.
+    pub star_cluster_seed:             u32,
+    /// Spatial frequency the cluster-density noise is sampled at - lower values produce large,
+    /// sweeping Milky-Way-style bands and voids; higher values produce tighter, more numerous
+    /// clusters.
+    #[inspector(min = 0.001, max = 0.5, display = NumberDisplay::Slider)]
+    pub star_cluster_noise_frequency:  f64,
+    /// Exponent `spawn_stars` raises the normalized noise sample to before using it as an
+    /// acceptance probability - higher sharpens mid-density regions toward fully empty or fully
+    /// dense, carving clearer voids between clusters.
+    #[inspector(min = 0.1, max = 5.0, display = NumberDisplay::Slider)]
+    pub star_cluster_contrast:         f32,
+    /// Spatial frequency of the second, much-lower-frequency noise channel driving regional color
+    /// temperature, so neighboring stars share a bluish-vs-warm hue bias instead of each being
+    /// independently colored.
+    #[inspector(min = 0.0001, max = 0.05, display = NumberDisplay::Slider)]
+    pub star_color_temperature_noise_frequency: f64,
+    /// How strongly the color-temperature noise sample nudges a star's channel balance toward
+    /// warm or cool - `0.0` disables it entirely, reproducing the old fully-independent coloring.
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub star_color_temperature_influence: f32,
+    /// Parallax depth bands `spawn_stars` splits `star_count` across - see [`StarLayer`]. Ignored
+    /// by the inspector (a `Vec` of a custom struct doesn't have a sensible per-field widget),
+    /// same as `ActorConfig::collapse_sequence`.
+    #[reflect(ignore)]
+    pub star_layers:                   Vec<StarLayer>,
     pub start_twinkling_delay:         f32,
     pub twinkle_duration:              Range<f32>,
     pub twinkle_intensity:             Range<f32>,
@@ -109,6 +174,26 @@ pub struct StarConfig {
     #[inspector(min = 0.01667, max = 30.0, display = NumberDisplay::Slider)]
     pub rotation_cycle_minutes:        f32,
     pub rotation_axis:                 Vec3,
+    /// Grid density the `starfield_shader` procedural skybox hashes the view direction against -
+    /// higher packs more (smaller) cells into the sky, so the sparse-star threshold lands more of
+    /// them.
+    #[inspector(min = 1.0, max = 200.0, display = NumberDisplay::Slider)]
+    pub shader_star_density:           f32,
+    /// Peak intensity the procedural skybox's brightest stars render at.
+    #[inspector(min = 0.0, max = 2.0, display = NumberDisplay::Slider)]
+    pub shader_star_brightness:        f32,
+    /// Radians/sec the procedural skybox's per-star twinkle phase advances at.
+    #[inspector(min = 0.0, max = 10.0, display = NumberDisplay::Slider)]
+    pub shader_twinkle_speed:          f32,
+    /// Cells per axis `star_light_grid` bakes the ambient-light grid into - higher resolves
+    /// tighter color/density gradients across the playfield at the cost of one more bake pass per
+    /// axis doubling.
+    #[inspector(min = 2, max = 32, display = NumberDisplay::Slider)]
+    pub light_grid_resolution:         u32,
+    /// Scales the baked ambient color `StarLightGrid::sample` returns before a consumer applies
+    /// it - `0.0` disables the tint entirely without needing to remove the consumer.
+    #[inspector(min = 0.0, max = 5.0, display = NumberDisplay::Slider)]
+    pub light_grid_intensity:          f32,
 }
 
 impl Default for StarConfig {
@@ -122,47 +207,146 @@ impl Default for StarConfig {
             star_color_white_start_ratio:  0.7,
             star_radius_max:               2.5,
             star_radius_min:               0.3,
-            star_field_inner_diameter:     200.,
-            star_field_outer_diameter:     400.,
+            star_cluster_seed:             42,
+            star_cluster_noise_frequency:  0.01,
+            star_cluster_contrast:         2.0,
+            star_color_temperature_noise_frequency: 0.005,
+            star_color_temperature_influence: 0.4,
+            star_layers:                   vec![
+                // Near: half the stars, bigger/brighter, visibly drifting faster than the rest.
+                StarLayer {
+                    inner_diameter:            150.,
+                    outer_diameter:            250.,
+                    star_fraction:             0.5,
+                    rotation_speed_multiplier: 1.6,
+                    radius_scale:              1.2,
+                    emissive_scale:            1.1,
+                },
+                // Mid: the baseline layer - radius, emissive, and rotation speed all unscaled.
+                StarLayer {
+                    inner_diameter:            250.,
+                    outer_diameter:            400.,
+                    star_fraction:             0.3,
+                    rotation_speed_multiplier: 1.0,
+                    radius_scale:              1.0,
+                    emissive_scale:            1.0,
+                },
+                // Far: smaller, dimmer, and nearly static - reads as distant background.
+                StarLayer {
+                    inner_diameter:            400.,
+                    outer_diameter:            600.,
+                    star_fraction:             0.2,
+                    rotation_speed_multiplier: 0.5,
+                    radius_scale:              0.7,
+                    emissive_scale:            0.6,
+                },
+            ],
             start_twinkling_delay:         0.5,
             twinkle_duration:              0.5..2.,
             twinkle_intensity:             10.0..20.,
             twinkle_choose_multiple_count: 2, // stars to look at each update
             rotation_cycle_minutes:        15., // i mean why not
             rotation_axis:                 Vec3::Y,
+            shader_star_density:           60.0,
+            shader_star_brightness:        1.0,
+            shader_twinkle_speed:          2.0,
+            light_grid_resolution:         8,
+            light_grid_intensity:          1.0,
         }
     }
 }
 
+/// One parallax depth band `spawn_stars` draws a fraction of `StarConfig::star_count` into:
+/// `inner_diameter`/`outer_diameter` bound the spherical shell (same role
+/// `star_field_inner/outer_diameter` played for the old single-shell field), `star_fraction` is
+/// this layer's share of the total star count, `rotation_speed_multiplier` scales
+/// `rotation_cycle_minutes` so near layers (> 1.0) visibly drift faster than far ones (< 1.0), and
+/// `radius_scale`/`emissive_scale` shrink/dim farther layers for a cheap depth cue.
+#[derive(Reflect, Debug, Clone, Copy)]
+pub struct StarLayer {
+    pub inner_diameter:            f32,
+    pub outer_diameter:            f32,
+    pub star_fraction:             f32,
+    pub rotation_speed_multiplier: f32,
+    pub radius_scale:              f32,
+    pub emissive_scale:            f32,
+}
+
 #[derive(Resource, Reflect, InspectorOptions, Debug, PartialEq, Clone, Copy)]
 #[reflect(Resource, InspectorOptions)]
 pub struct ZoomConfig {
     /// Maximum iterations before giving up
     #[inspector(min = 50, max = 500)]
-    pub max_iterations:   usize,
+    pub max_iterations:         usize,
     #[inspector(min = 0.0, max = 0.5, display = NumberDisplay::Slider)]
-    pub margin:           f32,
+    pub margin:                 f32,
     /// Margin tolerance for convergence detection (0.001 = 0.1% tolerance).
     /// Used for both balance and fit checks.
     #[inspector(min = 0.00001, max = 0.01, display = NumberDisplay::Slider)]
-    pub margin_tolerance: f32,
+    pub margin_tolerance:       f32,
     // Zoom-to-fit convergence parameters
-    /// Convergence rate for zoom-to-fit adjustments (0.18 = 18% per frame).
-    #[inspector(min = 0.01, max = 0.5, display = NumberDisplay::Slider)]
-    pub convergence_rate: f32,
+    /// Time (seconds) the critically-damped convergence spring takes to settle on its target.
+    /// Drives `omega = 2.0 / settle_time_secs` for the focus/radius/scale/fov spring steps, so
+    /// convergence speed no longer depends on frame rate.
+    #[inspector(min = 0.05, max = 2.0, display = NumberDisplay::Slider)]
+    pub settle_time_secs:       f32,
+    /// Whether zoom-to-fit dollies the camera (`Radius`) or narrows the lens
+    /// at a fixed distance (`Fov`).
+    pub fit_mode:               ZoomFitMode,
+    /// Lower bound (radians) the `Fov` fit mode will converge to before
+    /// falling back to radius adjustment for the remaining error.
+    #[inspector(min = 0.008, max = 1.309, display = NumberDisplay::Slider)]
+    pub min_fov:                f32,
+    /// Upper bound (radians) the `Fov` fit mode will converge to before
+    /// falling back to radius adjustment for the remaining error.
+    #[inspector(min = 0.008, max = 1.309, display = NumberDisplay::Slider)]
+    pub max_fov:                f32,
+    /// Whether mouse-wheel zoom converges toward the point under the cursor
+    /// (`zoom::apply_cursor_zoom`) or the stock `bevy_panorbit_camera` behavior of zooming
+    /// straight toward `target_focus`. Lives on this resource (rather than a standalone flag) so
+    /// the egui inspector can flip it at runtime without a code change.
+    pub zoom_to_cursor:         bool,
+    /// Lower bound `apply_cursor_zoom` clamps `target_radius` to.
+    #[inspector(min = 1.0, max = 1000.0, display = NumberDisplay::Slider)]
+    pub min_height:             f32,
+    /// Upper bound `apply_cursor_zoom` clamps `target_radius` to.
+    #[inspector(min = 100.0, max = 100_000.0, display = NumberDisplay::Slider)]
+    pub max_height:             f32,
+    /// Floor `calculate_home_radius` clamps its result to, guarding against a degenerate (zero,
+    /// negative, `NaN`, or infinite) radius reaching `PanOrbitCamera::set_home_position` while
+    /// the boundary or projection is momentarily unset during scene load.
+    #[inspector(min = 0.1, max = 1000.0, display = NumberDisplay::Slider)]
+    pub min_home_radius:       f32,
 }
 
 impl Default for ZoomConfig {
     fn default() -> Self {
         Self {
-            max_iterations:   200,
-            margin:           0.1, //percent of screen
-            margin_tolerance: 0.00001,
-            convergence_rate: 0.30,
+            max_iterations:         200,
+            margin:                 0.1, //percent of screen
+            margin_tolerance:       0.00001,
+            settle_time_secs:       0.25,
+            fit_mode:               ZoomFitMode::Radius,
+            min_fov:                0.5_f32.to_radians(),
+            max_fov:                75.0_f32.to_radians(),
+            zoom_to_cursor:         true,
+            min_height:             50.0,
+            max_height:             50_000.0,
+            min_home_radius:        1.0,
         }
     }
 }
 
+/// Selects how `update_zoom_to_fit` converges a perspective camera onto the
+/// boundary: by dollying (`Radius`) or by narrowing the lens at a fixed
+/// distance (`Fov`), which keeps parallax constant while framing.
+#[derive(Reflect, Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum ZoomFitMode {
+    #[default]
+    Radius,
+    Fov,
+}
+
 impl ZoomConfig {
     /// Returns the zoom margin multiplier (1.0 + margin)
     /// For example, a margin of 0.08 returns 1.08 (8% margin)
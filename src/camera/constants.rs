@@ -13,8 +13,62 @@ pub const CAMERA_ZOOM_SENSITIVITY: f32 = 0.2;
 /// Minimum zoom distance (allows zoom-to-fit to get very close)
 pub const CAMERA_ZOOM_LOWER_LIMIT: f32 = 0.001;
 
+/// Extra depth added beyond the boundary's near/far extent when zoom-to-fit
+/// recomputes the perspective clip planes, so geometry right at the boundary
+/// edge doesn't get clipped.
+pub const FRUSTUM_Z_MARGIN: f32 = 1.0;
+
+/// Floor for the near clip plane when zoom-to-fit recomputes it; keeps depth
+/// precision sane even as `current_radius` shrinks toward
+/// `CAMERA_ZOOM_LOWER_LIMIT`.
+pub const FRUSTUM_MIN_NEAR_Z: f32 = 0.1;
+
+/// World-space distance at which a click-to-focus animation is considered converged and
+/// `FocusToPointActive` is removed.
+pub const FOCUS_TO_POINT_CONVERGED_DISTANCE: f32 = 0.01;
+
+/// Multiplier applied to cursor-zoom's focus-slide fraction while `KeyCode::ShiftLeft` is held,
+/// letting the user deliberately zoom toward the cursor faster than a single scroll tick would
+/// otherwise slide the focus.
+pub const CURSOR_ZOOM_SHIFT_MULTIPLIER: f32 = 3.0;
+
+/// Ordered zoom multipliers `GameAction::ZoomIn`/`ZoomOut` step through, applied to the
+/// aspect-ratio-aware base fit radius (`zoom::calculate_base_fit_radius`). Index 0 is the
+/// widest framing (the full fit); later entries back the camera in closer.
+pub const ZOOM_PRESET_LEVELS: [f32; 5] = [1.0, 0.75, 0.5, 0.375, 0.25];
+
+/// Duration of the `CameraMoveList` animation spawned when stepping between
+/// `ZOOM_PRESET_LEVELS`.
+pub const ZOOM_PRESET_MOVE_DURATION_MS: f32 = 400.0;
+
+/// Fraction `target_radius` is multiplied by each time `GameAction::ZoomTowardAnchor` fires,
+/// i.e. how far a single anchored-zoom step dollies in.
+pub const ANCHORED_ZOOM_STEP_FACTOR: f32 = 0.6;
+
+/// Radians of pitch tilt `apply_cursor_zoom` applies per unit of cancelled zoom factor while
+/// `KeyCode::ControlLeft` is held, i.e. how fast scrolling tilts the camera instead of zooming it.
+pub const CURSOR_TILT_SENSITIVITY: f32 = 1.5;
+
+/// Duration of the `CameraMoveList` animation `apply_cursor_zoom` spawns for the "instant zoom"
+/// (`KeyCode::AltLeft` + scroll) to the standard height - smoothed rather than snapped, like
+/// `ZOOM_PRESET_MOVE_DURATION_MS` but a touch quicker since it's a single deliberate jump.
+pub const INSTANT_ZOOM_MOVE_DURATION_MS: f32 = 250.0;
+
 /// Font size for debug edge markers
 pub const EDGE_MARKER_FONT_SIZE: f32 = 11.0;
 
 /// Radius for edge marker spheres
 pub const EDGE_MARKER_SPHERE_RADIUS: f32 = 1.0;
+
+/// Trauma added to `ScreenShake` per unit of recoil impulse magnitude a `SpaceshipRecoiled`
+/// event reports.
+pub const SCREEN_SHAKE_RECOIL_SCALE: f32 = 0.001;
+
+/// How fast accumulated shake trauma decays back to zero, in units/sec.
+pub const SCREEN_SHAKE_DECAY_PER_SEC: f32 = 2.5;
+
+/// Largest camera-right-axis offset a maxed-out shake can apply.
+pub const SCREEN_SHAKE_MAX_OFFSET: f32 = 2.0;
+
+/// Oscillation frequency (Hz) of the shake offset.
+pub const SCREEN_SHAKE_FREQUENCY: f32 = 40.0;
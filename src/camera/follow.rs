@@ -0,0 +1,202 @@
+//! Cyclable alternative camera viewpoints (`GameAction::FollowCamera`), stepping through
+//! `CameraMode::Orbit -> Chase -> Cockpit -> Orbit` each time the key is pressed. `Chase` and
+//! `Cockpit` both write the `PanOrbitCamera` entity's `Transform` directly from the `Spaceship`'s
+//! own transform every frame, with `PanOrbitCamera::enabled` held false for as long as either is
+//! active so nothing else fights those writes. The one wrinkle is wraparound - on the frame
+//! `teleport_at_boundary` sets `Teleporter::just_teleported`, the ship's position jumps
+//! discontinuously, so that frame just carries the same delta over to the camera's `Transform`
+//! rather than re-deriving a framing that would otherwise visibly snap across the whole playfield.
+//! Cycling back to `Orbit` restores the orbit target captured on the way out and leaves
+//! `PanOrbitCameraExt::enable_interpolation` in charge of easing the `Transform` back onto it,
+//! rather than snapping.
+use avian3d::prelude::LinearVelocity;
+use avian3d::prelude::PhysicsSet;
+use bevy::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::CameraConfig;
+use super::PanOrbitCameraExt;
+use crate::actor::Spaceship;
+use crate::actor::SpaceshipControlConfig;
+use crate::actor::Teleporter;
+use crate::game_input::GameAction;
+use crate::game_input::just_pressed;
+
+pub struct FollowCameraPlugin;
+
+impl Plugin for FollowCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraMode>()
+            .add_observer(on_remove_saved_orbit_state)
+            .add_systems(
+                Update,
+                cycle_camera_mode.run_if(just_pressed(GameAction::FollowCamera)),
+            )
+            .add_systems(
+                PostUpdate,
+                (drive_follow_camera, update_speed_fov)
+                    .chain()
+                    .after(PhysicsSet::Sync)
+                    .run_if(not(resource_equals(CameraMode::Orbit))),
+            );
+    }
+}
+
+/// Which scheme currently drives the game camera's `Transform`.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+enum CameraMode {
+    #[default]
+    Orbit,
+    Chase,
+    Cockpit,
+}
+
+impl CameraMode {
+    const fn next(self) -> Self {
+        match self {
+            Self::Orbit => Self::Chase,
+            Self::Chase => Self::Cockpit,
+            Self::Cockpit => Self::Orbit,
+        }
+    }
+}
+
+/// `PanOrbitCamera`'s own orbit target, snapshotted the moment `CameraMode` leaves `Orbit` so it
+/// can be restored - rather than re-homed from scratch - once the cycle wraps back around.
+/// Removing this component is what triggers the restore; see `on_remove_saved_orbit_state`.
+#[derive(Component)]
+struct SavedOrbitState {
+    focus:  Vec3,
+    yaw:    f32,
+    pitch:  f32,
+    radius: f32,
+}
+
+/// Restores `PanOrbitCamera`'s target state the instant `SavedOrbitState` is removed, i.e. the
+/// instant `CameraMode` cycles back to `Orbit`. `cycle_camera_mode` already restored the
+/// configured smoothness before triggering this, so the camera eases back onto the restored
+/// orbit instead of snapping to it.
+fn on_remove_saved_orbit_state(
+    remove: On<Remove, SavedOrbitState>,
+    saved: Query<&SavedOrbitState>,
+    mut camera: Query<&mut PanOrbitCamera>,
+) {
+    let Ok(state) = saved.get(remove.entity) else {
+        return;
+    };
+    let Ok(mut pan_orbit) = camera.get_mut(remove.entity) else {
+        return;
+    };
+
+    pan_orbit.target_focus = state.focus;
+    pan_orbit.target_yaw = state.yaw;
+    pan_orbit.target_pitch = state.pitch;
+    pan_orbit.target_radius = state.radius;
+    pan_orbit.force_update = true;
+}
+
+/// Advances `CameraMode` and wires `PanOrbitCamera::enabled` to match: disabled the moment a
+/// follow mode (`Chase`/`Cockpit`) takes over direct control of the `Transform`, re-enabled (with
+/// the orbit state it owned restored) the moment the cycle wraps back to `Orbit`.
+fn cycle_camera_mode(
+    mut commands: Commands,
+    mut mode: ResMut<CameraMode>,
+    camera_config: Res<CameraConfig>,
+    mut camera_query: Query<(Entity, &mut PanOrbitCamera)>,
+) {
+    let Ok((entity, mut pan_orbit)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let previous = *mode;
+    *mode = previous.next();
+
+    if previous == CameraMode::Orbit {
+        commands.entity(entity).insert(SavedOrbitState {
+            focus:  pan_orbit.target_focus,
+            yaw:    pan_orbit.target_yaw,
+            pitch:  pan_orbit.target_pitch,
+            radius: pan_orbit.target_radius,
+        });
+        pan_orbit.enabled = false;
+    }
+
+    if *mode == CameraMode::Orbit {
+        pan_orbit.enable_interpolation(&camera_config);
+        pan_orbit.enabled = true;
+        commands.entity(entity).remove::<SavedOrbitState>();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn drive_follow_camera(
+    mode: Res<CameraMode>,
+    camera_config: Res<CameraConfig>,
+    spaceship: Query<(&Transform, &Teleporter), (With<Spaceship>, Without<PanOrbitCamera>)>,
+    mut camera_query: Query<&mut Transform, (With<PanOrbitCamera>, Without<Spaceship>)>,
+    mut last_ship_position: Local<Option<Vec3>>,
+) {
+    let Ok((ship_transform, teleporter)) = spaceship.single() else {
+        return;
+    };
+    let Ok(mut camera_transform) = camera_query.single_mut() else {
+        return;
+    };
+
+    if teleporter.just_teleported
+        && let (Some(new_position), Some(previous_position)) =
+            (teleporter.last_teleported_position, *last_ship_position)
+    {
+        camera_transform.translation += new_position - previous_position;
+        *last_ship_position = Some(ship_transform.translation);
+        return;
+    }
+
+    let (position, look_target) = match *mode {
+        CameraMode::Chase => (
+            ship_transform.translation
+                + ship_transform.back() * camera_config.follow_distance
+                + ship_transform.up() * camera_config.follow_height,
+            ship_transform.translation,
+        ),
+        CameraMode::Cockpit => (
+            ship_transform.translation,
+            ship_transform.translation + ship_transform.forward() * camera_config.follow_distance,
+        ),
+        CameraMode::Orbit => return,
+    };
+
+    camera_transform.translation = position;
+    camera_transform.look_at(look_target, ship_transform.up());
+    *last_ship_position = Some(ship_transform.translation);
+}
+
+/// Eases the follow camera's perspective FOV between `CameraConfig::fov_rest` and `fov_boost`
+/// based on how fast the spaceship is moving relative to `SpaceshipControlConfig::max_speed`, so
+/// accelerating reads as a subtle zoom-out and decelerating eases back in. Runs right after
+/// `drive_follow_camera` in the same `PostUpdate`, `PhysicsSet::Sync`-ordered slot, so both sample
+/// the spaceship's settled end-of-step transform/velocity rather than a mid-step one.
+fn update_speed_fov(
+    time: Res<Time>,
+    camera_config: Res<CameraConfig>,
+    control_config: Res<SpaceshipControlConfig>,
+    spaceship: Query<&LinearVelocity, With<Spaceship>>,
+    mut camera_query: Query<&mut Projection, With<PanOrbitCamera>>,
+) {
+    let Ok(velocity) = spaceship.single() else {
+        return;
+    };
+    let Ok(mut projection) = camera_query.single_mut() else {
+        return;
+    };
+    let Projection::Perspective(perspective) = &mut *projection else {
+        return;
+    };
+
+    let speed_ratio = (velocity.length() / control_config.max_speed).clamp(0.0, 1.0);
+    let target_fov =
+        camera_config.fov_rest + (camera_config.fov_boost - camera_config.fov_rest) * speed_ratio;
+
+    let t = 1.0 - (-camera_config.fov_smoothing * time.delta_secs()).exp();
+    perspective.fov += (target_fov - perspective.fov) * t;
+}
@@ -1,40 +1,69 @@
+mod auto_frame;
 mod cameras;
 mod config;
 mod constants;
+mod follow;
 mod lights;
 mod move_queue;
+mod offscreen_indicator;
 mod pan_orbit_ext;
+mod screen_anchor;
+mod shake;
+mod star_instancing;
+mod star_light_grid;
 mod star_twinkling;
+mod starfield_shader;
 mod stars;
 mod zoom;
 
+use auto_frame::AutoFramePlugin;
 use bevy::camera::visibility::Layer;
 use bevy::prelude::*;
 use cameras::CamerasPlugin;
+use follow::FollowCameraPlugin;
 pub use cameras::Edge;
 pub use cameras::ScreenSpaceBoundary;
 pub use cameras::calculate_home_radius;
 pub use config::CameraConfig;
 use config::CameraConfigPlugin;
 pub use config::ZoomConfig;
+pub use config::ZoomFitMode;
 use lights::DirectionalLightsPlugin;
 pub use move_queue::CameraMove;
 pub use move_queue::CameraMoveList;
 use move_queue::MoveQueuePlugin;
+pub use offscreen_indicator::OffscreenIndicator;
+use offscreen_indicator::OffscreenIndicatorPlugin;
 pub use pan_orbit_ext::PanOrbitCameraExt;
+pub use screen_anchor::FollowScreenAnchor;
+use screen_anchor::ScreenAnchorPlugin;
+use shake::ScreenShakePlugin;
+use star_instancing::StarInstancingPlugin;
+pub use star_light_grid::StarLightGrid;
+use star_light_grid::StarLightGridPlugin;
 use star_twinkling::StarTwinklingPlugin;
+use starfield_shader::StarfieldShaderPlugin;
 use stars::StarsPlugin;
+pub use zoom::FitTarget;
 use zoom::ZoomPlugin;
 
 pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(CameraConfigPlugin)
+        app.add_plugins(AutoFramePlugin)
+            .add_plugins(CameraConfigPlugin)
             .add_plugins(CamerasPlugin)
             .add_plugins(DirectionalLightsPlugin)
+            .add_plugins(FollowCameraPlugin)
             .add_plugins(MoveQueuePlugin)
+            .add_plugins(OffscreenIndicatorPlugin)
+            .add_plugins(ScreenAnchorPlugin)
+            .add_plugins(ScreenShakePlugin)
+            .add_plugins(StarInstancingPlugin)
+            .add_plugins(StarLightGridPlugin)
             .add_plugins(StarTwinklingPlugin)
+            .add_plugins(StarfieldShaderPlugin)
             .add_plugins(StarsPlugin)
             .add_plugins(ZoomPlugin);
     }
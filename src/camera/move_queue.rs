@@ -16,27 +16,91 @@ use super::PanOrbitCameraExt;
 pub struct MoveQueuePlugin;
 
 impl Plugin for MoveQueuePlugin {
-    fn build(&self, app: &mut App) { app.add_systems(Update, move_camera_system); }
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraOrbitVelocity>()
+            .add_systems(Update, move_camera_system);
+    }
+}
+
+/// Per-axis yaw/pitch/radius rate (units per second) of the `PanOrbitCamera`'s last
+/// `move_camera_system` step, refreshed every frame a [`CameraMoveList`] is in progress. Captured
+/// here rather than in [`MoveState`] so a freshly inserted
+/// [`CameraMoveList::with_velocity_continuity`] can still read the outgoing queue's momentum even
+/// though replacing the component discards the old `MoveState` along with it.
+#[derive(Resource, Default, Clone, Copy)]
+struct CameraOrbitVelocity {
+    yaw:    f32,
+    pitch:  f32,
+    radius: f32,
 }
 
-/// Individual camera movement with target position and duration
+/// Individual camera movement with target position and duration.
+///
+/// [`CameraMove::ToTarget`] bakes in a stationary destination captured once when the move is
+/// queued; [`CameraMove::Follow`] instead tracks a live `Entity`'s transform, so
+/// [`move_camera_system`] re-derives the canonical radius/yaw/pitch from the entity's current
+/// position every frame and the camera smoothly chases it.
 #[derive(Clone, Reflect)]
-pub struct CameraMove {
-    pub target_translation: Vec3, // Where to position the camera in world space
-    pub target_focus:       Vec3, // What point the camera should look at
-    pub duration_ms:        f32,  // Duration in milliseconds to complete this move
-    pub easing:             EaseFunction, // Easing function for this move
+pub enum CameraMove {
+    ToTarget {
+        target_translation: Vec3, // Where to position the camera in world space
+        target_focus:       Vec3, // What point the camera should look at
+        duration_ms:        f32,  // Duration in milliseconds to complete this move
+        easing:             EaseFunction, // Easing function for this move
+    },
+    Follow {
+        target:      Entity, // Entity whose transform is tracked every frame
+        offset:      Vec3,   // Camera position relative to the target, re-applied every frame
+        duration_ms: f32,    // Duration in milliseconds to complete this move
+        easing:      EaseFunction, // Easing function for this move
+    },
+}
+
+impl CameraMove {
+    const fn duration_ms(&self) -> f32 {
+        match self {
+            Self::ToTarget { duration_ms, .. } | Self::Follow { duration_ms, .. } => *duration_ms,
+        }
+    }
+
+    fn easing(&self) -> EaseFunction {
+        match self {
+            Self::ToTarget { easing, .. } | Self::Follow { easing, .. } => easing.clone(),
+        }
+    }
+
+    /// Resolves this frame's destination translation/focus - fixed for [`Self::ToTarget`],
+    /// re-read from `targets` every call for [`Self::Follow`] (falling back to the origin if the
+    /// tracked entity has despawned mid-move).
+    fn resolve(&self, targets: &Query<&GlobalTransform>) -> (Vec3, Vec3) {
+        match self {
+            Self::ToTarget { target_translation, target_focus, .. } => {
+                (*target_translation, *target_focus)
+            },
+            Self::Follow { target, offset, .. } => {
+                let focus = targets.get(*target).map_or(Vec3::ZERO, GlobalTransform::translation);
+                (focus + *offset, focus)
+            },
+        }
+    }
 }
 
 /// State tracking for the current camera movement
 #[derive(Clone, Reflect, Default, Debug)]
 enum MoveState {
     InProgress {
-        elapsed_ms:   f32,
-        start_focus:  Vec3,
-        start_pitch:  f32,
-        start_radius: f32,
-        start_yaw:    f32,
+        elapsed_ms:        f32,
+        start_focus:       Vec3,
+        start_pitch:       f32,
+        start_radius:      f32,
+        start_yaw:         f32,
+        /// Outgoing yaw/pitch/radius rates (units per second) captured from
+        /// [`CameraOrbitVelocity`] when this move started, used as the Hermite start tangent in
+        /// [`move_camera_system`]'s velocity-continuity mode. Zero unless
+        /// [`CameraMoveList::velocity_continuity`] is set.
+        start_yaw_rate:    f32,
+        start_pitch_rate:  f32,
+        start_radius_rate: f32,
     },
     #[default]
     Ready,
@@ -53,8 +117,19 @@ enum MoveState {
 #[derive(Component, Reflect, Default)]
 #[reflect(Component, Default)]
 pub struct CameraMoveList {
-    pub moves: VecDeque<CameraMove>,
-    state:     MoveState,
+    pub moves:           VecDeque<CameraMove>,
+    state:               MoveState,
+    smooth_path:         bool,
+    /// The control point just before the current segment's start (`P0` in
+    /// [`move_camera_system`]'s Catmull-Rom evaluation), carried over from the previous segment's
+    /// own start so the spline has a neighbor to lean on past the first move. `None` for the very
+    /// first segment, which duplicates its own start instead (`P0 = P1`).
+    spline_p0:           Option<(Vec3, Vec3)>,
+    /// When set, [`move_camera_system`] seeds each move's starting yaw/pitch/radius tangent from
+    /// [`CameraOrbitVelocity`] instead of starting at rest, so replacing an in-progress
+    /// `CameraMoveList` with a new one preserves the outgoing momentum rather than snapping to a
+    /// dead stop.
+    velocity_continuity: bool,
 }
 
 impl CameraMoveList {
@@ -62,6 +137,39 @@ impl CameraMoveList {
         Self {
             moves,
             state: MoveState::Ready,
+            smooth_path: false,
+            spline_p0: None,
+            velocity_continuity: false,
+        }
+    }
+
+    /// Like [`Self::new`], but [`move_camera_system`] animates translation and focus across the
+    /// whole queue as a Catmull-Rom spline instead of interpolating linearly segment-by-segment,
+    /// giving C1-continuous motion across `CameraMove` boundaries - smooth multi-waypoint flights
+    /// instead of a visible kink at every intermediate target. Assumes every move is a
+    /// [`CameraMove::ToTarget`]; a [`CameraMove::Follow`] entry has no fixed control point, so its
+    /// live resolved position is used as a control point for that frame only, without smoothing.
+    pub const fn with_smooth_path(moves: VecDeque<CameraMove>) -> Self {
+        Self {
+            moves,
+            state: MoveState::Ready,
+            smooth_path: true,
+            spline_p0: None,
+            velocity_continuity: false,
+        }
+    }
+
+    /// Like [`Self::new`], but the first move blends in from the outgoing
+    /// [`CameraOrbitVelocity`] instead of starting at rest - eliminates the visible velocity snap
+    /// that comes from replacing an in-progress `CameraMoveList` with a fresh one mid-flight,
+    /// producing a decelerating arrival into the new target instead of an instantaneous restart.
+    pub const fn with_velocity_continuity(moves: VecDeque<CameraMove>) -> Self {
+        Self {
+            moves,
+            state: MoveState::Ready,
+            smooth_path: false,
+            spline_p0: None,
+            velocity_continuity: true,
         }
     }
 
@@ -71,21 +179,61 @@ impl CameraMoveList {
         let current_remaining = match &self.state {
             MoveState::InProgress { elapsed_ms, .. } => {
                 if let Some(current_move) = self.moves.front() {
-                    (current_move.duration_ms - elapsed_ms).max(0.0)
+                    (current_move.duration_ms() - elapsed_ms).max(0.0)
                 } else {
                     0.0
                 }
             },
-            MoveState::Ready => self.moves.front().map_or(0.0, |m| m.duration_ms),
+            MoveState::Ready => self.moves.front().map_or(0.0, CameraMove::duration_ms),
         };
 
         // Add duration of all remaining moves (skip first since already counted)
-        let remaining_queue: f32 = self.moves.iter().skip(1).map(|m| m.duration_ms).sum();
+        let remaining_queue: f32 = self.moves.iter().skip(1).map(CameraMove::duration_ms).sum();
 
         current_remaining + remaining_queue
     }
 }
 
+/// Evaluates the Catmull-Rom segment between control points `p1` and `p2` (with neighbors `p0`
+/// and `p3`) at normalized parameter `t` in `[0, 1]`.
+fn catmull_rom(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * (2.0 * p1
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * t3)
+}
+
+/// Inverse of the `offset -> (radius, yaw, pitch)` derivation in [`move_camera_system`]: turns
+/// canonical orbital parameters back into the world-space offset from the focus point.
+fn orbit_offset(radius: f32, yaw: f32, pitch: f32) -> Vec3 {
+    let horizontal = radius * pitch.cos();
+    Vec3::new(horizontal * yaw.sin(), -radius * pitch.sin(), horizontal * yaw.cos())
+}
+
+/// Evaluates a cubic Hermite curve from `p0` (with start tangent `m0`) to `p1` (with an implicit
+/// zero end tangent) at normalized parameter `t` in `[0, 1]`. `move_camera_system`'s
+/// velocity-continuity mode uses this in place of the usual eased lerp so a freshly queued move's
+/// starting rate matches the outgoing queue's momentum instead of snapping to rest, while still
+/// decelerating smoothly into `p1` by the end of the move.
+fn hermite(p0: f32, m0: f32, p1: f32, t: f32) -> f32 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+    let h10 = t3 - 2.0 * t2 + t;
+    let h01 = -2.0 * t3 + 3.0 * t2;
+    p0 * h00 + m0 * h10 + p1 * h01
+}
+
+/// Wraps `current - previous` into `[-PI, PI]`, so a per-frame yaw/pitch rate computed from two
+/// raw samples stays continuous across the +-PI seam instead of spiking when the angle wraps.
+fn wrapped_angle_delta(current: f32, previous: f32) -> f32 {
+    let diff = current - previous;
+    std::f32::consts::TAU
+        .mul_add(-((diff + std::f32::consts::PI) / std::f32::consts::TAU).floor(), diff)
+}
+
 /// System that processes camera movement queues with duration-based linear interpolation
 ///
 /// When the `PanOrbitCamera` has a `MoveQueue`, interpolates linearly toward the target over
@@ -95,6 +243,8 @@ pub fn move_camera_system(
     mut commands: Commands,
     time: Res<Time>,
     mut camera_query: Single<(Entity, &mut PanOrbitCamera, &mut CameraMoveList)>,
+    targets: Query<&GlobalTransform>,
+    mut orbit_velocity: ResMut<CameraOrbitVelocity>,
 ) {
     let (entity, ref mut pan_orbit, ref mut queue) = *camera_query;
 
@@ -114,12 +264,21 @@ pub fn move_camera_system(
             pan_orbit.disable_interpolation();
 
             // Transition to InProgress with captured starting orbital parameters
+            let (start_yaw_rate, start_pitch_rate, start_radius_rate) =
+                if queue.velocity_continuity {
+                    (orbit_velocity.yaw, orbit_velocity.pitch, orbit_velocity.radius)
+                } else {
+                    (0.0, 0.0, 0.0)
+                };
             queue.state = MoveState::InProgress {
-                elapsed_ms:   0.0,
-                start_focus:  pan_orbit.target_focus,
+                elapsed_ms: 0.0,
+                start_focus: pan_orbit.target_focus,
                 start_radius: pan_orbit.target_radius,
-                start_yaw:    pan_orbit.target_yaw,
-                start_pitch:  pan_orbit.target_pitch,
+                start_yaw: pan_orbit.target_yaw,
+                start_pitch: pan_orbit.target_pitch,
+                start_yaw_rate,
+                start_pitch_rate,
+                start_radius_rate,
             };
         },
         MoveState::InProgress {
@@ -128,28 +287,89 @@ pub fn move_camera_system(
             start_radius,
             start_yaw,
             start_pitch,
+            start_yaw_rate,
+            start_pitch_rate,
+            start_radius_rate,
         } => {
+            let (previous_yaw, previous_pitch, previous_radius) =
+                (pan_orbit.target_yaw, pan_orbit.target_pitch, pan_orbit.target_radius);
+
             // Update elapsed time
             *elapsed_ms += time.delta_secs() * 1000.0;
 
             // Calculate interpolation factor (0.0 to 1.0)
-            let t = (*elapsed_ms / current_move.duration_ms).min(1.0);
+            let t = (*elapsed_ms / current_move.duration_ms()).min(1.0);
 
             let is_final_frame = t >= 1.0;
 
+            // Clamp t to exactly 1.0 if over (important for smooth completion)
+            let t_clamped = t.min(1.0);
+
+            // Apply easing function from the move
+            let t_interp = current_move.easing().sample_unchecked(t_clamped);
+
+            // Re-read the destination every frame - a no-op for `ToTarget`, but lets `Follow`
+            // chase a moving entity instead of baking in a stationary destination.
+            let (target_translation, target_focus) = current_move.resolve(&targets);
+
+            if queue.smooth_path {
+                // Spline mode replaces the per-frame orbital-parameter lerp below with a
+                // Catmull-Rom evaluation in cartesian space, then derives yaw/pitch/radius from
+                // the splined offset - giving C1-continuous motion across move boundaries instead
+                // of the velocity discontinuity a segment-by-segment lerp leaves at each waypoint.
+                let start_translation =
+                    *start_focus + orbit_offset(*start_radius, *start_yaw, *start_pitch);
+                let (p0_translation, p0_focus) =
+                    queue.spline_p0.unwrap_or((start_translation, *start_focus));
+                let (p3_translation, p3_focus) = queue
+                    .moves
+                    .get(1)
+                    .map_or((target_translation, target_focus), |next| {
+                        next.resolve(&targets)
+                    });
+
+                let spline_translation = catmull_rom(
+                    p0_translation,
+                    start_translation,
+                    target_translation,
+                    p3_translation,
+                    t_interp,
+                );
+                let spline_focus =
+                    catmull_rom(p0_focus, *start_focus, target_focus, p3_focus, t_interp);
+
+                let offset = spline_translation - spline_focus;
+                let horizontal_dist = offset.x.hypot(offset.z);
+                pan_orbit.target_focus = spline_focus;
+                pan_orbit.target_radius = offset.length();
+                pan_orbit.target_yaw = offset.x.atan2(offset.z);
+                pan_orbit.target_pitch = (-offset.y).atan2(horizontal_dist);
+                pan_orbit.force_update = true;
+
+                let dt = time.delta_secs();
+                if dt > 0.0 {
+                    orbit_velocity.yaw =
+                        wrapped_angle_delta(pan_orbit.target_yaw, previous_yaw) / dt;
+                    orbit_velocity.pitch =
+                        wrapped_angle_delta(pan_orbit.target_pitch, previous_pitch) / dt;
+                    orbit_velocity.radius = (pan_orbit.target_radius - previous_radius) / dt;
+                }
+
+                if is_final_frame {
+                    queue.spline_p0 = Some((start_translation, *start_focus));
+                    queue.moves.pop_front();
+                    queue.state = MoveState::Ready;
+                }
+                return;
+            }
+
             // Calculate canonical orbital parameters from target position
-            let offset = current_move.target_translation - current_move.target_focus;
+            let offset = target_translation - target_focus;
             let canonical_radius = offset.length();
             let canonical_yaw = offset.x.atan2(offset.z);
             let horizontal_dist = offset.x.hypot(offset.z);
             let canonical_pitch = (-offset.y).atan2(horizontal_dist);
 
-            // Clamp t to exactly 1.0 if over (important for smooth completion)
-            let t_clamped = t.min(1.0);
-
-            // Apply easing function from the move
-            let t_interp = current_move.easing.sample_unchecked(t_clamped);
-
             // Determine angle diffs: unwrap during animation, canonical on final frame
             let (yaw_diff, pitch_diff) = if is_last_move && is_final_frame {
                 // Final frame of last move: use canonical angles (no unwrapping)
@@ -176,14 +396,47 @@ pub fn move_camera_system(
                 (yaw_diff, pitch_diff)
             };
 
-            // Interpolate to target (single code path for all cases)
-            pan_orbit.target_focus = start_focus.lerp(current_move.target_focus, t_interp);
-            pan_orbit.target_radius =
-                (canonical_radius - *start_radius).mul_add(t_interp, *start_radius);
-            pan_orbit.target_yaw = yaw_diff.mul_add(t_interp, *start_yaw);
-            pan_orbit.target_pitch = pitch_diff.mul_add(t_interp, *start_pitch);
+            // Interpolate to target. Velocity-continuity mode replaces the plain eased lerp for
+            // yaw/pitch/radius with a Hermite blend seeded by the outgoing rate, so a freshly
+            // queued move picks up the prior queue's momentum instead of snapping to rest; focus
+            // stays a plain lerp since the request only concerns angular/radial velocity.
+            pan_orbit.target_focus = start_focus.lerp(target_focus, t_interp);
+            if queue.velocity_continuity {
+                let duration_secs = current_move.duration_ms() / 1000.0;
+                pan_orbit.target_radius = hermite(
+                    *start_radius,
+                    *start_radius_rate * duration_secs,
+                    canonical_radius,
+                    t_interp,
+                );
+                pan_orbit.target_yaw = hermite(
+                    *start_yaw,
+                    *start_yaw_rate * duration_secs,
+                    *start_yaw + yaw_diff,
+                    t_interp,
+                );
+                pan_orbit.target_pitch = hermite(
+                    *start_pitch,
+                    *start_pitch_rate * duration_secs,
+                    *start_pitch + pitch_diff,
+                    t_interp,
+                );
+            } else {
+                pan_orbit.target_radius =
+                    (canonical_radius - *start_radius).mul_add(t_interp, *start_radius);
+                pan_orbit.target_yaw = yaw_diff.mul_add(t_interp, *start_yaw);
+                pan_orbit.target_pitch = pitch_diff.mul_add(t_interp, *start_pitch);
+            }
             pan_orbit.force_update = true;
 
+            let dt = time.delta_secs();
+            if dt > 0.0 {
+                orbit_velocity.yaw = wrapped_angle_delta(pan_orbit.target_yaw, previous_yaw) / dt;
+                orbit_velocity.pitch =
+                    wrapped_angle_delta(pan_orbit.target_pitch, previous_pitch) / dt;
+                orbit_velocity.radius = (pan_orbit.target_radius - previous_radius) / dt;
+            }
+
             // Check if move complete and advance to next
             if is_final_frame {
                 queue.moves.pop_front();
@@ -0,0 +1,158 @@
+//! Directional HUD markers for tracked entities that have left the viewport - asteroids, threats,
+//! anything a caller tags with [`OffscreenIndicator`]. Reuses the same tangent-space projection
+//! [`ScreenSpaceBoundary::from_camera_view`] builds for zoom-to-fit, fed a single corner (the
+//! target's own position) instead of the playfield's eight.
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+use super::CameraOrder;
+use super::ScreenSpaceBoundary;
+use crate::camera::RenderLayer;
+use crate::state::PlayingGame;
+
+/// Screen-space units the marker's arrowhead occupies at `avg_depth == 1.0` - scaled by the
+/// target's actual depth each frame so the marker reads as a constant on-screen size regardless
+/// of how far off-screen (and therefore how far from the camera) the target has drifted.
+const INDICATOR_SIZE: f32 = 0.025;
+/// Fraction inset from the true screen edge the marker clamps to, so it never draws flush against
+/// the viewport border.
+const INDICATOR_EDGE_MARGIN: f32 = 0.92;
+/// Stand-in depth (world units) used to place a marker for a target directly behind the camera,
+/// where `ScreenSpaceBoundary` has no real depth to report.
+const BEHIND_CAMERA_MARKER_DEPTH: f32 = 50.0;
+
+pub struct OffscreenIndicatorPlugin;
+
+impl Plugin for OffscreenIndicatorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_gizmo_group::<OffscreenIndicatorGizmo>()
+            .add_systems(Startup, configure_offscreen_indicator_gizmo)
+            .add_systems(
+                Update,
+                draw_offscreen_indicators.run_if(in_state(PlayingGame)),
+            );
+    }
+}
+
+#[derive(Default, Reflect, GizmoConfigGroup)]
+struct OffscreenIndicatorGizmo {}
+
+fn configure_offscreen_indicator_gizmo(mut config_store: ResMut<GizmoConfigStore>) {
+    let (config, _) = config_store.config_mut::<OffscreenIndicatorGizmo>();
+    config.render_layers = RenderLayers::from_layers(RenderLayer::Game.layers());
+}
+
+/// Opts an entity into off-screen directional markers - attach to anything players should be
+/// able to track past the edge of the viewport (asteroids, threats), with `icon_color`
+/// distinguishing target types.
+#[derive(Component)]
+pub struct OffscreenIndicator {
+    pub icon_color: Color,
+}
+
+/// For each [`OffscreenIndicator`] currently outside the Game camera's frustum, clamps its
+/// tangent-space position to the (inset) screen edge and draws an arrow there pointing back
+/// toward the target's true direction. Entities still on-screen draw nothing.
+fn draw_offscreen_indicators(
+    mut gizmos: Gizmos<OffscreenIndicatorGizmo>,
+    camera_query: Query<(&Camera, &GlobalTransform, &Projection)>,
+    targets: Query<(&GlobalTransform, &OffscreenIndicator)>,
+) {
+    let Some((camera, cam_global, projection)) = camera_query
+        .iter()
+        .find(|(camera, ..)| camera.order == CameraOrder::Game.order())
+    else {
+        return;
+    };
+
+    let Projection::Perspective(perspective) = projection else {
+        return;
+    };
+
+    let aspect_ratio = camera
+        .logical_viewport_size()
+        .map_or(perspective.aspect_ratio, |size| size.x / size.y);
+
+    let cam_pos = cam_global.translation();
+    let cam_rot = cam_global.rotation();
+    let cam_right = cam_rot * Vec3::X;
+    let cam_up = cam_rot * Vec3::Y;
+    let cam_forward = cam_rot * Vec3::NEG_Z;
+    let half_tan_vfov = (perspective.fov * 0.5).tan();
+    let half_tan_hfov = half_tan_vfov * aspect_ratio;
+
+    for (target_global, indicator) in &targets {
+        // (true_x, true_y, depth): the target's un-clamped tangent-space position and how far
+        // in front of the camera the marker should sit. Behind-camera targets have no real
+        // depth to measure, so fall back to their raw lateral offset at a fixed stand-in depth.
+        let (true_x, true_y, depth) = match ScreenSpaceBoundary::from_camera_view(
+            &[target_global.translation()],
+            cam_global,
+            perspective,
+            aspect_ratio,
+            1.0,
+        ) {
+            Some(margins)
+                if margins.left_margin >= 0.0
+                    && margins.right_margin >= 0.0
+                    && margins.top_margin >= 0.0
+                    && margins.bottom_margin >= 0.0 =>
+            {
+                continue;
+            },
+            Some(margins) => (margins.min_norm_x, margins.min_norm_y, margins.avg_depth),
+            None => {
+                let relative = target_global.translation() - cam_pos;
+                (
+                    relative.dot(cam_right),
+                    relative.dot(cam_up),
+                    BEHIND_CAMERA_MARKER_DEPTH,
+                )
+            },
+        };
+
+        let edge_x = half_tan_hfov * INDICATOR_EDGE_MARGIN;
+        let edge_y = half_tan_vfov * INDICATOR_EDGE_MARGIN;
+        let clamped_x = true_x.clamp(-edge_x, edge_x);
+        let clamped_y = true_y.clamp(-edge_y, edge_y);
+
+        let direction = Vec2::new(true_x - clamped_x, true_y - clamped_y).normalize_or(Vec2::Y);
+        let marker_position =
+            cam_pos + cam_forward * depth + cam_right * clamped_x + cam_up * clamped_y;
+
+        draw_arrow(
+            &mut gizmos,
+            marker_position,
+            direction,
+            cam_right,
+            cam_up,
+            INDICATOR_SIZE * depth,
+            indicator.icon_color,
+        );
+    }
+}
+
+/// Draws a simple arrowhead - a shaft plus two back-swept wings - pointing along `direction`
+/// (a unit vector in the camera's own image plane, spanned by `plane_right`/`plane_up`).
+fn draw_arrow(
+    gizmos: &mut Gizmos<OffscreenIndicatorGizmo>,
+    position: Vec3,
+    direction: Vec2,
+    plane_right: Vec3,
+    plane_up: Vec3,
+    size: f32,
+    color: Color,
+) {
+    let forward = plane_right * direction.x + plane_up * direction.y;
+    let perpendicular = plane_right * -direction.y + plane_up * direction.x;
+
+    let tip = position + forward * size;
+    let back = position - forward * size * 0.3;
+    let wing_spread = size * 0.5;
+    let left_wing = back + perpendicular * wing_spread;
+    let right_wing = back - perpendicular * wing_spread;
+
+    gizmos.line(tip, left_wing, color);
+    gizmos.line(tip, right_wing, color);
+    gizmos.line(left_wing, right_wing, color);
+}
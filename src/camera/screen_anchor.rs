@@ -0,0 +1,58 @@
+//! Pins a UI `Node` to the screen-space projection of a tracked world entity - floating
+//! nameplates, damage numbers, waypoint markers - by projecting the target's `GlobalTransform`
+//! each frame with `Camera::world_to_viewport`, the same projection `draw_camera_focus_gizmo`
+//! uses for its distance label.
+use bevy::prelude::*;
+
+use super::CameraOrder;
+
+pub struct ScreenAnchorPlugin;
+
+impl Plugin for ScreenAnchorPlugin {
+    fn build(&self, app: &mut App) { app.add_systems(Update, update_screen_anchors); }
+}
+
+/// Pins the entity's UI `Node` to the screen-space projection of `target`, offset by `offset`
+/// screen-space pixels. Hidden (via `Visibility::Hidden`) whenever `target` is behind the camera
+/// or outside the frustum, since `world_to_viewport` returns an error in that case.
+#[derive(Component)]
+pub struct FollowScreenAnchor {
+    pub target: Entity,
+    pub offset: Vec2,
+}
+
+/// Projects each [`FollowScreenAnchor`]'s `target` into screen space and moves its `Node` there,
+/// hiding it when the projection fails. Runs in `Update` alongside `bevy_panorbit_camera`'s own
+/// movement system rather than a dedicated ordering set - like `apply_cursor_zoom`, this risks a
+/// one-frame lag against `CameraMoveList` interpolation rather than a correctness issue, since
+/// `GlobalTransform` propagation always lands the *previous* frame's camera position by the time
+/// `Update` runs anyway.
+fn update_screen_anchors(
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    targets: Query<&GlobalTransform, Without<FollowScreenAnchor>>,
+    mut anchors: Query<(&FollowScreenAnchor, &mut Node, &mut Visibility)>,
+) {
+    let Some((camera, cam_global)) = camera_query
+        .iter()
+        .find(|(camera, _)| camera.order == CameraOrder::Game.order())
+    else {
+        return;
+    };
+
+    for (anchor, mut node, mut visibility) in &mut anchors {
+        let Ok(target_global) = targets.get(anchor.target) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let Ok(screen_pos) = camera.world_to_viewport(cam_global, target_global.translation())
+        else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        *visibility = Visibility::Inherited;
+        node.left = Val::Px(screen_pos.x + anchor.offset.x);
+        node.top = Val::Px(screen_pos.y + anchor.offset.y);
+    }
+}
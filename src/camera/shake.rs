@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::constants::SCREEN_SHAKE_DECAY_PER_SEC;
+use super::constants::SCREEN_SHAKE_FREQUENCY;
+use super::constants::SCREEN_SHAKE_MAX_OFFSET;
+use super::constants::SCREEN_SHAKE_RECOIL_SCALE;
+use crate::actor::SpaceshipRecoiled;
+
+pub struct ScreenShakePlugin;
+
+impl Plugin for ScreenShakePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenShake>()
+            .add_observer(on_spaceship_recoiled)
+            .add_systems(Update, apply_screen_shake);
+    }
+}
+
+/// Accumulated shake "trauma" (0.0-1.0), decaying back to zero over time. Offset applied each
+/// frame scales with `trauma.powi(2)` - small knocks barely register, a maxed-out budget punches
+/// the camera hard - the standard squared-trauma shake curve.
+#[derive(Resource, Default, Debug)]
+struct ScreenShake {
+    trauma: f32,
+}
+
+fn on_spaceship_recoiled(trigger: On<SpaceshipRecoiled>, mut shake: ResMut<ScreenShake>) {
+    shake.trauma = (shake.trauma + trigger.magnitude * SCREEN_SHAKE_RECOIL_SCALE).min(1.0);
+}
+
+/// Nudges the game camera sideways by a small, decaying, oscillating offset whenever recoil has
+/// left trauma on the budget. The offset is recomputed from scratch each frame rather than
+/// accumulated onto the transform, so it fades cleanly as `trauma` decays instead of drifting.
+fn apply_screen_shake(
+    time: Res<Time>,
+    mut shake: ResMut<ScreenShake>,
+    mut camera: Query<&mut Transform, (With<Camera>, With<PanOrbitCamera>)>,
+) {
+    shake.trauma = (shake.trauma - SCREEN_SHAKE_DECAY_PER_SEC * time.delta_secs()).max(0.0);
+
+    if shake.trauma <= 0.0 {
+        return;
+    }
+
+    let Ok(mut transform) = camera.single_mut() else {
+        return;
+    };
+
+    let offset = shake.trauma * shake.trauma
+        * SCREEN_SHAKE_MAX_OFFSET
+        * (time.elapsed_secs() * SCREEN_SHAKE_FREQUENCY).sin();
+    transform.translation += transform.right() * offset;
+}
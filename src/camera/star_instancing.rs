@@ -0,0 +1,139 @@
+//! Bakes stars into static [`Mesh`]es - one per `StarLayer` - instead of spawning one
+//! entity/material pair per star, so each layer renders with a single draw call. `stars.rs` builds
+//! each layer's mesh once from its stars' (position, radius, emissive) via [`build_star_field_mesh`];
+//! `star_twinkling.rs` repaints only the vertex range of whichever stars are mid-twinkle via
+//! [`set_star_color`], instead of an ECS query plus a `materials.get_mut` per star.
+use bevy::pbr::Material;
+use bevy::pbr::MaterialPlugin;
+use bevy::prelude::*;
+use bevy::render::mesh::Indices;
+use bevy::render::mesh::PrimitiveTopology;
+use bevy::render::mesh::VertexAttributeValues;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::AsBindGroup;
+use bevy::render::render_resource::ShaderRef;
+
+pub struct StarInstancingPlugin;
+
+impl Plugin for StarInstancingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MaterialPlugin::<StarInstanceMaterial>::default());
+    }
+}
+
+/// One star's baked-mesh inputs: world-space position (pre field-rotation - the whole field
+/// rotates through a single entity `Transform` in `stars.rs::rotate_stars`), sphere radius, and
+/// starting emissive color.
+#[derive(Clone, Copy)]
+pub struct StarInstance {
+    pub position: Vec3,
+    pub radius:   f32,
+    pub emissive: Vec4,
+}
+
+/// Emissive-only material for the merged star mesh. Keeps the default PBR vertex shader (so
+/// baked vertex colors still flow through `forward_io::VertexOutput`) and overrides only the
+/// fragment shader (`shaders/star_instances.wgsl`) to output each star's vertex color directly,
+/// unlit and unaffected by scene lighting - the same "override fragment only" technique
+/// `starfield_shader.rs`'s `StarfieldMaterial` uses.
+#[derive(Asset, TypePath, AsBindGroup, Clone, Default)]
+pub struct StarInstanceMaterial {}
+
+impl Material for StarInstanceMaterial {
+    fn fragment_shader() -> ShaderRef { "shaders/star_instances.wgsl".into() }
+}
+
+/// Replicates `base_sphere`'s vertices once per star, scaled by radius and offset by position,
+/// with the star's emissive baked onto every vertex it contributes - producing one mesh that
+/// draws every star in a single call. Falls back to an empty mesh if `base_sphere` is missing
+/// position/index data (shouldn't happen for a procedural `Sphere` mesh).
+pub fn build_star_field_mesh(base_sphere: &Mesh, stars: &[StarInstance]) -> Mesh {
+    let empty = || Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+
+    let Some(VertexAttributeValues::Float32x3(base_positions)) =
+        base_sphere.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return empty();
+    };
+    let Some(base_indices) = base_sphere.indices() else {
+        return empty();
+    };
+    let base_indices: Vec<u32> = base_indices.iter().map(|index| index as u32).collect();
+    let verts_per_star = base_positions.len() as u32;
+
+    let mut positions = Vec::with_capacity(stars.len() * base_positions.len());
+    let mut colors = Vec::with_capacity(stars.len() * base_positions.len());
+    let mut indices = Vec::with_capacity(stars.len() * base_indices.len());
+
+    for (star_index, star) in stars.iter().enumerate() {
+        let vertex_offset = star_index as u32 * verts_per_star;
+        let color = [star.emissive.x, star.emissive.y, star.emissive.z, star.emissive.w];
+
+        for base_position in base_positions {
+            let position = Vec3::from(*base_position) * star.radius + star.position;
+            positions.push([position.x, position.y, position.z]);
+            colors.push(color);
+        }
+
+        indices.extend(base_indices.iter().map(|&index| index + vertex_offset));
+    }
+
+    empty()
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+        .with_inserted_indices(Indices::U32(indices))
+}
+
+/// Overwrites just the vertex-position range belonging to `star_index`, re-deriving each vertex
+/// from `base_sphere`'s unit-sphere positions scaled by `radius` and offset by `position` - the
+/// same transform [`build_star_field_mesh`] applies per star, but for one recycled star instead
+/// of the whole field.
+pub fn set_star_position(
+    mesh: &mut Mesh,
+    base_sphere: &Mesh,
+    star_index: usize,
+    verts_per_star: usize,
+    position: Vec3,
+    radius: f32,
+) {
+    let Some(VertexAttributeValues::Float32x3(base_positions)) =
+        base_sphere.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return;
+    };
+    let base_positions: Vec<Vec3> = base_positions.iter().map(|p| Vec3::from(*p)).collect();
+
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return;
+    };
+
+    let start = star_index * verts_per_star;
+    let Some(slice) = positions.get_mut(start..start + verts_per_star) else {
+        return;
+    };
+
+    for (vertex, base) in slice.iter_mut().zip(base_positions.iter()) {
+        let world = *base * radius + position;
+        *vertex = [world.x, world.y, world.z];
+    }
+}
+
+/// Overwrites just the vertex-color range belonging to `star_index` (`verts_per_star` vertices
+/// starting at `star_index * verts_per_star`), leaving every other star's vertices untouched.
+pub fn set_star_color(mesh: &mut Mesh, star_index: usize, verts_per_star: usize, color: Vec4) {
+    let Some(VertexAttributeValues::Float32x4(colors)) = mesh.attribute_mut(Mesh::ATTRIBUTE_COLOR)
+    else {
+        return;
+    };
+
+    let start = star_index * verts_per_star;
+    let Some(slice) = colors.get_mut(start..start + verts_per_star) else {
+        return;
+    };
+
+    for vertex_color in slice {
+        *vertex_color = [color.x, color.y, color.z, color.w];
+    }
+}
@@ -0,0 +1,143 @@
+//! Coarse 3D ambient-light grid over the playfield volume, baked once from the star field's
+//! positions/colors (see [`super::stars::StarFieldSamples`]) so on-field entities can be cheaply
+//! tinted by nearby star color/density without per-star real lights. [`StarLightGrid::sample`]
+//! trilinearly interpolates the 8 surrounding cells for a world position; `despawn.rs` is the
+//! first consumer (tinting a dying nateroid's initial death materials), but any system with a
+//! world position can call it the same way.
+
+use bevy::prelude::*;
+
+use super::config::StarConfig;
+use super::stars::StarFieldSamples;
+use crate::playfield::Boundary;
+
+pub struct StarLightGridPlugin;
+
+impl Plugin for StarLightGridPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StarLightGrid>()
+            .add_systems(Update, rebuild_star_light_grid);
+    }
+}
+
+/// Baked ambient color and dominant light direction per grid cell, covering the boundary volume.
+/// Empty (zero-sized) until the star field has spawned at least once.
+#[derive(Resource, Default)]
+pub struct StarLightGrid {
+    origin:     Vec3,
+    cell_size:  Vec3,
+    resolution: UVec3,
+    ambient:    Vec<Vec3>,
+    direction:  Vec<Vec3>,
+}
+
+impl StarLightGrid {
+    /// Trilinearly interpolates the baked ambient color and dominant light direction at `pos`.
+    /// Returns `(Vec3::ZERO, Vec3::Z)` before the grid has been baked.
+    pub fn sample(&self, pos: Vec3) -> (Vec3, Vec3) {
+        let Some(max) = self.max_index() else {
+            return (Vec3::ZERO, Vec3::Z);
+        };
+
+        let inv_cell_size = Vec3::ONE / self.cell_size;
+        // Cells store their sample at the cell center, so offset by half a cell to interpolate
+        // between centers rather than edges.
+        let v = (pos - self.origin) * inv_cell_size - Vec3::splat(0.5);
+        let base = v.floor();
+        let frac = (v - base).clamp(Vec3::ZERO, Vec3::ONE);
+
+        let clamp_axis = |value: f32, axis_max: u32| value.clamp(0.0, axis_max as f32) as u32;
+        let lo = UVec3::new(
+            clamp_axis(base.x, max.x),
+            clamp_axis(base.y, max.y),
+            clamp_axis(base.z, max.z),
+        );
+        let hi = (lo + UVec3::ONE).min(max);
+
+        (
+            self.trilinear_blend(&self.ambient, lo, hi, frac),
+            self.trilinear_blend(&self.direction, lo, hi, frac),
+        )
+    }
+
+    fn trilinear_blend(&self, samples: &[Vec3], lo: UVec3, hi: UVec3, frac: Vec3) -> Vec3 {
+        let corner = |x: u32, y: u32, z: u32| samples[self.index(x, y, z)];
+
+        let c00 = corner(lo.x, lo.y, lo.z).lerp(corner(hi.x, lo.y, lo.z), frac.x);
+        let c10 = corner(lo.x, hi.y, lo.z).lerp(corner(hi.x, hi.y, lo.z), frac.x);
+        let c01 = corner(lo.x, lo.y, hi.z).lerp(corner(hi.x, lo.y, hi.z), frac.x);
+        let c11 = corner(lo.x, hi.y, hi.z).lerp(corner(hi.x, hi.y, hi.z), frac.x);
+
+        let c0 = c00.lerp(c10, frac.y);
+        let c1 = c01.lerp(c11, frac.y);
+
+        c0.lerp(c1, frac.z)
+    }
+
+    fn index(&self, x: u32, y: u32, z: u32) -> usize {
+        (x + y * self.resolution.x + z * self.resolution.x * self.resolution.y) as usize
+    }
+
+    fn max_index(&self) -> Option<UVec3> {
+        if self.ambient.is_empty() {
+            return None;
+        }
+        Some(self.resolution - UVec3::ONE)
+    }
+}
+
+/// Rebakes [`StarLightGrid`] whenever [`StarFieldSamples`] changes (i.e. every time the star
+/// field (re)spawns) - reactive to the resource rather than explicitly ordered after
+/// `spawn_stars`, so this stays decoupled from the stars module's own system scheduling.
+fn rebuild_star_light_grid(
+    samples: Res<StarFieldSamples>,
+    boundary: Res<Boundary>,
+    config: Res<StarConfig>,
+    mut grid: ResMut<StarLightGrid>,
+) {
+    if !samples.is_changed() {
+        return;
+    }
+
+    *grid = bake_star_light_grid(&boundary, &config, &samples.0);
+}
+
+fn bake_star_light_grid(
+    boundary: &Boundary,
+    config: &StarConfig,
+    samples: &[(Vec3, Vec4)],
+) -> StarLightGrid {
+    let resolution = UVec3::splat(config.light_grid_resolution.max(2));
+    let half_size = boundary.transform.scale / 2.0;
+    let origin = boundary.transform.translation - half_size;
+    let cell_size = (half_size * 2.0) / resolution.as_vec3();
+
+    let cell_count = (resolution.x * resolution.y * resolution.z) as usize;
+    let mut ambient = vec![Vec3::ZERO; cell_count];
+    let mut direction = vec![Vec3::ZERO; cell_count];
+
+    for z in 0..resolution.z {
+        for y in 0..resolution.y {
+            for x in 0..resolution.x {
+                let index = (x + y * resolution.x + z * resolution.x * resolution.y) as usize;
+                let cell_center =
+                    origin + cell_size * (UVec3::new(x, y, z).as_vec3() + Vec3::splat(0.5));
+
+                for &(star_position, star_color) in samples {
+                    let to_star = star_position - cell_center;
+                    // Floor the falloff so distant stars still contribute a faint ambient term
+                    // rather than vanishing entirely under inverse-square falloff.
+                    let attenuation = 1.0 / to_star.length_squared().max(1.0);
+
+                    ambient[index] += star_color.truncate() * attenuation;
+                    direction[index] += to_star.normalize_or_zero() * attenuation;
+                }
+
+                ambient[index] *= config.light_grid_intensity;
+                direction[index] = direction[index].normalize_or(Vec3::Z);
+            }
+        }
+    }
+
+    StarLightGrid { origin, cell_size, resolution, ambient, direction }
+}
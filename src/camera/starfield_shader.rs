@@ -0,0 +1,112 @@
+//! Procedural starfield skybox rendered behind `stars.rs`'s merged star-field mesh: a single large
+//! inward-facing sphere on `RenderLayer::Stars`, shaded by a WGSL fragment shader
+//! (`assets/shaders/starfield.wgsl`) that hashes the view direction into a sparse, twinkling field
+//! of stars rather than any per-star entity/mesh of its own. Cells the hash doesn't light up as a
+//! star still render `CameraConfig::nebula_tint` rather than flat black, so the sphere reads as a
+//! deep-space color gradient with the bloom-capable instanced stars composited in front of it.
+use bevy::camera::visibility::RenderLayers;
+use bevy::pbr::Material;
+use bevy::pbr::MaterialPlugin;
+use bevy::prelude::*;
+use bevy::render::render_resource::AsBindGroup;
+use bevy::render::render_resource::ShaderRef;
+
+use super::RenderLayer;
+use super::config::CameraConfig;
+use super::config::StarConfig;
+
+/// World-space radius of the skybox sphere - comfortably outside the star field and boundary so
+/// nothing in front of it ever clips through.
+const STARFIELD_SPHERE_RADIUS: f32 = 5000.0;
+
+pub struct StarfieldShaderPlugin;
+
+impl Plugin for StarfieldShaderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MaterialPlugin::<StarfieldMaterial>::default())
+            .add_systems(Startup, spawn_starfield_sphere)
+            .add_systems(Update, update_starfield_material);
+    }
+}
+
+/// Drives `assets/shaders/starfield.wgsl`'s uniform: `density`/`brightness`/`twinkle_speed` are
+/// inspector-tunable via [`StarConfig`], `tint_r`/`tint_g`/`tint_b` come from
+/// [`CameraConfig::nebula_tint`], and `time` just advances every frame for the twinkle.
+#[derive(Asset, TypePath, AsBindGroup, Clone)]
+pub struct StarfieldMaterial {
+    #[uniform(0)]
+    pub density:       f32,
+    #[uniform(0)]
+    pub brightness:    f32,
+    #[uniform(0)]
+    pub twinkle_speed: f32,
+    #[uniform(0)]
+    pub time:          f32,
+    #[uniform(0)]
+    pub tint_r:        f32,
+    #[uniform(0)]
+    pub tint_g:        f32,
+    #[uniform(0)]
+    pub tint_b:        f32,
+}
+
+impl Material for StarfieldMaterial {
+    fn fragment_shader() -> ShaderRef { "shaders/starfield.wgsl".into() }
+}
+
+#[derive(Component)]
+struct StarfieldSphere;
+
+fn spawn_starfield_sphere(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StarfieldMaterial>>,
+    star_config: Res<StarConfig>,
+    camera_config: Res<CameraConfig>,
+) {
+    let tint = camera_config.nebula_tint.to_linear();
+
+    // Negating one scale axis flips triangle winding, turning the sphere's (normally culled)
+    // inward faces into the ones the default backface-culled pipeline renders - exactly what a
+    // camera sitting at the origin looking out needs.
+    commands.spawn((
+        StarfieldSphere,
+        Mesh3d(meshes.add(Sphere::new(STARFIELD_SPHERE_RADIUS).mesh().ico(6).unwrap())),
+        MeshMaterial3d(materials.add(StarfieldMaterial {
+            density:       star_config.shader_star_density,
+            brightness:    star_config.shader_star_brightness,
+            twinkle_speed: star_config.shader_twinkle_speed,
+            time:          0.0,
+            tint_r:        tint.red,
+            tint_g:        tint.green,
+            tint_b:        tint.blue,
+        })),
+        Transform::from_scale(Vec3::new(-1.0, 1.0, 1.0)),
+        RenderLayers::from_layers(RenderLayer::Stars.layers()),
+    ));
+}
+
+fn update_starfield_material(
+    time: Res<Time>,
+    star_config: Res<StarConfig>,
+    camera_config: Res<CameraConfig>,
+    starfield: Query<&MeshMaterial3d<StarfieldMaterial>, With<StarfieldSphere>>,
+    mut materials: ResMut<Assets<StarfieldMaterial>>,
+) {
+    let Ok(handle) = starfield.single() else {
+        return;
+    };
+    let Some(material) = materials.get_mut(&handle.0) else {
+        return;
+    };
+
+    let tint = camera_config.nebula_tint.to_linear();
+
+    material.density = star_config.shader_star_density;
+    material.brightness = star_config.shader_star_brightness;
+    material.twinkle_speed = star_config.shader_twinkle_speed;
+    material.time += time.delta_secs();
+    material.tint_r = tint.red;
+    material.tint_g = tint.green;
+    material.tint_b = tint.blue;
+}
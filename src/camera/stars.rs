@@ -2,21 +2,32 @@ use std::f32::consts::PI;
 
 use bevy::camera::visibility::RenderLayers;
 use bevy::prelude::*;
+use noise::NoiseFn;
+use noise::Perlin;
 use rand::Rng;
 use rand::prelude::ThreadRng;
 
 use super::RenderLayer;
 use super::config::StarConfig;
+use super::config::StarLayer;
+use super::star_instancing::StarInstance;
+use super::star_instancing::StarInstanceMaterial;
+use super::star_instancing::build_star_field_mesh;
+use super::star_instancing::set_star_color;
+use super::star_instancing::set_star_position;
 use crate::playfield::Boundary;
 use crate::schedule::InGameSet;
 use crate::state::GameState;
-use crate::traits::TransformExt;
 
 pub struct StarsPlugin;
 
 impl Plugin for StarsPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(StarRotationState { current_angle: 0.0 })
+        app.insert_resource(StarRotationState { current_angles: Vec::new() })
+            .init_resource::<StarFieldData>()
+            .init_resource::<StarFieldSamples>()
+            .init_resource::<StarRecycleFades>()
+            .init_resource::<NextRecycleIndex>()
             .add_systems(
                 OnEnter(GameState::Splash),
                 (despawn_stars, spawn_stars).chain(),
@@ -26,103 +37,290 @@ impl Plugin for StarsPlugin {
                 (despawn_stars, spawn_stars).chain(),
             )
             .add_systems(Update, rotate_stars.in_set(InGameSet::EntityUpdates))
+            .add_systems(Update, recycle_stars.in_set(InGameSet::EntityUpdates))
             .add_systems(Update, debug_stars);
     }
 }
 
 fn debug_stars(
-    stars: Query<(Entity, Option<&ViewVisibility>), With<Star>>,
+    star_field: Query<(Entity, Option<&ViewVisibility>), With<StarField>>,
     stars_camera: Query<
         (Entity, &Camera, Option<&RenderLayers>),
-        With<super::cameras::StarsCamera>,
+        With<super::cameras::StarCamera>,
     >,
 ) {
-    let count = stars.iter().count();
-    if count > 0 {
-        let visible_count = stars
-            .iter()
-            .filter(|(_, v)| v.is_some_and(|vv| vv.get()))
-            .count();
-
-        if let Ok((cam_entity, camera, render_layers)) = stars_camera.single() {
-            debug!(
-                "Stars: {count} total, {visible_count} visible | Camera {cam_entity}: active={}, layers={:?}",
-                camera.is_active, render_layers
-            );
-        } else {
-            debug!("Stars: {count} total, {visible_count} visible | NO STARS CAMERA!");
-        }
+    let count = star_field.iter().count();
+    if count == 0 {
+        return;
+    }
+    let visible_count = star_field.iter().filter(|(_, v)| v.is_some_and(|vv| vv.get())).count();
+
+    if let Ok((cam_entity, camera, render_layers)) = stars_camera.single() {
+        debug!(
+            "Star layers: {count} total, {visible_count} visible | Camera {cam_entity}: active={}, layers={:?}",
+            camera.is_active, render_layers
+        );
+    } else {
+        debug!("Star layers: {count} total, {visible_count} visible | NO STARS CAMERA!");
     }
 }
 
-#[derive(Reflect, Component, Default)]
-pub struct Star {
-    position:     Vec3,
-    radius:       f32,
-    pub emissive: Vec4,
+/// Marks one depth layer's merged-mesh entity - see [`star_instancing`] for why a layer's stars
+/// are one draw call instead of one entity/material each, and [`StarLayer`] for why there's more
+/// than one of these.
+#[derive(Component)]
+struct StarField;
+
+/// Which [`StarConfig::star_layers`] entry a [`StarField`] entity renders, so [`rotate_stars`] can
+/// look up that layer's `rotation_speed_multiplier`.
+#[derive(Component)]
+struct StarFieldLayer(usize);
+
+/// Handle and layout of the currently spawned star field, so `star_twinkling.rs` can repaint a
+/// star's vertex-color range without needing its own query. Stars are numbered globally in spawn
+/// order (layer 0 first, then layer 1, ...); `star_layer`/`star_local` map a global index
+/// back to which layer's mesh holds it and where in that mesh, `verts_per_star` locates a star's
+/// vertex slice inside its mesh once there, and `base_emissive` is each star's pristine
+/// (non-twinkling) color.
+#[derive(Resource, Default)]
+pub struct StarFieldData {
+    pub layer_meshes:   Vec<Handle<Mesh>>,
+    pub verts_per_star: usize,
+    pub base_emissive:  Vec<Vec4>,
+    pub star_layer:     Vec<usize>,
+    pub star_local:     Vec<usize>,
 }
 
+/// Every spawned star's world position and color, flattened across all [`StarConfig::star_layers`]
+/// - `star_light_grid` bakes [`super::StarLightGrid`] from this whenever it changes, i.e. every
+/// time the star field (re)spawns.
+#[derive(Resource, Default)]
+pub struct StarFieldSamples(pub Vec<(Vec3, Vec4)>);
+
 #[derive(Resource)]
 struct StarRotationState {
-    current_angle: f32,
+    current_angles: Vec<f32>,
+}
+
+/// Noise fields and per-layer shell bounds `spawn_stars` built the field from, kept around so
+/// `recycle_stars` can resample a replacement star from the same density/color-temperature fields
+/// instead of a statistically different one.
+#[derive(Resource)]
+struct StarNoiseFields {
+    cluster_noise:     Perlin,
+    temperature_noise: Perlin,
+    /// `(inner_sphere_radius, outer_sphere_radius)` per `StarConfig::star_layers` entry.
+    shell_radii:       Vec<(f32, f32)>,
 }
 
+/// `spawn_stars`' unbuilt unit sphere, kept around so `recycle_stars` can re-derive a single
+/// star's vertex positions the same way `build_star_field_mesh` does for the whole field.
+#[derive(Resource)]
+struct StarBaseSphere(Mesh);
+
+/// Ticks at `StarConfig::duration_replace_timer` - each time it fires, `recycle_stars` starts
+/// fading out the next `StarConfig::batch_size_replace` stars in `NextRecycleIndex`'s rotation.
+#[derive(Resource)]
+struct StarRecycleTimer(Timer);
+
+/// Walks every star index in a repeating ring, so `recycle_stars` eventually replaces the whole
+/// field over time instead of ever favoring the same stars.
+#[derive(Resource, Default)]
+struct NextRecycleIndex(usize);
+
+enum RecyclePhase {
+    FadingOut,
+    FadingIn { new_emissive: Vec4 },
+}
+
+/// One star mid-recycle: faded out, resampled, and faded back in - see [`recycle_stars`].
+struct RecycleFade {
+    star_index: usize,
+    phase:      RecyclePhase,
+    timer:      Timer,
+}
+
+#[derive(Resource, Default)]
+struct StarRecycleFades(Vec<RecycleFade>);
+
+/// Seconds each half (fade-out, fade-in) of a star's recycle takes.
+const RECYCLE_FADE_SECS: f32 = 0.6;
+
+/// Cosine ease-in/ease-out, `0.0..=1.0` -> `0.0..=1.0` - the "smoothed sine" brightness curve a
+/// recycled star eases along instead of popping in or out.
+fn ease_in_out(t: f32) -> f32 { 0.5 - 0.5 * (PI * t.clamp(0.0, 1.0)).cos() }
+
 fn despawn_stars(
     mut commands: Commands,
-    stars: Query<Entity, With<Star>>,
+    star_field: Query<Entity, With<StarField>>,
     mut rotation_state: ResMut<StarRotationState>,
 ) {
-    debug!("despawning stars");
-    for entity in stars.iter() {
+    debug!("despawning star field");
+    for entity in &star_field {
         commands.entity(entity).despawn();
     }
-    // Reset rotation angle so new stars start from 0 (prevents jump on reset)
+    // Reset rotation angles so new layers start from 0 (prevents jump on reset)
     // This was a nasty bug - we couldn't tell why the Splash animation would land smoothly
     // but when we manally re-invoked this, it looked like the spaceship jumped with
     // respect to the star background at the end - thinking this was a camera movement but
     // but it was actually that we needed to reset the rotation angle so we wouldn't be using the
     // previous rotation state when spawning a new set of stars. dang!
-    rotation_state.current_angle = 0.0;
+    rotation_state.current_angles.clear();
+}
+
+/// Splits `total` across `layers` by `star_fraction`, rounding each layer down and dumping the
+/// rounding remainder into the last (farthest) layer so counts always sum to exactly `total`.
+fn layer_star_counts(total: usize, layers: &[StarLayer]) -> Vec<usize> {
+    let mut counts: Vec<usize> =
+        layers.iter().map(|layer| (total as f32 * layer.star_fraction).floor() as usize).collect();
+    let assigned: usize = counts.iter().sum();
+    if let Some(last) = counts.last_mut() {
+        *last += total.saturating_sub(assigned);
+    }
+    counts
 }
 
-/// Spawn stars with all components at once to avoid archetype changes after spawn
+/// Builds one merged mesh per [`StarLayer`] - each layer gets its own spherical shell, radius/
+/// emissive scaling, and [`StarField`] entity, so [`rotate_stars`] can spin each at its own
+/// `rotation_speed_multiplier` for a parallax depth effect.
 fn spawn_stars(
     mut commands: Commands,
     config: Res<StarConfig>,
     boundary_config: Res<Boundary>,
     mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut materials: ResMut<Assets<StarInstanceMaterial>>,
 ) {
-    debug!("spawning stars");
+    debug!("spawning star field");
     let longest_diagonal = boundary_config.longest_diagonal();
-    let inner_sphere_radius = longest_diagonal + config.star_field_inner_diameter;
-    let outer_sphere_radius = inner_sphere_radius + config.star_field_outer_diameter;
-
-    let mesh = meshes.add(Sphere::new(1.));
+    let base_sphere = Sphere::new(1.).mesh().build();
+    let verts_per_star =
+        base_sphere.attribute(Mesh::ATTRIBUTE_POSITION).map(|attribute| attribute.len()).unwrap_or_default();
     let mut rng = rand::rng();
 
-    for _ in 0..config.star_count {
-        let position = get_star_position(inner_sphere_radius, outer_sphere_radius, &mut rng);
-        let radius = rng.random_range(config.star_radius_min..config.star_radius_max);
-        let emissive = get_star_color(&config, &mut rng);
+    // Two independent noise fields seeded off the same `star_cluster_seed` so a given seed
+    // reproduces both the same clustering and the same regional color bias every time.
+    let cluster_noise = Perlin::new(config.star_cluster_seed);
+    let temperature_noise = Perlin::new(config.star_cluster_seed.wrapping_add(1));
+
+    let mut layer_meshes = Vec::with_capacity(config.star_layers.len());
+    let mut base_emissive = Vec::with_capacity(config.star_count);
+    let mut star_layer = Vec::with_capacity(config.star_count);
+    let mut star_local = Vec::with_capacity(config.star_count);
+    let mut shell_radii = Vec::with_capacity(config.star_layers.len());
+    let mut samples = Vec::with_capacity(config.star_count);
+
+    for (layer_index, (layer, star_count)) in
+        config.star_layers.iter().zip(layer_star_counts(config.star_count, &config.star_layers)).enumerate()
+    {
+        let inner_sphere_radius = longest_diagonal + layer.inner_diameter;
+        let outer_sphere_radius = longest_diagonal + layer.outer_diameter;
+        shell_radii.push((inner_sphere_radius, outer_sphere_radius));
+
+        let instances: Vec<StarInstance> = (0..star_count)
+            .map(|local_index| {
+                let position = sample_star_position(
+                    inner_sphere_radius,
+                    outer_sphere_radius,
+                    &cluster_noise,
+                    &config,
+                    &mut rng,
+                );
+                let radius =
+                    rng.random_range(config.star_radius_min..config.star_radius_max) * layer.radius_scale;
+                let temperature = sample_temperature(position, &temperature_noise, &config);
+                let emissive = get_star_color(&config, &mut rng, temperature) * layer.emissive_scale;
+
+                star_layer.push(layer_index);
+                star_local.push(local_index);
+                base_emissive.push(emissive);
+                samples.push((position, emissive));
+
+                StarInstance { position, radius, emissive }
+            })
+            .collect();
 
-        let material = materials.add(StandardMaterial {
-            emissive: LinearRgba::new(emissive.x, emissive.y, emissive.z, emissive.w),
-            ..default()
-        });
+        let mesh = meshes.add(build_star_field_mesh(&base_sphere, &instances));
+        layer_meshes.push(mesh.clone());
 
         commands.spawn((
-            Star {
-                position,
-                radius,
-                emissive,
-            },
+            StarField,
+            StarFieldLayer(layer_index),
             RenderLayers::from_layers(RenderLayer::Stars.layers()),
-            Mesh3d(mesh.clone()),
-            MeshMaterial3d(material),
-            Transform::from_trs(position, Quat::IDENTITY, Vec3::splat(radius)),
+            Mesh3d(mesh),
+            MeshMaterial3d(materials.add(StarInstanceMaterial::default())),
+            Transform::IDENTITY,
         ));
     }
+
+    commands.insert_resource(StarFieldData {
+        layer_meshes,
+        verts_per_star,
+        base_emissive,
+        star_layer,
+        star_local,
+    });
+    commands.insert_resource(StarNoiseFields { cluster_noise, temperature_noise, shell_radii });
+    commands.insert_resource(StarBaseSphere(base_sphere));
+    commands.insert_resource(StarRecycleTimer(Timer::from_seconds(
+        config.duration_replace_timer,
+        TimerMode::Repeating,
+    )));
+    commands.insert_resource(NextRecycleIndex::default());
+    commands.insert_resource(StarRecycleFades::default());
+    commands.insert_resource(StarFieldSamples(samples));
+}
+
+/// Rejection-sampling attempts before giving up and accepting whatever candidate came last - keeps
+/// `sample_star_position` from looping forever in the sparsest voids of the density field.
+const MAX_CLUSTER_SAMPLE_ATTEMPTS: u32 = 64;
+
+/// Draws candidate positions from [`get_star_position`]'s uniform spherical-shell distribution
+/// until one lands in a dense-enough region of `cluster_noise` (per [`cluster_density`]),
+/// producing Milky-Way-style clusters and voids instead of a statistically flat scatter.
+fn sample_star_position(
+    inner_sphere_radius: f32,
+    outer_sphere_radius: f32,
+    cluster_noise: &Perlin,
+    config: &StarConfig,
+    rng: &mut ThreadRng,
+) -> Vec3 {
+    let mut candidate = get_star_position(inner_sphere_radius, outer_sphere_radius, rng);
+
+    for _ in 0..MAX_CLUSTER_SAMPLE_ATTEMPTS {
+        if rng.random::<f32>() < cluster_density(candidate, cluster_noise, config) {
+            return candidate;
+        }
+        candidate = get_star_position(inner_sphere_radius, outer_sphere_radius, rng);
+    }
+
+    candidate
+}
+
+/// Samples `cluster_noise` at `position` and maps it from Perlin's `[-1, 1]` output into a `[0, 1]`
+/// acceptance probability, sharpened by `star_cluster_contrast` - values above `1.0` carve clearer
+/// voids between clusters, values below `1.0` flatten back toward the old uniform scatter.
+fn cluster_density(position: Vec3, cluster_noise: &Perlin, config: &StarConfig) -> f32 {
+    let frequency = config.star_cluster_noise_frequency;
+    let sample = cluster_noise.get([
+        position.x as f64 * frequency,
+        position.y as f64 * frequency,
+        position.z as f64 * frequency,
+    ]);
+    let normalized = ((sample + 1.0) * 0.5).clamp(0.0, 1.0) as f32;
+
+    normalized.powf(config.star_cluster_contrast.max(0.01))
+}
+
+/// Samples a second, much-lower-frequency noise channel at `position` so neighboring stars share
+/// a color-temperature bias rather than each being colored fully independently. Output is roughly
+/// `[-1, 1]`: negative reads as a cooler (bluer) region, positive as warmer.
+fn sample_temperature(position: Vec3, temperature_noise: &Perlin, config: &StarConfig) -> f32 {
+    let frequency = config.star_color_temperature_noise_frequency;
+    temperature_noise.get([
+        position.x as f64 * frequency,
+        position.y as f64 * frequency,
+        position.z as f64 * frequency,
+    ]) as f32
 }
 
 fn get_star_position(
@@ -147,7 +345,21 @@ fn get_star_position(
     Vec3::new(x, y, z)
 }
 
-fn get_star_color(config: &StarConfig, rng: &mut impl Rng) -> Vec4 {
+/// Nudges `color`'s channel balance toward warm (red/green) or cool (blue) based on `temperature`
+/// (roughly `[-1, 1]`, from [`sample_temperature`]), scaled by `star_color_temperature_influence` -
+/// `0.0` influence reproduces the original fully-independent coloring.
+fn apply_color_temperature(color: Vec4, temperature: f32, influence: f32) -> Vec4 {
+    let bias = (temperature * influence).clamp(-1.0, 1.0);
+
+    Vec4::new(
+        color.x * (1.0 + bias.max(0.0)),
+        color.y * (1.0 + bias.max(0.0) * 0.5),
+        color.z * (1.0 + (-bias).max(0.0)),
+        color.w,
+    )
+}
+
+fn get_star_color(config: &StarConfig, rng: &mut impl Rng, temperature: f32) -> Vec4 {
     let end = config.star_color.end;
     let color_start = config.star_color.start;
     let white_start = end * config.star_color_white_start_ratio;
@@ -178,30 +390,139 @@ fn get_star_color(config: &StarConfig, rng: &mut impl Rng) -> Vec4 {
     // Alpha can remain as is
     let a = rng.random_range(start..end);
 
-    Vec4::new(r, g, b, a)
+    apply_color_temperature(
+        Vec4::new(r, g, b, a),
+        temperature,
+        config.star_color_temperature_influence,
+    )
 }
 
+/// Rotates each depth layer through its own [`StarField`] entity's `Transform`, at
+/// `rotation_cycle_minutes` scaled by that layer's `rotation_speed_multiplier` - near layers spin
+/// faster than far ones, the parallax depth cue the single rigidly-rotating shell used to lack.
 fn rotate_stars(
     time: Res<Time>,
     config: Res<StarConfig>,
     mut rotation_state: ResMut<StarRotationState>,
-    mut stars: Query<(&Star, &mut Transform)>,
+    mut star_fields: Query<(&StarFieldLayer, &mut Transform)>,
 ) {
     // Guard against invalid rotation cycle values (min: 1 second = 0.01667 minutes)
     if config.rotation_cycle_minutes < 0.01667 {
         return;
     }
 
-    // Calculate rotation speed (radians per second)
-    let rotation_speed = (2.0 * PI) / (config.rotation_cycle_minutes * 60.0);
+    if rotation_state.current_angles.len() != config.star_layers.len() {
+        rotation_state.current_angles = vec![0.0; config.star_layers.len()];
+    }
+
+    // Base rotation speed (radians per second); negative for clockwise rotation when viewed from
+    // above, scaled per layer below.
+    let base_rotation_speed = -(2.0 * PI) / (config.rotation_cycle_minutes * 60.0);
+
+    for (StarFieldLayer(layer_index), mut transform) in &mut star_fields {
+        let Some(layer) = config.star_layers.get(*layer_index) else {
+            continue;
+        };
+        let Some(angle) = rotation_state.current_angles.get_mut(*layer_index) else {
+            continue;
+        };
 
-    // Update current angle (negative for clockwise rotation when viewed from above)
-    rotation_state.current_angle -= rotation_speed * time.delta_secs();
+        *angle += base_rotation_speed * layer.rotation_speed_multiplier * time.delta_secs();
+        transform.rotation = Quat::from_axis_angle(config.rotation_axis, *angle);
+    }
+}
 
-    // Apply rotation to each star around the configured axis
-    let rotation = Quat::from_axis_angle(config.rotation_axis, rotation_state.current_angle);
+/// Continuously replaces the star field in place: every `duration_replace_timer` seconds, starts
+/// fading out the next `batch_size_replace` stars in `NextRecycleIndex`'s rotation; once a star's
+/// fade-out finishes it's resampled from the same noise fields `spawn_stars` used and faded back
+/// in. Gives a continuously shifting field (per the old "despawn the oldest" TODO this replaces)
+/// without ever fully respawning it, now that stars are baked mesh vertices rather than entities.
+fn recycle_stars(
+    time: Res<Time>,
+    config: Res<StarConfig>,
+    mut recycle_timer: ResMut<StarRecycleTimer>,
+    mut next_index: ResMut<NextRecycleIndex>,
+    mut fades: ResMut<StarRecycleFades>,
+    mut star_field: ResMut<StarFieldData>,
+    noise: Res<StarNoiseFields>,
+    base_sphere: Res<StarBaseSphere>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    if config.star_count == 0 || star_field.layer_meshes.is_empty() {
+        return;
+    }
+
+    recycle_timer.0.tick(time.delta());
+    if recycle_timer.0.just_finished() {
+        for _ in 0..config.batch_size_replace.min(config.star_count) {
+            let star_index = next_index.0 % config.star_count;
+            next_index.0 = (next_index.0 + 1) % config.star_count;
+
+            if fades.0.iter().any(|fade| fade.star_index == star_index) {
+                continue;
+            }
+
+            fades.0.push(RecycleFade {
+                star_index,
+                phase: RecyclePhase::FadingOut,
+                timer: Timer::from_seconds(RECYCLE_FADE_SECS, TimerMode::Once),
+            });
+        }
+    }
+
+    let mut rng = rand::rng();
+    let mut finished = Vec::new();
+
+    for (fade_index, fade) in fades.0.iter_mut().enumerate() {
+        fade.timer.tick(time.delta());
+        let progress = fade.timer.elapsed_secs() / fade.timer.duration().as_secs_f32();
+
+        let layer_index = star_field.star_layer[fade.star_index];
+        let local_index = star_field.star_local[fade.star_index];
+        let Some(mesh) =
+            star_field.layer_meshes.get(layer_index).and_then(|handle| meshes.get_mut(handle))
+        else {
+            continue;
+        };
+
+        match fade.phase {
+            RecyclePhase::FadingOut => {
+                let base = star_field.base_emissive[fade.star_index];
+                set_star_color(mesh, local_index, star_field.verts_per_star, base * ease_in_out(1.0 - progress));
+
+                if fade.timer.finished() {
+                    let Some(&(inner, outer)) = noise.shell_radii.get(layer_index) else {
+                        continue;
+                    };
+                    let Some(layer) = config.star_layers.get(layer_index) else {
+                        continue;
+                    };
+
+                    let position = sample_star_position(inner, outer, &noise.cluster_noise, &config, &mut rng);
+                    let radius =
+                        rng.random_range(config.star_radius_min..config.star_radius_max) * layer.radius_scale;
+                    let temperature = sample_temperature(position, &noise.temperature_noise, &config);
+                    let emissive = get_star_color(&config, &mut rng, temperature) * layer.emissive_scale;
+
+                    set_star_position(mesh, &base_sphere.0, local_index, star_field.verts_per_star, position, radius);
+                    set_star_color(mesh, local_index, star_field.verts_per_star, Vec4::ZERO);
+
+                    fade.phase = RecyclePhase::FadingIn { new_emissive: emissive };
+                    fade.timer = Timer::from_seconds(RECYCLE_FADE_SECS, TimerMode::Once);
+                }
+            },
+            RecyclePhase::FadingIn { new_emissive } => {
+                set_star_color(mesh, local_index, star_field.verts_per_star, new_emissive * ease_in_out(progress));
+
+                if fade.timer.finished() {
+                    star_field.base_emissive[fade.star_index] = new_emissive;
+                    finished.push(fade_index);
+                }
+            },
+        }
+    }
 
-    for (star, mut transform) in &mut stars {
-        transform.translation = rotation * star.position;
+    for &index in finished.iter().rev() {
+        fades.0.remove(index);
     }
 }
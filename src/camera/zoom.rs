@@ -1,9 +1,27 @@
+use std::collections::VecDeque;
+
+use avian3d::prelude::SpatialQuery;
+use avian3d::prelude::SpatialQueryFilter;
+use bevy::math::curve::easing::EaseFunction;
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
 use bevy_panorbit_camera::PanOrbitCamera;
 
+use super::constants::CURSOR_TILT_SENSITIVITY;
+use super::constants::CURSOR_ZOOM_SHIFT_MULTIPLIER;
+use super::constants::FOCUS_TO_POINT_CONVERGED_DISTANCE;
+use super::constants::FRUSTUM_MIN_NEAR_Z;
+use super::constants::FRUSTUM_Z_MARGIN;
+use super::constants::ANCHORED_ZOOM_STEP_FACTOR;
+use super::constants::INSTANT_ZOOM_MOVE_DURATION_MS;
+use super::constants::ZOOM_PRESET_LEVELS;
+use super::constants::ZOOM_PRESET_MOVE_DURATION_MS;
+use super::move_queue::CameraMove;
+use super::move_queue::CameraMoveList;
+use crate::actor::Aabb;
 use crate::camera::CameraConfig;
-use crate::camera::ScreenSpaceBoundary;
 use crate::camera::ZoomConfig;
+use crate::camera::ZoomFitMode;
 use crate::game_input::GameAction;
 use crate::game_input::just_pressed;
 use crate::playfield::Boundary;
@@ -17,14 +35,66 @@ impl Plugin for ZoomPlugin {
             Update,
             start_zoom_to_fit.run_if(just_pressed(GameAction::ZoomToFit)),
         )
+        .add_systems(
+            Update,
+            start_zoom_to_selection.run_if(just_pressed(GameAction::ZoomToSelection)),
+        )
+        .add_systems(
+            Update,
+            start_focus_to_point.run_if(just_pressed(GameAction::FocusToPoint)),
+        )
+        .add_systems(
+            Update,
+            zoom_in_to_preset.run_if(just_pressed(GameAction::ZoomIn)),
+        )
+        .add_systems(
+            Update,
+            zoom_out_to_preset.run_if(just_pressed(GameAction::ZoomOut)),
+        )
+        .add_systems(
+            Update,
+            start_zoom_toward_anchor.run_if(just_pressed(GameAction::ZoomTowardAnchor)),
+        )
         .add_systems(Update, update_zoom_to_fit)
-        .add_observer(on_remove_zoom_to_fit);
+        .add_systems(Update, update_focus_to_point)
+        .init_resource::<CursorZoomState>()
+        .init_resource::<ZoomPresetState>()
+        .add_systems(Update, apply_cursor_zoom)
+        .add_observer(on_remove_zoom_to_fit)
+        .add_observer(on_remove_focus_to_point);
     }
 }
 
+/// Marker for entities included in a fit-to-selection zoom (`GameAction::ZoomToSelection`)
+/// rather than the whole playfield `Boundary`.
+#[derive(Component)]
+pub struct FitTarget;
+
 #[derive(Component)]
 struct ZoomToFitActive {
+    /// Frames spent easing toward the analytic target - purely a safety bail-out against a
+    /// degenerate target that never settles, not the convergence mechanism itself; see
+    /// [`update_zoom_to_fit`].
     iteration_count: usize,
+    /// World-space corners of whatever's being fitted - the playfield boundary for a normal
+    /// zoom-to-fit, or a selection's unioned bounding box for fit-to-selection. Captured once
+    /// at start rather than re-read from `Res<Boundary>` each frame, so the same analytic solve
+    /// works for either source.
+    corners:         [Vec3; 8],
+    /// Critically-damped spring velocity for `target_focus`.
+    focus_velocity:  Vec3,
+    /// Critically-damped spring velocity for whichever scalar is being fitted this frame
+    /// (radius, orthographic scale, or FOV, depending on projection/fit mode).
+    value_velocity:  f32,
+}
+
+/// Short-lived click-to-focus state: eases `target_focus` toward a raycast hit point (or
+/// ground-plane fallback) with the same critically-damped spring zoom-to-fit uses, without
+/// touching radius or projection.
+#[derive(Component)]
+struct FocusToPointActive {
+    target:         Vec3,
+    focus_velocity: Vec3,
 }
 
 /// Observer that runs whenever `ZoomToFitActive` is removed from an entity.
@@ -47,223 +117,833 @@ fn on_remove_zoom_to_fit(
     );
 }
 
+/// Observer that runs whenever `FocusToPointActive` is removed from an entity.
+/// Restores camera smoothness values from config.
+fn on_remove_focus_to_point(
+    remove: On<Remove, FocusToPointActive>,
+    camera_config: Res<CameraConfig>,
+    mut camera: Query<&mut PanOrbitCamera>,
+) {
+    let Ok(mut pan_orbit) = camera.get_mut(remove.entity) else {
+        return;
+    };
+
+    pan_orbit.zoom_smoothness = camera_config.zoom_smoothness;
+    pan_orbit.pan_smoothness = camera_config.pan_smoothness;
+
+    println!(
+        "FocusToPointActive removed: restored smoothness (zoom={:.2}, pan={:.2})",
+        camera_config.zoom_smoothness, camera_config.pan_smoothness
+    );
+}
+
 // Start the zoom-to-fit animation
 fn start_zoom_to_fit(
     mut commands: Commands,
+    boundary: Res<Boundary>,
     mut camera_query: Query<
         (Entity, &mut PanOrbitCamera, Option<&ZoomToFitActive>),
         With<PanOrbitCamera>,
     >,
 ) {
-    if let Ok((camera_entity, mut pan_orbit, existing_zoom)) = camera_query.single_mut() {
-        // Allow restart if already running
-        if existing_zoom.is_some() {
-            println!("Zoom-to-fit already active, restarting");
+    if let Ok((camera_entity, pan_orbit, existing_zoom)) = camera_query.single_mut() {
+        begin_zoom_to_fit(
+            &mut commands,
+            camera_entity,
+            pan_orbit,
+            existing_zoom,
+            boundary.corners(),
+        );
+        println!("Starting zoom-to-fit animation");
+    }
+}
+
+/// Fits the camera to an arbitrary set of entities marked with [`FitTarget`] instead of the
+/// whole playfield, by unioning each entity's world-space `Aabb` into one bounding box and
+/// feeding its corners into the same convergence loop `update_zoom_to_fit` already runs.
+fn start_zoom_to_selection(
+    mut commands: Commands,
+    targets: Query<(&GlobalTransform, &Aabb), With<FitTarget>>,
+    mut camera_query: Query<
+        (Entity, &mut PanOrbitCamera, Option<&ZoomToFitActive>),
+        With<PanOrbitCamera>,
+    >,
+) {
+    let Ok((camera_entity, pan_orbit, existing_zoom)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let mut min = Vec3::splat(f32::INFINITY);
+    let mut max = Vec3::splat(f32::NEG_INFINITY);
+    for (transform, aabb) in &targets {
+        for local_corner in [
+            Vec3::new(aabb.min.x, aabb.min.y, aabb.min.z),
+            Vec3::new(aabb.max.x, aabb.min.y, aabb.min.z),
+            Vec3::new(aabb.min.x, aabb.max.y, aabb.min.z),
+            Vec3::new(aabb.max.x, aabb.max.y, aabb.min.z),
+            Vec3::new(aabb.min.x, aabb.min.y, aabb.max.z),
+            Vec3::new(aabb.max.x, aabb.min.y, aabb.max.z),
+            Vec3::new(aabb.min.x, aabb.max.y, aabb.max.z),
+            Vec3::new(aabb.max.x, aabb.max.y, aabb.max.z),
+        ] {
+            let world_corner = transform.transform_point(local_corner);
+            min = min.min(world_corner);
+            max = max.max(world_corner);
         }
+    }
+
+    if !min.is_finite() || !max.is_finite() {
+        println!("ZoomToSelection: no FitTarget entities, ignoring");
+        return;
+    }
 
-        // Disable smoothing so targets apply immediately
-        pan_orbit.zoom_smoothness = 0.0;
-        pan_orbit.pan_smoothness = 0.0;
+    let corners = [
+        Vec3::new(min.x, min.y, min.z),
+        Vec3::new(max.x, min.y, min.z),
+        Vec3::new(min.x, max.y, min.z),
+        Vec3::new(max.x, max.y, min.z),
+        Vec3::new(min.x, min.y, max.z),
+        Vec3::new(max.x, min.y, max.z),
+        Vec3::new(min.x, max.y, max.z),
+        Vec3::new(max.x, max.y, max.z),
+    ];
+
+    begin_zoom_to_fit(&mut commands, camera_entity, pan_orbit, existing_zoom, corners);
+    println!("Starting zoom-to-selection animation");
+}
 
-        commands
-            .entity(camera_entity)
-            .insert(ZoomToFitActive { iteration_count: 0 });
-        println!("Starting zoom-to-fit animation");
+fn begin_zoom_to_fit(
+    commands: &mut Commands,
+    camera_entity: Entity,
+    mut pan_orbit: Mut<PanOrbitCamera>,
+    existing_zoom: Option<&ZoomToFitActive>,
+    corners: [Vec3; 8],
+) {
+    // Allow restart if already running
+    if existing_zoom.is_some() {
+        println!("Zoom-to-fit already active, restarting");
     }
+
+    // Disable smoothing so targets apply immediately
+    pan_orbit.zoom_smoothness = 0.0;
+    pan_orbit.pan_smoothness = 0.0;
+
+    commands.entity(camera_entity).insert(ZoomToFitActive {
+        iteration_count: 0,
+        corners,
+        focus_velocity: Vec3::ZERO,
+        value_velocity: 0.0,
+    });
 }
 
-/// Calculates the target focus point using a two-phase approach.
-///
-/// **Phase 1** (far from boundary): When focus is more than half the camera radius away from
-/// the boundary center, move directly toward `Vec3::ZERO`.
-///
-/// **Phase 2** (close to boundary): Use screen-space centering to fine-tune the focus position
-/// by converting screen-space offsets to world-space corrections.
-fn calculate_target_focus(
-    current_focus: Vec3,
-    current_radius: f32,
-    margins: &ScreenSpaceBoundary,
-    cam_global: &GlobalTransform,
-) -> Vec3 {
-    let focus_to_boundary_distance = current_focus.length();
-    let far_from_boundary_threshold = current_radius * 0.5;
-
-    if focus_to_boundary_distance > far_from_boundary_threshold {
-        // Phase 1: Move toward boundary center
-        Vec3::ZERO
-    } else {
-        // Phase 2: Fine-tune using screen-space centering
-        let (center_x, center_y) = margins.center();
-        let cam_rot = cam_global.rotation();
-        let cam_right = cam_rot * Vec3::X;
-        let cam_up = cam_rot * Vec3::Y;
-
-        // Convert screen-space offset to world-space adjustment
-        let world_offset_x = center_x * margins.avg_depth;
-        let world_offset_y = center_y * margins.avg_depth;
-        let focus_correction = cam_right * world_offset_x + cam_up * world_offset_y;
-
-        current_focus + focus_correction
+/// Cross-frame state for the discrete zoom-preset stepper (`GameAction::ZoomIn`/`ZoomOut`):
+/// tracks which entry of [`ZOOM_PRESET_LEVELS`] the camera is currently parked at.
+#[derive(Resource, Default)]
+struct ZoomPresetState {
+    index: usize,
+}
+
+impl ZoomPresetState {
+    /// Steps to the next (smaller, closer-in) preset level. Returns whether the index actually
+    /// changed, so callers can skip re-animating when already at the tightest level.
+    fn zoom_in(&mut self) -> bool {
+        if self.index + 1 >= ZOOM_PRESET_LEVELS.len() {
+            return false;
+        }
+        self.index += 1;
+        true
+    }
+
+    /// Steps to the previous (larger, further-out) preset level. Returns whether the index
+    /// actually changed, so callers can skip re-animating when already at the widest level.
+    fn zoom_out(&mut self) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+        self.index -= 1;
+        true
     }
+
+    fn level(&self) -> f32 { ZOOM_PRESET_LEVELS[self.index] }
 }
 
-/// Convergence algorithm for zoom-to-fit animation using iterative adjustments.
-///
-/// **Convergence Rate**: Applies `convergence_rate` to both focus and radius adjustments each
-/// frame, moving the camera gradually toward the target configuration.
-///
-/// **Convergence Detection**: Stops when both `is_fitted(margin_tolerance)` and
-/// `is_balanced(margin_tolerance)` are true.
-fn update_zoom_to_fit(
-    mut commands: Commands,
+fn zoom_in_to_preset(
+    commands: Commands,
+    preset_state: ResMut<ZoomPresetState>,
     boundary: Res<Boundary>,
     zoom_config: Res<ZoomConfig>,
-    mut camera_query: Query<(
-        Entity,
-        &GlobalTransform,
-        &mut PanOrbitCamera,
-        &Projection,
-        &Camera,
-        &mut ZoomToFitActive,
-    )>,
+    camera_query: Query<(Entity, &PanOrbitCamera, &Projection, &Camera), With<PanOrbitCamera>>,
 ) {
-    let Ok((entity, cam_global, mut pan_orbit, projection, camera, mut zoom_state)) =
-        camera_query.single_mut()
-    else {
-        return;
-    };
+    step_zoom_preset(
+        commands,
+        preset_state,
+        &boundary,
+        &zoom_config,
+        camera_query,
+        ZoomPresetState::zoom_in,
+    );
+}
 
-    let Projection::Perspective(perspective) = projection else {
+fn zoom_out_to_preset(
+    commands: Commands,
+    preset_state: ResMut<ZoomPresetState>,
+    boundary: Res<Boundary>,
+    zoom_config: Res<ZoomConfig>,
+    camera_query: Query<(Entity, &PanOrbitCamera, &Projection, &Camera), With<PanOrbitCamera>>,
+) {
+    step_zoom_preset(
+        commands,
+        preset_state,
+        &boundary,
+        &zoom_config,
+        camera_query,
+        ZoomPresetState::zoom_out,
+    );
+}
+
+/// Shared driver for [`zoom_in_to_preset`]/[`zoom_out_to_preset`]: steps `ZoomPresetState` by
+/// `step`, and if the index actually moved, spawns a `CameraMoveList` animating the camera to
+/// `base_fit_radius * level` along its *current* orbit direction (yaw/pitch are preserved, only
+/// the radius changes), so stepping presets reads as a zoom, not a re-orientation.
+fn step_zoom_preset(
+    mut commands: Commands,
+    mut preset_state: ResMut<ZoomPresetState>,
+    boundary: &Boundary,
+    zoom_config: &ZoomConfig,
+    camera_query: Query<(Entity, &PanOrbitCamera, &Projection, &Camera), With<PanOrbitCamera>>,
+    step: fn(&mut ZoomPresetState) -> bool,
+) {
+    let Ok((camera_entity, pan_orbit, projection, camera)) = camera_query.single() else {
         return;
     };
 
-    // Get actual viewport aspect ratio
-    let aspect_ratio = if let Some(viewport_size) = camera.logical_viewport_size() {
-        viewport_size.x / viewport_size.y
-    } else {
-        perspective.aspect_ratio
-    };
+    if !step(&mut preset_state) {
+        return;
+    }
 
-    // Calculate screen-space bounds and margins
-    let Some(margins) = ScreenSpaceBoundary::from_camera_view(
-        &boundary,
-        cam_global,
-        perspective,
-        aspect_ratio,
+    let Some(base_fit_radius) = calculate_base_fit_radius(
+        boundary.scale(),
         zoom_config.zoom_margin_multiplier(),
+        projection,
+        camera,
     ) else {
-        // Boundary behind camera, move camera back
-        println!(
-            "Iteration {}: Boundary behind camera, moving back",
-            zoom_state.iteration_count
-        );
-        let boundary_corners = boundary.corners();
-        let boundary_center =
-            boundary_corners.iter().sum::<Vec3>() / boundary_corners.len().to_f32();
-        pan_orbit.target_focus = boundary_center;
-        pan_orbit.target_radius *= 1.5;
-        pan_orbit.force_update = true;
-        zoom_state.iteration_count += 1;
         return;
     };
 
-    // Use FOV tangent values from margins (already calculated in from_camera_view)
-    let half_tan_vfov = margins.half_tan_vfov;
-    let half_tan_hfov = margins.half_tan_hfov;
+    let target_radius = base_fit_radius * preset_state.level();
+    let target_focus = pan_orbit.target_focus;
+    let direction = Vec3::new(
+        pan_orbit.target_pitch.cos() * pan_orbit.target_yaw.sin(),
+        -pan_orbit.target_pitch.sin(),
+        pan_orbit.target_pitch.cos() * pan_orbit.target_yaw.cos(),
+    );
 
-    // Calculate center and span for debug printing
-    let (center_x, center_y) = margins.center();
-    let (span_x, span_y) = margins.span();
+    let moves = VecDeque::from([CameraMove::ToTarget {
+        target_translation: target_focus + direction * target_radius,
+        target_focus,
+        duration_ms: ZOOM_PRESET_MOVE_DURATION_MS,
+        easing: EaseFunction::QuadraticOut,
+    }]);
+    commands.entity(camera_entity).insert(CameraMoveList::new(moves));
 
     println!(
-        "Iteration {}: center=({:.3},{:.3}), span=({:.3},{:.3})",
-        zoom_state.iteration_count, center_x, center_y, span_x, span_y
+        "Zoom preset: index={} level={:.3} radius={:.1}",
+        preset_state.index,
+        preset_state.level(),
+        target_radius
     );
+}
 
-    let h_min = margins.left_margin.min(margins.right_margin);
-    let v_min = margins.top_margin.min(margins.bottom_margin);
-    let (constraining_dim, current_margin, target_margin) = if h_min < v_min {
-        ("H", h_min, margins.target_margin_x)
-    } else {
-        ("V", v_min, margins.target_margin_y)
+/// Aspect-ratio-aware fit radius for the zoom-preset stepper. Differs from
+/// [`super::cameras::calculate_home_radius`]'s horizontal-FOV derivation by instead scaling the
+/// target footprint directly by `viewport_size / max(viewport_size.x, viewport_size.y)`, so a
+/// very wide or very tall window doesn't stretch framing on the short axis before the fit is
+/// computed.
+fn calculate_base_fit_radius(
+    grid_size: Vec3,
+    margin: f32,
+    projection: &Projection,
+    camera: &Camera,
+) -> Option<f32> {
+    let Projection::Perspective(perspective) = projection else {
+        return None;
     };
 
-    println!(
-        "  Margins: L={:.3} R={:.3} T={:.3} B={:.3}, target=({:.3},{:.3})",
-        margins.left_margin,
-        margins.right_margin,
-        margins.top_margin,
-        margins.bottom_margin,
-        margins.target_margin_x,
-        margins.target_margin_y
+    let viewport_size = camera.logical_viewport_size()?;
+    let max_dimension = viewport_size.x.max(viewport_size.y);
+    let aspect_scale = viewport_size / max_dimension;
+
+    let scaled_x = grid_size.x * aspect_scale.x;
+    let scaled_y = grid_size.y * aspect_scale.y;
+
+    let half_fov = perspective.fov * 0.5;
+    let xy_distance = scaled_x.max(scaled_y) * 0.5 / half_fov.tan();
+    let z_half_depth = grid_size.z * 0.5;
+
+    Some((xy_distance + z_half_depth) * margin)
+}
+
+/// Resolves the world-space point an anchored zoom should hold fixed on screen: the centroid of
+/// any [`FitTarget`]-marked entities if a selection exists, otherwise whatever's under the
+/// cursor, falling back to the ground plane exactly like `start_focus_to_point`.
+fn resolve_zoom_anchor(
+    spatial_query: &SpatialQuery,
+    boundary: &Boundary,
+    windows: &Query<&Window, With<PrimaryWindow>>,
+    cam_global: &GlobalTransform,
+    camera: &Camera,
+    targets: &Query<&GlobalTransform, With<FitTarget>>,
+) -> Option<Vec3> {
+    if targets.iter().len() > 0 {
+        let centroid =
+            targets.iter().map(GlobalTransform::translation).sum::<Vec3>() / targets.iter().len().to_f32();
+        return Some(centroid);
+    }
+
+    let window = windows.single().ok()?;
+    let cursor_pos = window.cursor_position()?;
+    let ray = camera.viewport_to_world(cam_global, cursor_pos).ok()?;
+
+    let hit_point = spatial_query
+        .cast_ray(
+            ray.origin,
+            ray.direction,
+            f32::MAX,
+            true,
+            &SpatialQueryFilter::default(),
+        )
+        .map(|hit| ray.get_point(hit.distance));
+
+    let ground_plane_origin = Vec3::new(0.0, boundary.transform.translation.y, 0.0);
+    hit_point.or_else(|| {
+        ray.intersect_plane(ground_plane_origin, InfinitePlane3d::new(Vec3::Y))
+            .map(|distance| ray.get_point(distance))
+    })
+}
+
+/// Starts an anchored zoom step (`GameAction::ZoomTowardAnchor`): resolves the anchor via
+/// [`resolve_zoom_anchor`], then spawns a short `CameraMoveList` (reusing the same
+/// interpolation/`reset_camera_after_moves` cleanup as the zoom-preset stepper) that dollies
+/// `target_radius` in by `ANCHORED_ZOOM_STEP_FACTOR` while sliding `target_focus` so the anchor
+/// stays fixed on screen: `new_focus = anchor - (anchor - old_focus) * (new_radius /
+/// old_radius)`. This is the "scaleBy with anchor" behavior familiar from map renderers.
+fn start_zoom_toward_anchor(
+    mut commands: Commands,
+    spatial_query: SpatialQuery,
+    boundary: Res<Boundary>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    targets: Query<&GlobalTransform, With<FitTarget>>,
+    camera_query: Query<(Entity, &PanOrbitCamera, &GlobalTransform, &Camera), With<PanOrbitCamera>>,
+) {
+    let Ok((camera_entity, pan_orbit, cam_global, camera)) = camera_query.single() else {
+        return;
+    };
+
+    let Some(anchor) =
+        resolve_zoom_anchor(&spatial_query, &boundary, &windows, cam_global, camera, &targets)
+    else {
+        println!("ZoomTowardAnchor: no anchor resolved, ignoring");
+        return;
+    };
+
+    let old_radius = pan_orbit.target_radius;
+    let old_focus = pan_orbit.target_focus;
+    let new_radius = old_radius * ANCHORED_ZOOM_STEP_FACTOR;
+    let new_focus = anchor - (anchor - old_focus) * (new_radius / old_radius);
+
+    let direction = Vec3::new(
+        pan_orbit.target_pitch.cos() * pan_orbit.target_yaw.sin(),
+        -pan_orbit.target_pitch.sin(),
+        pan_orbit.target_pitch.cos() * pan_orbit.target_yaw.cos(),
     );
+
+    let moves = VecDeque::from([CameraMove::ToTarget {
+        target_translation: new_focus + direction * new_radius,
+        target_focus: new_focus,
+        duration_ms: ZOOM_PRESET_MOVE_DURATION_MS,
+        easing: EaseFunction::QuadraticOut,
+    }]);
+    commands.entity(camera_entity).insert(CameraMoveList::new(moves));
+
     println!(
-        "  Constraining: {}, margin={:.3}/{:.3} (ratio={:.2})",
-        constraining_dim,
-        current_margin,
-        target_margin,
-        current_margin / target_margin
+        "ZoomTowardAnchor: anchor=({:.1},{:.1},{:.1}) radius {:.1}->{:.1}",
+        anchor.x, anchor.y, anchor.z, old_radius, new_radius
     );
+}
 
-    // Use target_radius instead of actual radius to avoid one-frame delay
-    // Since we set smoothness to 0, target should equal actual, but Transform updates next frame
-    let current_radius = pan_orbit.target_radius;
+/// Raycasts from the cursor into the scene and starts easing the pan-orbit camera's
+/// `target_focus` toward the hit point, so the user can recenter the orbit pivot on whatever
+/// they're looking at without a full zoom-to-fit. Falls back to where the cursor ray meets the
+/// playfield's ground plane (`y = boundary.transform.translation.y`) when the ray doesn't hit
+/// anything, mirroring how `rmf_site` derives its `orbit_center` from a cursor selection.
+fn start_focus_to_point(
+    mut commands: Commands,
+    spatial_query: SpatialQuery,
+    boundary: Res<Boundary>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut camera_query: Query<
+        (
+            Entity,
+            &mut PanOrbitCamera,
+            &GlobalTransform,
+            &Camera,
+            Option<&FocusToPointActive>,
+        ),
+        With<PanOrbitCamera>,
+    >,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Some(cursor_pos) = window.cursor_position() else {
+        return;
+    };
+    let Ok((camera_entity, mut pan_orbit, cam_global, camera, existing_focus)) =
+        camera_query.single_mut()
+    else {
+        return;
+    };
+    let Ok(ray) = camera.viewport_to_world(cam_global, cursor_pos) else {
+        return;
+    };
 
-    let target_focus =
-        calculate_target_focus(pan_orbit.target_focus, current_radius, &margins, cam_global);
+    let hit_point = spatial_query
+        .cast_ray(
+            ray.origin,
+            ray.direction,
+            f32::MAX,
+            true,
+            &SpatialQueryFilter::default(),
+        )
+        .map(|hit| ray.get_point(hit.distance));
 
-    // Calculate target radius using span ratios
-    // Physics: At distance R, object has span S. Closer = larger span.
-    // Relationship: S * R = constant, so target_R = current_R * (current_S / target_S)
+    let ground_plane_origin = Vec3::new(0.0, boundary.transform.translation.y, 0.0);
+    let target = hit_point.or_else(|| {
+        ray.intersect_plane(ground_plane_origin, InfinitePlane3d::new(Vec3::Y))
+            .map(|distance| ray.get_point(distance))
+    });
 
-    // Target spans with proper margins
-    let target_span_x = 2.0 * half_tan_hfov / zoom_config.zoom_margin_multiplier();
-    let target_span_y = 2.0 * half_tan_vfov / zoom_config.zoom_margin_multiplier();
+    let Some(target) = target else {
+        println!("FocusToPoint: ray parallel to ground plane, ignoring");
+        return;
+    };
 
-    // Calculate ratios for each dimension
-    let ratio_x = span_x / target_span_x;
-    let ratio_y = span_y / target_span_y;
+    if existing_focus.is_some() {
+        println!("Focus-to-point already active, restarting");
+    }
 
-    // Use the larger ratio (constraining dimension) to ensure both fit
-    let ratio = ratio_x.max(ratio_y);
+    pan_orbit.zoom_smoothness = 0.0;
+    pan_orbit.pan_smoothness = 0.0;
 
-    // Calculate target radius from current radius and span ratio
-    let target_radius = current_radius * ratio;
+    commands.entity(camera_entity).insert(FocusToPointActive {
+        target,
+        focus_velocity: Vec3::ZERO,
+    });
+    println!("Starting focus-to-point animation");
+}
 
-    // Calculate error magnitudes
-    let focus_delta = target_focus - pan_orbit.target_focus;
-    let radius_delta = target_radius - current_radius;
+/// Eases `target_focus` toward `FocusToPointActive::target` using the same critically-damped
+/// spring as zoom-to-fit's focus convergence, removing the component once within
+/// [`FOCUS_TO_POINT_CONVERGED_DISTANCE`].
+fn update_focus_to_point(
+    mut commands: Commands,
+    zoom_config: Res<ZoomConfig>,
+    time: Res<Time>,
+    mut camera_query: Query<(Entity, &mut PanOrbitCamera, &mut FocusToPointActive)>,
+) {
+    let Ok((entity, mut pan_orbit, mut focus_state)) = camera_query.single_mut() else {
+        return;
+    };
 
-    println!(
-        "  Focus: adj=({:.3},{:.3},{:.3})",
-        focus_delta.x, focus_delta.y, focus_delta.z
+    let dt = time.delta_secs();
+    let omega = 2.0 / zoom_config.settle_time_secs;
+    let target = focus_state.target;
+
+    let new_focus = Vec3::new(
+        spring_step(
+            pan_orbit.target_focus.x,
+            target.x,
+            &mut focus_state.focus_velocity.x,
+            omega,
+            dt,
+        ),
+        spring_step(
+            pan_orbit.target_focus.y,
+            target.y,
+            &mut focus_state.focus_velocity.y,
+            omega,
+            dt,
+        ),
+        spring_step(
+            pan_orbit.target_focus.z,
+            target.z,
+            &mut focus_state.focus_velocity.z,
+            omega,
+            dt,
+        ),
     );
 
-    // Apply convergence rate to both focus and radius
-    let rate = zoom_config.convergence_rate;
-    let focus_adjustment = focus_delta * rate;
-    let radius_adjustment = radius_delta * rate;
+    pan_orbit.target_focus = new_focus;
+    pan_orbit.force_update = true;
+
+    if new_focus.distance(target) < FOCUS_TO_POINT_CONVERGED_DISTANCE {
+        commands.entity(entity).remove::<FocusToPointActive>();
+    }
+}
+
+/// Cross-frame state for [`apply_cursor_zoom`]: `target_radius` as of the end of the previous
+/// frame, so this frame can recover how much `bevy_panorbit_camera`'s own scroll-wheel handling
+/// just changed it by, without re-reading scroll events itself.
+#[derive(Resource, Default)]
+struct CursorZoomState {
+    last_radius: f32,
+}
+
+/// Makes mouse-wheel zoom converge toward whatever's under the cursor instead of the orbit focus,
+/// layered on top of `bevy_panorbit_camera`'s own scroll handling rather than replacing it:
+/// `CamerasPlugin` registers `PanOrbitCameraPlugin` before `ZoomPlugin` is added, so in practice
+/// this runs after the crate's own zoom system has already applied the frame's scroll delta to
+/// `target_radius` (Bevy doesn't guarantee that ordering without an explicit `.after`, so a
+/// one-frame lag is possible, not a correctness issue). Comparing the new radius against
+/// [`CursorZoomState::last_radius`] recovers the fraction the built-in zoom just applied; this
+/// system then slides `target_focus` toward the cursor's world hit point by that same fraction,
+/// so the point under the cursor stays roughly fixed on screen as the radius changes. Disabled by
+/// `ZoomConfig::zoom_to_cursor`, which leaves the stock zoom-to-focus behavior untouched.
+#[allow(clippy::too_many_lines)]
+fn apply_cursor_zoom(
+    mut commands: Commands,
+    zoom_config: Res<ZoomConfig>,
+    boundary: Res<Boundary>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut state: ResMut<CursorZoomState>,
+    mut camera_query: Query<
+        (Entity, &GlobalTransform, &Camera, &mut PanOrbitCamera),
+        (Without<ZoomToFitActive>, Without<FocusToPointActive>),
+    >,
+) {
+    let Ok((camera_entity, cam_global, camera, mut pan_orbit)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let previous_radius = state.last_radius;
+    state.last_radius = pan_orbit.target_radius;
+
+    if !zoom_config.zoom_to_cursor || previous_radius <= 0.0 {
+        return;
+    }
+
+    let zoom_factor = pan_orbit.target_radius / previous_radius;
+    if (zoom_factor - 1.0).abs() < f32::EPSILON {
+        return;
+    }
+
+    // Ctrl and Alt both steal the scroll gesture for something other than a plain zoom, so
+    // whatever `bevy_panorbit_camera` already did to `target_radius` this frame is undone first.
+    if keyboard.pressed(KeyCode::ControlLeft) {
+        pan_orbit.target_radius = previous_radius;
+        pan_orbit.target_pitch = (pan_orbit.target_pitch + (1.0 - zoom_factor) * CURSOR_TILT_SENSITIVITY)
+            .clamp(0.01, std::f32::consts::FRAC_PI_2);
+        pan_orbit.force_update = true;
+        return;
+    }
+
+    if keyboard.pressed(KeyCode::AltLeft) {
+        pan_orbit.target_radius = previous_radius;
+
+        let standard_height = zoom_config.min_height.midpoint(zoom_config.max_height);
+        let target_focus = pan_orbit.target_focus;
+        let direction = Vec3::new(
+            pan_orbit.target_pitch.cos() * pan_orbit.target_yaw.sin(),
+            -pan_orbit.target_pitch.sin(),
+            pan_orbit.target_pitch.cos() * pan_orbit.target_yaw.cos(),
+        );
+
+        let moves = VecDeque::from([CameraMove::ToTarget {
+            target_translation: target_focus + direction * standard_height,
+            target_focus,
+            duration_ms: INSTANT_ZOOM_MOVE_DURATION_MS,
+            easing: EaseFunction::QuadraticOut,
+        }]);
+        commands.entity(camera_entity).insert(CameraMoveList::new(moves));
+        return;
+    }
 
-    // Apply adjustments
-    pan_orbit.target_focus += focus_adjustment;
-    let new_target_radius = current_radius + radius_adjustment;
-    pan_orbit.target_radius = new_target_radius;
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Some(cursor_pos) = window.cursor_position() else {
+        return;
+    };
+    let Ok(ray) = camera.viewport_to_world(cam_global, cursor_pos) else {
+        return;
+    };
+
+    let view_plane_hit = ray
+        .intersect_plane(pan_orbit.target_focus, InfinitePlane3d::new(*cam_global.forward()))
+        .map(|distance| ray.get_point(distance));
+    let boundary_hit = boundary
+        .intersect_ray(ray.origin, *ray.direction)
+        .and_then(|intersection| (intersection.enter >= 0.0).then_some(intersection.enter_point));
+
+    let hit_point = match (view_plane_hit, boundary_hit) {
+        (Some(plane), Some(wall)) if ray.origin.distance(wall) < ray.origin.distance(plane) => wall,
+        (Some(plane), _) => plane,
+        (None, Some(wall)) => wall,
+        (None, None) => return,
+    };
+
+    let mut slide_fraction = 1.0 - zoom_factor;
+    if keyboard.pressed(KeyCode::ShiftLeft) {
+        slide_fraction *= CURSOR_ZOOM_SHIFT_MULTIPLIER;
+    }
+
+    pan_orbit.target_radius = pan_orbit
+        .target_radius
+        .clamp(zoom_config.min_height, zoom_config.max_height);
+    pan_orbit.target_focus += (hit_point - pan_orbit.target_focus) * slide_fraction;
     pan_orbit.force_update = true;
+}
 
-    let balanced = margins.is_balanced(zoom_config.margin_tolerance);
-    let fitted = margins.is_fitted(zoom_config.margin_tolerance);
+/// Advances a critically-damped spring one step: `a = omega² * (target - current) -
+/// 2·omega·velocity; velocity += a·dt; current += velocity·dt`. Frame-rate independent and,
+/// unlike a plain `delta * rate` multiply, settles without the jitter a fixed per-frame rate
+/// produces near the tolerance threshold.
+pub(super) fn spring_step(current: f32, target: f32, velocity: &mut f32, omega: f32, dt: f32) -> f32 {
+    let acceleration = omega * omega * (target - current) - 2.0 * omega * *velocity;
+    *velocity += acceleration * dt;
+    current + *velocity * dt
+}
+
+/// Box-space extent of `corners` relative to `center`, measured along the camera's own
+/// right/up/forward axes: `(half_x, half_y, half_depth)`. This is what lets
+/// [`update_zoom_to_fit`] solve the fitting radius/scale/FOV directly instead of projecting
+/// through a current-camera-position-dependent tangent-space margin every frame.
+fn corner_extents(
+    corners: &[Vec3; 8],
+    center: Vec3,
+    cam_right: Vec3,
+    cam_up: Vec3,
+    cam_forward: Vec3,
+) -> (f32, f32, f32) {
+    let (mut half_x, mut half_y) = (0.0, 0.0);
+    let (mut min_depth, mut max_depth) = (f32::INFINITY, f32::NEG_INFINITY);
+    for corner in corners {
+        let relative = *corner - center;
+        half_x = half_x.max(relative.dot(cam_right).abs());
+        half_y = half_y.max(relative.dot(cam_up).abs());
+        let depth = relative.dot(cam_forward);
+        min_depth = min_depth.min(depth);
+        max_depth = max_depth.max(depth);
+    }
+    (half_x, half_y, (max_depth - min_depth) * 0.5)
+}
+
+/// Analytic zoom-to-fit solver: same closed-form, single-pass radius/scale/FOV math
+/// [`calculate_base_fit_radius`] already uses for the preset stepper, generalized from a
+/// world-axis-aligned `grid_size` to `zoom_state.corners`' real extent along the camera's own
+/// axes (via [`corner_extents`]). Replaces the old per-frame ratio-from-last-frame's-margins
+/// convergence loop - there's no "boundary behind camera" edge case to special-case either,
+/// since the solve no longer projects through the camera's current position at all.
+///
+/// **Smoothing**: [`spring_step`] still eases focus and the scalar being fitted toward this
+/// freshly solved target every frame, purely for a pleasant glide rather than to drive
+/// convergence itself; `margin_tolerance` gates a dead-band so the camera stops nudging once
+/// it's close enough, and `max_iterations` is now only a safety bail-out against a target that
+/// never settles, not the mechanism convergence depends on.
+///
+/// **Convergence Detection**: Stops once both the focus and the fitted scalar (radius, scale,
+/// or FOV) land within `margin_tolerance` of their analytic targets.
+fn update_zoom_to_fit(
+    mut commands: Commands,
+    zoom_config: Res<ZoomConfig>,
+    time: Res<Time>,
+    mut camera_query: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut PanOrbitCamera,
+        &mut Projection,
+        &Camera,
+        &mut ZoomToFitActive,
+    )>,
+) {
+    let Ok((entity, cam_global, mut pan_orbit, mut projection, camera, mut zoom_state)) =
+        camera_query.single_mut()
+    else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+    let omega = 2.0 / zoom_config.settle_time_secs;
+    let margin_multiplier = zoom_config.zoom_margin_multiplier();
+
+    // Get actual viewport aspect ratio
+    let aspect_ratio = camera
+        .logical_viewport_size()
+        .map(|viewport_size| viewport_size.x / viewport_size.y);
+
+    let target_focus =
+        zoom_state.corners.iter().sum::<Vec3>() / zoom_state.corners.len().to_f32();
+
+    let cam_rot = cam_global.rotation();
+    let cam_right = cam_rot * Vec3::X;
+    let cam_up = cam_rot * Vec3::Y;
+    let cam_forward = cam_rot * Vec3::NEG_Z;
+    let (half_x, half_y, half_depth) =
+        corner_extents(&zoom_state.corners, target_focus, cam_right, cam_up, cam_forward);
+
+    // Keep depth precision sane as the fitted view tightens: recompute near/far each
+    // iteration from the fitted corners' extent along the camera's forward axis, rather than
+    // leaving them at whatever they were set to before zoom-to-fit started.
+    if let Projection::Perspective(perspective) = &mut *projection {
+        let cam_pos = cam_global.translation();
+        let (mut min_dist, mut max_dist) = (f32::INFINITY, f32::NEG_INFINITY);
+        for corner in zoom_state.corners {
+            let dist = (corner - cam_pos).dot(cam_forward);
+            min_dist = min_dist.min(dist);
+            max_dist = max_dist.max(dist);
+        }
+        perspective.near = (min_dist - FRUSTUM_Z_MARGIN).max(FRUSTUM_MIN_NEAR_Z);
+        perspective.far = max_dist + FRUSTUM_Z_MARGIN;
+    }
 
     println!(
-        "  Radius: {:.1}→{:.1} (Δ={:.3}, rate={:.0}%)",
-        current_radius,
-        new_target_radius,
-        radius_delta,
-        rate * 100.0
+        "Iteration {}: focus=({:.3},{:.3},{:.3}) half_x={:.3} half_y={:.3} half_depth={:.3}",
+        zoom_state.iteration_count,
+        target_focus.x,
+        target_focus.y,
+        target_focus.z,
+        half_x,
+        half_y,
+        half_depth
     );
-    println!("  Status: balanced={}, fitted={}", balanced, fitted);
 
-    // Check completion: balanced AND fitted
-    if balanced && fitted {
+    // Use target_radius instead of actual radius to avoid one-frame delay
+    // Since we set smoothness to 0, target should equal actual, but Transform updates next frame
+    let current_radius = pan_orbit.target_radius;
+
+    let new_focus = Vec3::new(
+        spring_step(
+            pan_orbit.target_focus.x,
+            target_focus.x,
+            &mut zoom_state.focus_velocity.x,
+            omega,
+            dt,
+        ),
+        spring_step(
+            pan_orbit.target_focus.y,
+            target_focus.y,
+            &mut zoom_state.focus_velocity.y,
+            omega,
+            dt,
+        ),
+        spring_step(
+            pan_orbit.target_focus.z,
+            target_focus.z,
+            &mut zoom_state.focus_velocity.z,
+            omega,
+            dt,
+        ),
+    );
+    let focus_settled = (new_focus - target_focus).length()
+        <= zoom_config.margin_tolerance * current_radius.max(1.0);
+    pan_orbit.target_focus = new_focus;
+    pan_orbit.force_update = true;
+
+    // A perspective camera fits by dollying (radius) or, in `Fov` mode, by narrowing the lens
+    // at a fixed distance; an orthographic one fits by changing the projection's scale instead.
+    // Each branch solves its target directly from `half_x`/`half_y`/`half_depth` - no ratio
+    // against last frame's margins needed.
+    let scalar_settled = match &mut *projection {
+        Projection::Perspective(perspective) if zoom_config.fit_mode == ZoomFitMode::Fov => {
+            // Distance is fixed at `current_radius`; solve the half-vfov that puts half_x/half_y
+            // exactly at the margin-shrunk edge, clamped to configured bounds. Any error the
+            // clamp leaves behind is made up with a radius adjustment, same as `Radius` mode.
+            let viewport_aspect = aspect_ratio.unwrap_or(perspective.aspect_ratio);
+            let half_tan_vfov_from_y = half_y * margin_multiplier / current_radius;
+            let half_tan_vfov_from_x = half_x * margin_multiplier / (current_radius * viewport_aspect);
+            let unclamped_target_fov = 2.0 * half_tan_vfov_from_y.max(half_tan_vfov_from_x).atan();
+            let target_fov = unclamped_target_fov.clamp(zoom_config.min_fov, zoom_config.max_fov);
+
+            let current_fov = perspective.fov;
+            let new_fov = spring_step(
+                current_fov,
+                target_fov,
+                &mut zoom_state.value_velocity,
+                omega,
+                dt,
+            );
+            let fov_delta = new_fov - current_fov;
+            perspective.fov = new_fov;
+
+            // The clamp can leave error behind; make it up with a (non-springed, since it's a
+            // secondary correction) exponential-decay nudge to radius so the boundary still fits.
+            let residual_ratio = if unclamped_target_fov > zoom_config.max_fov {
+                unclamped_target_fov / zoom_config.max_fov
+            } else if unclamped_target_fov < zoom_config.min_fov {
+                unclamped_target_fov / zoom_config.min_fov
+            } else {
+                1.0
+            };
+            let target_radius = current_radius * residual_ratio;
+            let alpha = 1.0 - (-omega * dt).exp();
+            pan_orbit.target_radius = current_radius + (target_radius - current_radius) * alpha;
+
+            println!(
+                "  Fov: {:.3}→{:.3} (Δ={:.3}, residual ratio={:.2})",
+                current_fov, new_fov, fov_delta, residual_ratio
+            );
+
+            (new_fov - target_fov).abs() <= zoom_config.margin_tolerance
+        },
+        Projection::Perspective(perspective) => {
+            let half_tan_vfov = (perspective.fov * 0.5).tan();
+            let half_tan_hfov = half_tan_vfov * aspect_ratio.unwrap_or(perspective.aspect_ratio);
+            let target_radius = (half_x * margin_multiplier / half_tan_hfov)
+                .max(half_y * margin_multiplier / half_tan_vfov)
+                + half_depth;
+            let new_radius = spring_step(
+                current_radius,
+                target_radius,
+                &mut zoom_state.value_velocity,
+                omega,
+                dt,
+            );
+            pan_orbit.target_radius = new_radius;
+
+            println!(
+                "  Radius: {:.1}→{:.1} (target={:.1})",
+                current_radius, new_radius, target_radius
+            );
+
+            (new_radius - target_radius).abs() <= zoom_config.margin_tolerance * target_radius.max(1.0)
+        },
+        Projection::Orthographic(orthographic) => {
+            let half_width = orthographic.area.width() * 0.5;
+            let half_height = orthographic.area.height() * 0.5;
+            let target_scale = (half_x * margin_multiplier / half_width)
+                .max(half_y * margin_multiplier / half_height);
+            let current_scale = orthographic.scale;
+            let new_scale = spring_step(
+                current_scale,
+                target_scale,
+                &mut zoom_state.value_velocity,
+                omega,
+                dt,
+            );
+            orthographic.scale = new_scale;
+
+            println!(
+                "  Scale: {:.3}→{:.3} (target={:.3})",
+                current_scale, new_scale, target_scale
+            );
+
+            (new_scale - target_scale).abs() <= zoom_config.margin_tolerance * target_scale.max(1.0)
+        },
+        Projection::Custom(_) => true,
+    };
+
+    if focus_settled && scalar_settled {
         println!("  → CONVERGED");
         commands.entity(entity).remove::<ZoomToFitActive>();
         return;
@@ -271,7 +951,8 @@ fn update_zoom_to_fit(
 
     zoom_state.iteration_count += 1;
 
-    // Stop if we hit max iterations
+    // Safety bail-out: the analytic target is already correct every frame, so this should only
+    // ever trip on a degenerate case (e.g. corners collapsed to a point) that never settles.
     if zoom_state.iteration_count >= zoom_config.max_iterations {
         println!("  → MAX ITERATIONS REACHED (not converged)");
         commands.entity(entity).remove::<ZoomToFitActive>();
@@ -1,19 +1,32 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
-use rand::Rng;
 
+use crate::actor::CollapseSequence;
 use crate::actor::Deaderoid;
 use crate::actor::Health;
 use crate::actor::MissilePosition;
 use crate::actor::Nateroid;
 use crate::actor::NateroidDeathMaterials;
+use crate::actor::NateroidSize;
+use crate::actor::ShipState;
 use crate::actor::actor_template::DeathCorner;
+use crate::actor::actor_template::DeathEasing;
 use crate::actor::actor_template::NateroidConfig;
+use crate::actor::spawn_fragments;
+use crate::anim_automaton::AnimAutomaton;
+use crate::anim_automaton::AnimSection;
+use crate::camera::StarLightGrid;
 use crate::playfield::Boundary;
+use crate::rollback::RollbackRng;
 use crate::schedule::InGameSet;
 use crate::state::GameState;
 use crate::traits::UsizeExt;
 
+/// Fraction of `shrink_duration` over which a dying nateroid's launch velocity blends from its
+/// velocity at the moment of death to the eased-curve velocity, so the launch doesn't snap
+/// instantly onto the curve.
+const DEATH_VELOCITY_BLEND_FRACTION: f32 = 0.15;
+
 pub struct DespawnPlugin;
 
 impl Plugin for DespawnPlugin {
@@ -45,14 +58,18 @@ fn despawn_missiles(mut commands: Commands, query: Query<(Entity, &MissilePositi
 /// (e.g., missile reaching max distance AND taking lethal damage simultaneously)
 pub fn despawn(commands: &mut Commands, entity: Entity) { commands.entity(entity).try_despawn(); }
 
-/// Calculates velocity toward a boundary corner based on the death corner strategy.
-/// Velocity is calculated to reach the corner in exactly `death_duration` seconds.
-fn calculate_death_velocity(
+/// Selects the boundary corner a dying nateroid launches toward, based on the death corner
+/// strategy. `animate_dying_nateroids` derives the per-frame launch velocity from the straight
+/// line to this corner - for a large boundary and a short duration the resulting speed can exceed
+/// a collider's size in a single tick, but `teleport.rs`'s swept `Boundary::crossing` check (see
+/// `teleport_at_boundary`) catches the true exit point regardless, so the launch speed here
+/// doesn't need its own tunneling guard.
+fn select_death_corner(
     position: Vec3,
     current_velocity: Vec3,
     boundary: &Boundary,
-    death_duration: f32,
     death_corner: DeathCorner,
+    rng: &mut RollbackRng,
 ) -> Vec3 {
     const EPSILON: f32 = 0.001;
     let half_size = boundary.transform.scale / 2.0;
@@ -117,9 +134,9 @@ fn calculate_death_velocity(
                 .unwrap_or(corners[0])
         },
         DeathCorner::Random => {
-            // Randomly select one corner
-            let mut rng = rand::rng();
-            corners[rng.random_range(0..8)]
+            // Randomly select one corner, from the rollback-serializable RNG so
+            // resimulated frames pick the same corner as the original run.
+            corners[rng.random_range(8)]
         },
         DeathCorner::Directional => {
             // Find corner most aligned with current velocity direction
@@ -151,19 +168,40 @@ fn calculate_death_velocity(
 
             // If multiple corners equally aligned, randomly pick one
             if best_corners.len() > 1 {
-                let mut rng = rand::rng();
-                best_corners[rng.random_range(0..best_corners.len())]
+                best_corners[rng.random_range(best_corners.len())]
             } else {
                 best_corners.first().copied().unwrap_or(corners[0])
             }
         },
     };
 
-    // Calculate velocity to reach corner in exactly death_duration seconds
-    // velocity = (target_position - current_position) / time
-    (target_corner - position) / death_duration
+    target_corner
+}
+
+/// Clones `base` and blends its base color toward `tint` (a [`StarLightGrid`] ambient sample), so
+/// a dying nateroid's initial death materials pick up a faint cast from nearby stars without
+/// mutating `base` itself - `base` is one of `NateroidDeathMaterials`'s precomputed handles, shared
+/// across every nateroid dying at that transparency level.
+fn tint_material(
+    materials: &mut Assets<StandardMaterial>,
+    base: &Handle<StandardMaterial>,
+    tint: Vec3,
+) -> Handle<StandardMaterial> {
+    let Some(base_material) = materials.get(base) else {
+        return base.clone();
+    };
+
+    let mut tinted = base_material.clone();
+    let blend = tint.length().clamp(0.0, 1.0);
+    tinted.base_color = lerp_color(tinted.base_color, Color::srgb(tint.x, tint.y, tint.z), blend);
+    materials.add(tinted)
+}
+
+fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    Color::from(a.to_linear().mix(&b.to_linear(), t.clamp(0.0, 1.0)))
 }
 
+#[allow(clippy::type_complexity)]
 fn despawn_dead_entities(
     mut commands: Commands,
     query: Query<
@@ -173,48 +211,96 @@ fn despawn_dead_entities(
             &Transform,
             &LinearVelocity,
             Option<&Nateroid>,
+            Option<&NateroidSize>,
             Option<&Name>,
+            Option<&CollapseSequence>,
+            Has<ShipState>,
         ),
         Without<Deaderoid>,
     >,
     config: Res<NateroidConfig>,
     boundary: Res<Boundary>,
     death_materials: Option<Res<NateroidDeathMaterials>>,
+    star_light_grid: Res<StarLightGrid>,
     children_query: Query<&Children>,
     material_query: Query<&MeshMaterial3d<StandardMaterial>>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut rng: ResMut<RollbackRng>,
 ) {
-    for (entity, health, transform, linear_velocity, nateroid, name) in query.iter() {
+    for (
+        entity,
+        health,
+        transform,
+        linear_velocity,
+        nateroid,
+        nateroid_size,
+        name,
+        collapse_sequence,
+        is_ship,
+    ) in query.iter()
+    {
         if health.0 <= 0.0 {
-            if nateroid.is_some() {
+            if is_ship {
+                // Transition into ShipState::Dead instead of despawning outright, so
+                // `spaceship_destroyed` can react to an explicit death rather than relying solely
+                // on the spaceship query going empty - `despawn_all_entities` cleans the entity up
+                // once that system moves the game into GameOver.
+                commands.entity(entity).insert(ShipState::Dead);
+            } else if nateroid.is_some() {
                 let entity_name = name.map_or("Unknown", |n| (*n).as_str());
                 debug!(
                     "â˜ ï¸ despawn_dead_entities: Adding Deaderoid to {} (health: {})",
                     entity_name, health.0
                 );
 
-                // Calculate velocity to reach target corner in death_duration
-                let death_velocity = calculate_death_velocity(
+                // Pick the corner animate_dying_nateroids launches this nateroid toward
+                let target_corner = select_death_corner(
                     transform.translation,
                     linear_velocity.0,
                     &boundary,
-                    config.death_duration_secs,
                     config.death_corner,
+                    &mut rng,
                 );
 
+                // Fragment into smaller nateroids before the shrink-and-fade animation takes
+                // over - a no-op once a nateroid is already at the minimum size class.
+                if let Some(&size) = nateroid_size {
+                    spawn_fragments(
+                        &mut commands,
+                        &config,
+                        &mut rng,
+                        transform.translation,
+                        linear_velocity.0,
+                        transform.scale,
+                        size,
+                    );
+                }
+
+                // One section spanning the precomputed transparency levels - a no-op single
+                // frame if materials haven't loaded yet, so the automaton is always valid.
+                let material_levels =
+                    death_materials.as_ref().map_or(1, |m| m.materials.len().max(1));
+
                 // Nateroid - start death animation
                 commands
                     .entity(entity)
                     .insert((
                         Deaderoid {
-                            initial_scale:          transform.scale,
-                            target_shrink:          config.death_shrink_pct,
-                            shrink_duration:        config.death_duration_secs,
-                            elapsed_time:           0.0,
-                            current_shrink:         1.0,
-                            current_material_index: 0,
+                            initial_scale:   transform.scale,
+                            target_shrink:   config.death_shrink_pct,
+                            shrink_duration: config.death_duration_secs,
+                            elapsed_time:    0.0,
+                            current_shrink:  1.0,
+                            automaton:       AnimAutomaton::new(vec![AnimSection {
+                                start_frame: 0,
+                                end_frame:   material_levels - 1,
+                            }]),
+                            launch_start:    transform.translation,
+                            launch_target:   target_corner,
+                            launch_velocity: linear_velocity.0,
                         },
                         CollisionLayers::NONE,
-                        LinearVelocity(death_velocity),
+                        LinearVelocity(linear_velocity.0),
                     ))
                     .remove::<LockedAxes>();
 
@@ -225,13 +311,23 @@ fn despawn_dead_entities(
                     let materials_for_level = &death_materials.materials[0];
                     let mut material_index = 0;
 
+                    // Tint this death's initial materials toward the nearby star-light color once,
+                    // at the moment of death, rather than retrofitting the per-frame material-swap
+                    // loop in animate_dying_nateroids - that loop swaps every descendant's material
+                    // each time the automaton's frame changes, and cloning+tinting a fresh
+                    // StandardMaterial there would mean a new material allocation on every swap.
+                    let (ambient, _direction) = star_light_grid.sample(transform.translation);
+
                     for descendant in children_query.iter_descendants(entity) {
                         if material_query.get(descendant).is_ok()
                             && material_index < materials_for_level.len()
                         {
-                            commands.entity(descendant).insert(MeshMaterial3d(
-                                materials_for_level[material_index].clone(),
-                            ));
+                            let tinted = tint_material(
+                                &mut standard_materials,
+                                &materials_for_level[material_index],
+                                ambient,
+                            );
+                            commands.entity(descendant).insert(MeshMaterial3d(tinted));
                             material_index += 1;
                         }
                     }
@@ -241,10 +337,12 @@ fn despawn_dead_entities(
                         config.initial_alpha
                     );
                 }
-            } else {
-                // Other entities - despawn immediately
+            } else if collapse_sequence.is_none() {
+                // No scripted destruction timeline configured - despawn immediately
                 despawn(&mut commands, entity);
             }
+            // Entities with a CollapseSequence are left alone here - collapse.rs drives their
+            // timeline and despawns the root once it finishes
         }
     }
 }
@@ -263,7 +361,13 @@ fn despawn_splash(mut commands: Commands, query: Query<Entity, With<crate::splas
 }
 
 fn animate_dying_nateroids(
-    mut query: Query<(&mut Deaderoid, &mut Transform, Entity, Option<&Name>)>,
+    mut query: Query<(
+        &mut Deaderoid,
+        &mut Transform,
+        &mut LinearVelocity,
+        Entity,
+        Option<&Name>,
+    )>,
     time: Res<Time>,
     death_materials: Option<Res<NateroidDeathMaterials>>,
     children_query: Query<&Children>,
@@ -276,7 +380,7 @@ fn animate_dying_nateroids(
         return;
     };
 
-    for (mut deaderoid, mut transform, entity, name) in &mut query {
+    for (mut deaderoid, mut transform, mut linear_velocity, entity, name) in &mut query {
         let entity_name = name.map_or("Unknown", |n| (*n).as_str());
 
         // Update elapsed time
@@ -293,18 +397,27 @@ fn animate_dying_nateroids(
         // Apply shrinking to transform
         transform.scale = deaderoid.initial_scale * deaderoid.current_shrink;
 
+        // Drive the launch velocity from the derivative of the eased position curve along the
+        // straight line to the target corner (chain rule: ds/dt_real = (ds/dt) / duration), so
+        // the nateroid spins up, cruises, and decelerates into the corner rather than sliding at
+        // a constant speed. Blend in from the velocity at the moment of death over the first
+        // fraction of the duration so there's no instantaneous jump.
+        let launch_path = deaderoid.launch_target - deaderoid.launch_start;
+        let eased_velocity = launch_path
+            * (nateroid_config.death_easing.ease_derivative(progress) / deaderoid.shrink_duration);
+        let blend_t = (progress / DEATH_VELOCITY_BLEND_FRACTION).min(1.0);
+        let blend = DeathEasing::Smoothstep.ease(blend_t);
+        *linear_velocity = LinearVelocity(deaderoid.launch_velocity.lerp(eased_velocity, blend));
+
         // Apply ease-out curve (inverse cubic) for material swapping - fades rapidly at first,
         // then slows down (exponential decay)
         let eased_progress = 1.0 - (1.0 - progress).powi(3);
-        // Safe: eased_progress is 0.0-1.0, bounded by array size, result is valid index
-        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-        let new_index = (eased_progress * (death_materials.materials.len() - 1).to_f32()) as usize;
-
-        // Only swap materials when index changes
-        if new_index != deaderoid.current_material_index {
-            let old_index = deaderoid.current_material_index;
-            deaderoid.current_material_index = new_index;
+        let old_index = deaderoid.automaton.current_frame;
+        deaderoid.automaton.set_progress(eased_progress);
+        let new_index = deaderoid.automaton.current_frame;
 
+        // Only swap materials when the automaton's current frame changes
+        if new_index != old_index {
             // Calculate the alpha value for this level
             // FMA optimization (faster + more precise): initial_alpha - (new_index as f32 * 0.01)
             let alpha = new_index
@@ -335,7 +448,6 @@ fn animate_dying_nateroids(
             debug!("ðŸ’€ {entity_name}: Swapped materials on {material_index} descendants");
         }
 
-        // Note: Velocity is constant (set once in despawn_dead_entities)
         // Despawn happens in teleport system when Deaderoid entities teleport
     }
 }
@@ -7,6 +7,7 @@
 
 // exclude when targeting wasm - this breaks in the browser right now
 mod actor;
+mod anim_automaton;
 mod asset_loader;
 mod camera;
 mod despawn;
@@ -14,6 +15,7 @@ mod game_input;
 mod orientation;
 mod physics;
 mod playfield;
+mod rollback;
 mod schedule;
 mod splash;
 mod state;
@@ -29,6 +31,8 @@ use bevy_brp_extras::BrpExtrasPlugin;
 use bevy_inspector_egui::bevy_egui::EguiPlugin;
 
 use crate::actor::ActorPlugin;
+use crate::actor::EvolveConfig;
+use crate::actor::run_evolution;
 use crate::asset_loader::AssetLoaderPlugin;
 use crate::camera::CameraPlugin;
 use crate::despawn::DespawnPlugin;
@@ -36,11 +40,19 @@ use crate::game_input::InputPlugin;
 use crate::orientation::OrientationPlugin;
 use crate::physics::PhysicsPlugin;
 use crate::playfield::PlayfieldPlugin;
+use crate::rollback::RollbackPlugin;
+use crate::rollback::RollbackRng;
 use crate::schedule::SchedulePlugin;
 use crate::splash::SplashPlugin;
 use crate::state::StatePlugin;
 
 fn main() {
+    // `cargo run -- evolve` trains a HunterBrain genome headlessly instead of launching the game.
+    if std::env::args().any(|arg| arg == "evolve") {
+        run_hunter_evolution();
+        return;
+    }
+
     let mut app = App::new();
 
     // Get effective port from BrpExtrasPlugin to include in window title if non-default
@@ -98,9 +110,26 @@ fn main() {
         InputPlugin,
         OrientationPlugin,
         PhysicsPlugin,
+        RollbackPlugin,
         SchedulePlugin,
         SplashPlugin,
         StatePlugin,
     ))
     .run();
 }
+
+/// Dev-tool entry point for `hunter_evolve`'s headless training mode: seeds a fresh
+/// [`RollbackRng`] from the current time (training doesn't need rollback-determinism) and runs
+/// `run_evolution`, which persists the fittest genome for normal play to pick up.
+fn run_hunter_evolution() {
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    let mut rng = RollbackRng::from_seed(seed);
+
+    run_evolution(&EvolveConfig::default(), &mut rng);
+}
@@ -9,6 +9,27 @@ use crate::actor::Nateroid;
 use crate::camera::RenderLayer;
 use crate::game_input::GameAction;
 
+/// World units/sec average nateroid speed above which a single discrete `FixedUpdate` step risks
+/// tunneling clean through a collider - `monitor_physics_health` steps `SubstepCount` up toward
+/// [`SUBSTEP_CEILING`] as the swarm approaches this, trading frame time for solver accuracy.
+const SPEED_TUNNELING_RISK_THRESHOLD: f32 = 200.0;
+
+/// `SubstepCount` value `monitor_physics_health` eases back toward once the swarm is neither
+/// stressed nor approaching the tunneling-risk speed.
+const SUBSTEP_BASELINE: u32 = 15;
+/// Floor `monitor_physics_health` steps `SubstepCount` down toward while FPS stays stressed -
+/// never disabled entirely, just coarsened to claw back frame time.
+const SUBSTEP_FLOOR: u32 = 6;
+/// Ceiling `monitor_physics_health` steps `SubstepCount` up toward as average speed approaches
+/// [`SPEED_TUNNELING_RISK_THRESHOLD`].
+const SUBSTEP_CEILING: u32 = 24;
+/// Substeps adjusted per debounced transition - small enough that a swing from floor to ceiling
+/// takes several dwell periods rather than one jump.
+const SUBSTEP_STEP: u32 = 3;
+/// Minimum seconds between `SubstepCount` adjustments, reusing the same debounce idea as
+/// `last_stress_log` so the count can't oscillate frame to frame.
+const SUBSTEP_DWELL_SECS: f64 = 1.0;
+
 pub struct PhysicsPlugin;
 
 impl Plugin for PhysicsPlugin {
@@ -26,9 +47,10 @@ impl Plugin for PhysicsPlugin {
 
 #[derive(Resource, Default)]
 struct PhysicsMonitorState {
-    is_stressed:       bool,
-    last_stress_log:   f64,
-    logged_unstressed: bool,
+    is_stressed:         bool,
+    last_stress_log:     f64,
+    logged_unstressed:   bool,
+    last_substep_change: f64,
 }
 
 fn init_physics_debug_aabb(mut config_store: ResMut<GizmoConfigStore>) {
@@ -53,6 +75,7 @@ fn monitor_physics_health(
     time: Res<Time<Fixed>>,
     diagnostics: Res<DiagnosticsStore>,
     mut state: ResMut<PhysicsMonitorState>,
+    mut substep_count: ResMut<SubstepCount>,
 ) {
     let nateroid_count = nateroids.iter().len();
 
@@ -79,10 +102,10 @@ fn monitor_physics_health(
     // Use different thresholds for entering vs exiting stress state
     let physics_struggling = if state.is_stressed {
         // When already stressed, need FPS > 45.0 to exit
-        fps < 45.0 || avg_speed > 200.0
+        fps < 45.0 || avg_speed > SPEED_TUNNELING_RISK_THRESHOLD
     } else {
         // When not stressed, need FPS < 35.0 to enter
-        fps < 35.0 || avg_speed > 200.0
+        fps < 35.0 || avg_speed > SPEED_TUNNELING_RISK_THRESHOLD
     };
 
     let current_time = time.elapsed_secs_f64();
@@ -115,4 +138,45 @@ fn monitor_physics_health(
             state.is_stressed = false;
         }
     }
+
+    adapt_substep_count(&mut state, &mut substep_count, avg_speed, current_time);
+}
+
+/// Closed-loop controller over `SubstepCount`, debounced by [`SUBSTEP_DWELL_SECS`] so it can't
+/// oscillate frame to frame: steps down toward [`SUBSTEP_FLOOR`] while FPS stays stressed (clawing
+/// back frame time), steps up toward [`SUBSTEP_CEILING`] as `avg_speed` approaches the tunneling-risk
+/// threshold (buying solver accuracy), and otherwise eases back toward [`SUBSTEP_BASELINE`].
+fn adapt_substep_count(
+    state: &mut PhysicsMonitorState,
+    substep_count: &mut SubstepCount,
+    avg_speed: f32,
+    current_time: f64,
+) {
+    if current_time - state.last_substep_change < SUBSTEP_DWELL_SECS {
+        return;
+    }
+
+    let current = substep_count.0;
+    // Speed is checked first: `monitor_physics_health` also sets `is_stressed` from high speed
+    // alone, so past the threshold `is_stressed` no longer means FPS is low.
+    let target = if avg_speed > SPEED_TUNNELING_RISK_THRESHOLD {
+        (current + SUBSTEP_STEP).min(SUBSTEP_CEILING)
+    } else if state.is_stressed {
+        current.saturating_sub(SUBSTEP_STEP).max(SUBSTEP_FLOOR)
+    } else if current < SUBSTEP_BASELINE {
+        (current + SUBSTEP_STEP).min(SUBSTEP_BASELINE)
+    } else if current > SUBSTEP_BASELINE {
+        current.saturating_sub(SUBSTEP_STEP).max(SUBSTEP_BASELINE)
+    } else {
+        current
+    };
+
+    if target != current {
+        info!(
+            "Adaptive SubstepCount: {current} -> {target} (avg_speed: {avg_speed:.1}, stressed: {})",
+            state.is_stressed
+        );
+        substep_count.0 = target;
+        state.last_substep_change = current_time;
+    }
 }
@@ -11,6 +11,8 @@ use crate::game_input::GameAction;
 use crate::game_input::toggle_active;
 use crate::orientation::CameraOrientation;
 use crate::playfield::boundary_face::BoundaryFace;
+use crate::playfield::frustum::FrustumPlanes;
+use crate::playfield::frustum::FrustumTest;
 use crate::playfield::portals::Portal;
 use crate::playfield::portals::PortalGizmo;
 use crate::state::PlayingGame;
@@ -21,6 +23,10 @@ const BOUNDARY_OVEREXTENSION_EPSILON: f32 = BOUNDARY_SNAP_EPSILON * 2.0;
 
 const MIN_POINTS_FOR_ARC: usize = 2;
 
+// Tessellation resolution used only to determine whether a face has a visible clipped arc at all
+// (calculate_portal_face_count) - independent of the caller-supplied resolution used for rendering.
+const PORTAL_FACE_COUNT_RESOLUTION: u32 = 16;
+
 // Deaderoid portal colors
 const DEADEROID_APPROACHING_COLOR: Color = Color::srgb(1.0, 0.0, 0.0); // Red
 const CORNER_COLOR_LEFT_RIGHT_YZ: Color = Color::srgb(1.0, 0.0, 0.0); // Red
@@ -52,6 +58,73 @@ enum PortalGeometry {
     MultiFace(MultiFaceGeometry),
 }
 
+/// How a boundary face treats a body that crosses it.
+#[derive(Debug, Clone, Copy, Default, Reflect, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BoundaryFaceBehavior {
+    /// Toroidal topology (the original behavior) - reappear at the opposite face.
+    #[default]
+    Wrap,
+    /// Bounce - clamp just inside the face and flip the crossing velocity component.
+    Reflect,
+    /// No-portal wall - clamp just inside the face and stop the crossing velocity component.
+    Solid,
+}
+
+/// Per-face [`BoundaryFaceBehavior`], one entry per [`BoundaryFace`]. A plain six-field struct
+/// rather than a `HashMap` so every face has a value the inspector can show and edit, mirroring
+/// how the rest of [`Boundary`]'s inspectable fields work.
+#[derive(Debug, Clone, Copy, Default, Reflect, PartialEq)]
+pub struct BoundaryFaceBehaviors {
+    pub left:   BoundaryFaceBehavior,
+    pub right:  BoundaryFaceBehavior,
+    pub top:    BoundaryFaceBehavior,
+    pub bottom: BoundaryFaceBehavior,
+    pub front:  BoundaryFaceBehavior,
+    pub back:   BoundaryFaceBehavior,
+}
+
+impl BoundaryFaceBehaviors {
+    pub fn get(&self, face: BoundaryFace) -> BoundaryFaceBehavior {
+        match face {
+            BoundaryFace::Left => self.left,
+            BoundaryFace::Right => self.right,
+            BoundaryFace::Top => self.top,
+            BoundaryFace::Bottom => self.bottom,
+            BoundaryFace::Front => self.front,
+            BoundaryFace::Back => self.back,
+        }
+    }
+}
+
+/// What happens to a body that exits the boundary on at least one axis, per
+/// [`Boundary::resolve_boundary_crossing`]. When crossed faces disagree (e.g. a corner where one
+/// axis wraps and another is solid), `Solid` wins over `Reflect`, which wins over `Wrap` -
+/// stopping or bouncing a body is a firmer guarantee than visually wrapping it through the corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoundaryCrossing {
+    /// Still inside the boundary - nothing crossed.
+    None,
+    /// Crossed a `Wrap` face - reappear at `position`, on the opposite side.
+    Wrapped(Vec3),
+    /// Crossed a `Reflect` face - clamped just inside at `position`; flip velocity across `normal`.
+    Reflected { position: Vec3, normal: Dir3 },
+    /// Crossed a `Solid` face - clamped just inside at `position`; velocity should stop.
+    Stopped(Vec3),
+}
+
+/// A ray's full intersection with the boundary cuboid, from [`Boundary::intersect_ray`]: the `t`
+/// values and world-space points where the ray enters and exits, plus the normal of whichever face
+/// it crossed at each end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundaryIntersection {
+    pub enter:        f32,
+    pub exit:         f32,
+    pub enter_point:  Vec3,
+    pub exit_point:   Vec3,
+    pub enter_normal: Dir3,
+    pub exit_normal:  Dir3,
+}
+
 /// Describes portals that span multiple boundary faces
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum MultiFaceGeometry {
@@ -87,16 +160,32 @@ fn update_gizmos_config(mut config_store: ResMut<GizmoConfigStore>, boundary: Re
 #[derive(Resource, Reflect, InspectorOptions, Clone, Debug)]
 #[reflect(Resource, InspectorOptions)]
 pub struct Boundary {
-    pub cell_count:          UVec3,
-    pub grid_color:          Color,
-    pub outer_color:         Color,
+    pub cell_count:                    UVec3,
+    pub grid_color:                    Color,
+    pub outer_color:                   Color,
     #[inspector(min = 0.1, max = 40.0, display = NumberDisplay::Slider)]
-    pub grid_line_width:     f32,
+    pub grid_line_width:               f32,
     #[inspector(min = 0.1, max = 40.0, display = NumberDisplay::Slider)]
-    pub boundary_line_width: f32,
+    pub boundary_line_width:           f32,
     #[inspector(min = 50., max = 300., display = NumberDisplay::Slider)]
-    pub boundary_scalar:     f32,
-    pub transform:           Transform,
+    pub boundary_scalar:               f32,
+    pub transform:                     Transform,
+    /// When a player portal overextends past a corner: bump it inward to fit on one face
+    /// ([`Boundary::fit_portal_to_face`]) instead of wrapping it around the corner
+    /// ([`Boundary::draw_multiface_portal`]).
+    pub fit_portal_to_face_player:     bool,
+    /// As `fit_portal_to_face_player`, but for deaderoid (dying-nateroid) portals.
+    pub fit_portal_to_face_deaderoid:  bool,
+    /// Per-face wrap/reflect/solid behavior - defaults to wrapping on every face (the original
+    /// toroidal topology). See [`BoundaryFaceBehavior`].
+    pub face_behaviors:                BoundaryFaceBehaviors,
+    /// How much of a wrapped body's velocity, along the normal of the face it just wrapped
+    /// through, gets reflected back rather than carried straight through - `0.0` is a pure
+    /// wraparound (velocity fully continuous), `1.0` a full elastic bounce, anything between a
+    /// partial-bounce "kick". Applied by `teleport::apply_teleport_kick`, separately from
+    /// `face_behaviors`'s own `Reflect`/`Solid` handling.
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub wrap_velocity_kick_factor:     f32,
 }
 
 impl Default for Boundary {
@@ -112,11 +201,41 @@ impl Default for Boundary {
             boundary_line_width: 6.,
             boundary_scalar,
             transform: Transform::from_scale(boundary_scalar * cell_count.as_vec3()),
+            fit_portal_to_face_player: false,
+            fit_portal_to_face_deaderoid: false,
+            face_behaviors: BoundaryFaceBehaviors::default(),
+            wrap_velocity_kick_factor: 0.0,
         }
     }
 }
 
 impl Boundary {
+    /// Transforms a world-space point into the boundary's local frame: an axis-aligned cuboid of
+    /// `±scale/2` centered at the origin, regardless of `transform.rotation`/`transform.translation`.
+    /// Paired with [`Self::to_world`] so the geometric methods below can do their axis-aligned math
+    /// in local space - against plain min/max planes - then rotate/translate the result back out,
+    /// the standard "transform the query into object space, intersect the canonical shape,
+    /// transform the hit back" approach.
+    fn to_local(&self, world: Vec3) -> Vec3 {
+        self.transform.rotation.inverse() * (world - self.transform.translation)
+    }
+
+    /// Inverse of [`Self::to_local`]: rotates and translates a local-frame point back to world
+    /// space.
+    fn to_world(&self, local: Vec3) -> Vec3 {
+        self.transform.rotation * local + self.transform.translation
+    }
+
+    /// As [`Self::to_local`], but for directions/displacements - rotation only, no translation.
+    fn to_local_dir(&self, world: Vec3) -> Vec3 {
+        self.transform.rotation.inverse() * world
+    }
+
+    /// As [`Self::to_world`], but for directions/displacements - rotation only, no translation.
+    fn to_world_dir(&self, local: Vec3) -> Vec3 {
+        self.transform.rotation * local
+    }
+
     /// Analyzes portal geometry relative to boundary faces
     fn classify_portal_geometry(&self, portal: &Portal) -> PortalGeometry {
         let overextended_faces = self.get_overextended_faces_for(portal);
@@ -158,8 +277,10 @@ impl Boundary {
     /// - Finally, it returns the intersection point corresponding to the minimum distance, or
     ///   `None` if no valid intersection is found.
     pub fn calculate_teleport_position(&self, position: Vec3) -> Vec3 {
-        let boundary_min = self.transform.translation - self.transform.scale / 2.0;
-        let boundary_max = self.transform.translation + self.transform.scale / 2.0;
+        let half_size = self.transform.scale / 2.0;
+        let boundary_min = -half_size;
+        let boundary_max = half_size;
+        let position = self.to_local(position);
 
         let mut teleport_position = position;
 
@@ -187,57 +308,176 @@ impl Boundary {
             teleport_position.z = boundary_max.z - offset;
         }
 
-        teleport_position
+        self.to_world(teleport_position)
+    }
+
+    /// Resolves a potential boundary exit by consulting each crossed face's configured
+    /// [`BoundaryFaceBehavior`], built on top of [`Self::calculate_teleport_position`]'s
+    /// axis-independent wrap math. `Reflect`/`Solid` faces clamp `position` just inside the
+    /// boundary (via [`Self::snap_position_to_boundary_face`]) instead of wrapping it.
+    pub fn resolve_boundary_crossing(&self, position: Vec3) -> BoundaryCrossing {
+        let wrapped = self.calculate_teleport_position(position);
+        if wrapped == position {
+            return BoundaryCrossing::None;
+        }
+
+        let half_size = self.transform.scale / 2.0;
+        let boundary_min = -half_size;
+        let boundary_max = half_size;
+        let local = self.to_local(position);
+
+        let crossed_faces = [
+            (local.x >= boundary_max.x, BoundaryFace::Right),
+            (local.x <= boundary_min.x, BoundaryFace::Left),
+            (local.y >= boundary_max.y, BoundaryFace::Top),
+            (local.y <= boundary_min.y, BoundaryFace::Bottom),
+            (local.z >= boundary_max.z, BoundaryFace::Front),
+            (local.z <= boundary_min.z, BoundaryFace::Back),
+        ];
+
+        let behavior = crossed_faces
+            .into_iter()
+            .filter_map(|(crossed, face)| crossed.then(|| self.face_behaviors.get(face)))
+            .max()
+            .unwrap_or_default();
+
+        match behavior {
+            BoundaryFaceBehavior::Wrap => BoundaryCrossing::Wrapped(wrapped),
+            BoundaryFaceBehavior::Reflect | BoundaryFaceBehavior::Solid => {
+                let normal = self.get_normal_for_position(position);
+                let clamped = self.snap_position_to_boundary_face(position, normal);
+                if behavior == BoundaryFaceBehavior::Solid {
+                    BoundaryCrossing::Stopped(clamped)
+                } else {
+                    BoundaryCrossing::Reflected {
+                        position: clamped,
+                        normal,
+                    }
+                }
+            },
+        }
+    }
+
+    /// Finds where a ray from `origin` along `direction` exits the boundary cuboid - the forward
+    /// exit half of [`Self::intersect_ray`]. Returns `None` when the ray misses the cuboid entirely,
+    /// or exits entirely behind it (`exit < 0.0`).
+    ///
+    /// Unlike [`Self::calculate_teleport_position`], which wraps each axis independently, this finds
+    /// the single true exit point and face, so a fast body moving diagonally wraps through the corner
+    /// it actually crosses instead of reappearing at the wrong one.
+    pub fn ray_boundary_exit(&self, origin: Vec3, direction: Dir3) -> Option<(Vec3, Dir3)> {
+        let intersection = self.intersect_ray(origin, direction.as_vec3())?;
+        (intersection.exit >= 0.0).then_some((intersection.exit_point, intersection.exit_normal))
+    }
+
+    /// Swept boundary crossing for one frame's motion: same slab method as
+    /// [`Self::ray_boundary_exit`], but bounded to `velocity_delta` (the actual displacement this
+    /// frame) rather than an infinite ray, so a fast body that would tunnel clean through the
+    /// boundary in one step is still caught at its true exit point and face, with `t` (in `[0,
+    /// 1]`) giving the fraction of `velocity_delta` already spent reaching it - the caller re-enters
+    /// on the opposite face carrying the remaining `(1.0 - t)` of motion. Returns `None` when
+    /// `origin..=origin + velocity_delta` never reaches the boundary this frame.
+    pub fn crossing(&self, origin: Vec3, velocity_delta: Vec3) -> Option<(Vec3, BoundaryFace, f32)> {
+        let half_size = self.transform.scale / 2.0;
+        let boundary_min = -half_size;
+        let boundary_max = half_size;
+        let origin = self.to_local(origin);
+        let velocity_delta = self.to_local_dir(velocity_delta);
+
+        let axes = [
+            (
+                origin.x,
+                velocity_delta.x,
+                boundary_min.x,
+                boundary_max.x,
+                BoundaryFace::Left,
+                BoundaryFace::Right,
+            ),
+            (
+                origin.y,
+                velocity_delta.y,
+                boundary_min.y,
+                boundary_max.y,
+                BoundaryFace::Bottom,
+                BoundaryFace::Top,
+            ),
+            (
+                origin.z,
+                velocity_delta.z,
+                boundary_min.z,
+                boundary_max.z,
+                BoundaryFace::Back,
+                BoundaryFace::Front,
+            ),
+        ];
+
+        let mut t_near = f32::NEG_INFINITY;
+        let mut t_far = f32::INFINITY;
+        let mut exit_face = BoundaryFace::Right;
+
+        for (origin_axis, d, min, max, min_face, max_face) in axes {
+            if d.abs() < f32::EPSILON {
+                if origin_axis < min || origin_axis > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let t1 = (min - origin_axis) / d;
+            let t2 = (max - origin_axis) / d;
+
+            let (axis_near, axis_far, far_face) = if t1 <= t2 {
+                (t1, t2, max_face)
+            } else {
+                (t2, t1, min_face)
+            };
+
+            t_near = t_near.max(axis_near);
+            if axis_far < t_far {
+                t_far = axis_far;
+                exit_face = far_face;
+            }
+        }
+
+        if t_near > t_far || !(0.0..=1.0).contains(&t_far) {
+            return None;
+        }
+
+        Some((self.to_world(origin + velocity_delta * t_far), exit_face, t_far))
     }
 
     /// Snaps a position to slightly inside the boundary face based on the normal.
     /// Offsets by epsilon to prevent false-positive overextension detection that would trigger
     /// corner wrapping arcs. Clamps perpendicular axes to handle corner/edge teleportation cases.
+    ///
+    /// `normal` is a world-space direction (as returned by [`Self::get_normal_for_position`]), so
+    /// it's rotated into local space first to find which local axis it's snapping along - an exact
+    /// match against `Dir3::X`/etc, as this used to do, only works for an axis-aligned boundary.
     pub fn snap_position_to_boundary_face(&self, position: Vec3, normal: Dir3) -> Vec3 {
-        let boundary_min = self.transform.translation - self.transform.scale / 2.0;
-        let boundary_max = self.transform.translation + self.transform.scale / 2.0;
+        let half_size = self.transform.scale / 2.0;
+        let boundary_min = -half_size;
+        let boundary_max = half_size;
 
         // Without this offset, portals on exact boundary would be flagged as overextended
         let epsilon = BOUNDARY_SNAP_EPSILON;
 
-        let mut snapped_position = position;
+        let local_normal = self.to_local_dir(normal.as_vec3());
+        let mut snapped_position = self.to_local(position).clamp(boundary_min, boundary_max);
 
-        // Set primary axis slightly inside boundary face and clamp perpendicular axes
-        match normal {
-            Dir3::X => {
-                snapped_position.x = boundary_max.x - epsilon;
-                snapped_position.y = snapped_position.y.clamp(boundary_min.y, boundary_max.y);
-                snapped_position.z = snapped_position.z.clamp(boundary_min.z, boundary_max.z);
-            },
-            Dir3::NEG_X => {
-                snapped_position.x = boundary_min.x + epsilon;
-                snapped_position.y = snapped_position.y.clamp(boundary_min.y, boundary_max.y);
-                snapped_position.z = snapped_position.z.clamp(boundary_min.z, boundary_max.z);
-            },
-            Dir3::Y => {
-                snapped_position.y = boundary_max.y - epsilon;
-                snapped_position.x = snapped_position.x.clamp(boundary_min.x, boundary_max.x);
-                snapped_position.z = snapped_position.z.clamp(boundary_min.z, boundary_max.z);
-            },
-            Dir3::NEG_Y => {
-                snapped_position.y = boundary_min.y + epsilon;
-                snapped_position.x = snapped_position.x.clamp(boundary_min.x, boundary_max.x);
-                snapped_position.z = snapped_position.z.clamp(boundary_min.z, boundary_max.z);
-            },
-            Dir3::Z => {
-                snapped_position.z = boundary_max.z - epsilon;
-                snapped_position.x = snapped_position.x.clamp(boundary_min.x, boundary_max.x);
-                snapped_position.y = snapped_position.y.clamp(boundary_min.y, boundary_max.y);
-            },
-            Dir3::NEG_Z => {
-                snapped_position.z = boundary_min.z + epsilon;
-                snapped_position.x = snapped_position.x.clamp(boundary_min.x, boundary_max.x);
-                snapped_position.y = snapped_position.y.clamp(boundary_min.y, boundary_max.y);
-            },
-            _ => {},
-        }
+        let axis = [local_normal.x, local_normal.y, local_normal.z]
+            .into_iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.abs().total_cmp(b))
+            .map(|(axis, _)| axis)
+            .expect("three axes to compare");
 
-        snapped_position
+        snapped_position[axis] = if local_normal[axis] >= 0.0 {
+            boundary_max[axis] - epsilon
+        } else {
+            boundary_min[axis] + epsilon
+        };
+
+        self.to_world(snapped_position)
     }
 
     /// Calculates how many faces a portal spans at a given position
@@ -252,15 +492,10 @@ impl Boundary {
         }
     }
 
-    /// Counts how many faces have valid arc intersections for a multi-face portal
-    fn count_faces_with_valid_arcs(&self, portal: &Portal, multiface: &MultiFaceGeometry) -> usize {
-        // Calculate boundary extents for constraint checking
-        let half_size = self.transform.scale / 2.0;
-        let min = self.transform.translation - half_size;
-        let max = self.transform.translation + half_size;
-
-        // Collect all faces from the geometry
-        let all_faces_in_corner = match multiface {
+    /// Collects the primary face plus every overextended face covered by `multiface`, in the
+    /// order used to build each face's clipped arc.
+    fn faces_in_geometry(multiface: &MultiFaceGeometry) -> Vec<BoundaryFace> {
+        match multiface {
             MultiFaceGeometry::Edge {
                 primary,
                 overextended,
@@ -273,30 +508,31 @@ impl Boundary {
                 faces.extend(overextended);
                 faces
             },
-        };
+        }
+    }
 
-        let mut face_count = 0;
+    /// Counts how many faces have a visible clipped arc for a multi-face portal
+    fn count_faces_with_valid_arcs(&self, portal: &Portal, multiface: &MultiFaceGeometry) -> usize {
+        let half_size = self.transform.scale / 2.0;
+        let min = self.transform.translation - half_size;
+        let max = self.transform.translation + half_size;
 
-        // Calculate constrained intersections for each face
-        for &face in &all_faces_in_corner {
-            let face_points = face.get_face_points(&min, &max);
-            let raw_intersections = intersect_portal_with_rectangle(portal, &face_points);
-
-            // Apply constraints: filter out points that extend beyond face boundaries
-            let constrained_points = constrain_intersection_points(
-                raw_intersections,
-                face,
-                &all_faces_in_corner,
-                &min,
-                &max,
-            );
+        let disc = tessellate_portal_disc(portal, PORTAL_FACE_COUNT_RESOLUTION);
 
-            if constrained_points.len() >= MIN_POINTS_FOR_ARC {
-                face_count += 1;
-            }
-        }
+        Self::faces_in_geometry(multiface)
+            .into_iter()
+            .filter(|&face| {
+                let face_points = face.get_face_points(&min, &max);
+                clip_polygon_to_face_rectangle(&disc, &face_points).len() >= MIN_POINTS_FOR_ARC
+            })
+            .count()
+    }
 
-        face_count
+    /// Classifies a portal's world-space bounding box (center `portal.position`, half-extent
+    /// `portal.radius` on each axis) against `frustum` so callers can skip [`Boundary::draw_portal`]
+    /// entirely for portals that are fully outside the camera's view.
+    pub fn portal_frustum_test(&self, portal: &Portal, frustum: &FrustumPlanes) -> FrustumTest {
+        frustum.test_aabb(portal.position, Vec3::splat(portal.radius))
     }
 
     pub fn draw_portal(
@@ -308,6 +544,11 @@ impl Boundary {
         orientation: &CameraOrientation,
         is_deaderoid: bool,
     ) {
+        // A non-Wrap face has no opposite side to wrap to, so there's no portal to draw.
+        if self.face_behaviors.get(portal.face) != BoundaryFaceBehavior::Wrap {
+            return;
+        }
+
         let geometry = self.classify_portal_geometry(portal);
         self.render_portal_by_geometry(
             gizmos,
@@ -332,29 +573,58 @@ impl Boundary {
     ) {
         match geometry {
             PortalGeometry::SingleFace => {
-                // Draw full circle
-                let rotation = Quat::from_rotation_arc(
-                    orientation.config.axis_profundus,
-                    portal.normal().as_vec3(),
-                );
-                let isometry = Isometry3d::new(portal.position, rotation);
-                gizmos
-                    .circle(isometry, portal.radius, color)
-                    .resolution(resolution);
+                self.draw_full_circle(gizmos, portal, color, resolution, orientation);
             },
             PortalGeometry::MultiFace(multiface) => {
-                self.draw_multiface_portal(
-                    gizmos,
-                    portal,
-                    color,
-                    resolution,
-                    is_deaderoid,
-                    multiface,
-                );
+                let fit_to_face = if is_deaderoid {
+                    self.fit_portal_to_face_deaderoid
+                } else {
+                    self.fit_portal_to_face_player
+                };
+
+                match fit_to_face.then(|| self.fit_portal_to_face(portal)).flatten() {
+                    Some(fitted) => {
+                        self.draw_full_circle(gizmos, &fitted, color, resolution, orientation);
+                    },
+                    None => {
+                        self.draw_multiface_portal(
+                            gizmos,
+                            portal,
+                            color,
+                            resolution,
+                            is_deaderoid,
+                            multiface,
+                        );
+                    },
+                }
             },
         }
     }
 
+    /// Draws a portal disc as a full, unclipped circle - used both for single-face portals and
+    /// for a multi-face portal that [`Self::fit_portal_to_face`] has bumped back onto one face.
+    fn draw_full_circle(
+        &self,
+        gizmos: &mut Gizmos<PortalGizmo>,
+        portal: &Portal,
+        color: Color,
+        resolution: u32,
+        orientation: &CameraOrientation,
+    ) {
+        let rotation = Quat::from_rotation_arc(
+            orientation.config.axis_profundus,
+            portal.normal().as_vec3(),
+        );
+        let isometry = Isometry3d::new(portal.position, rotation);
+        gizmos
+            .circle(isometry, portal.radius, color)
+            .resolution(resolution);
+    }
+
+    /// Renders a multi-face portal as one clipped polyline per covered face: the portal disc is
+    /// tessellated once into an N-gon in its own plane, then Sutherland–Hodgman-clipped against
+    /// each face's bounding rectangle, so every face gets the exact sweep actually visible on it
+    /// and adjoining faces' arcs join seamlessly at the shared edge/corner.
     fn draw_multiface_portal(
         &self,
         gizmos: &mut Gizmos<PortalGizmo>,
@@ -364,51 +634,26 @@ impl Boundary {
         is_deaderoid: bool,
         geometry: &MultiFaceGeometry,
     ) {
-        // Extract primary face and overextended faces from geometry
-        let (primary_face, overextended_faces) = match geometry {
-            MultiFaceGeometry::Edge {
-                primary,
-                overextended,
-            } => (*primary, vec![*overextended]),
-            MultiFaceGeometry::Corner {
-                primary,
-                overextended,
-            } => (*primary, overextended.clone()),
-        };
-
-        // Calculate boundary extents for constraint checking
         let half_size = self.transform.scale / 2.0;
         let min = self.transform.translation - half_size;
         let max = self.transform.translation + half_size;
 
-        // Collect ALL faces that need arcs (primary + overextended)
-        let mut all_faces_in_corner = vec![primary_face];
-        all_faces_in_corner.extend(overextended_faces.iter());
+        let disc = tessellate_portal_disc(portal, resolution);
 
-        let mut face_arcs = Vec::new();
+        for face in Self::faces_in_geometry(geometry) {
+            // Same rationale as draw_portal's top-level check: a non-Wrap face has no opposite
+            // side, so it contributes no arc even when it only shares the portal via overextension.
+            if self.face_behaviors.get(face) != BoundaryFaceBehavior::Wrap {
+                continue;
+            }
 
-        // Calculate constrained intersections for each face
-        for &face in &all_faces_in_corner {
             let face_points = face.get_face_points(&min, &max);
-            let raw_intersections = intersect_portal_with_rectangle(portal, &face_points);
-
-            // Apply constraints: filter out points that extend beyond face boundaries
-            // Pass ALL faces so each face can check against all others
-            let constrained_points = constrain_intersection_points(
-                raw_intersections,
-                face,
-                &all_faces_in_corner,
-                &min,
-                &max,
-            );
+            let clipped = clip_polygon_to_face_rectangle(&disc, &face_points);
 
-            if constrained_points.len() >= MIN_POINTS_FOR_ARC {
-                face_arcs.push((face, constrained_points));
+            if clipped.len() < MIN_POINTS_FOR_ARC {
+                continue;
             }
-        }
 
-        // Draw all arcs
-        for (face, points) in face_arcs {
             // Apply face color-coding only for deaderoid portals
             let face_color = if is_deaderoid {
                 match geometry {
@@ -426,172 +671,10 @@ impl Boundary {
                 color // Non-deaderoid portals: always use the provided color
             };
 
-            // Only use draw_arc_with_center_and_normal for edge primary faces, notorners
-            match geometry {
-                MultiFaceGeometry::Edge { .. } if face == primary_face => {
-                    // Primary face at edge uses the complex arc logic with TAU - angle inversion
-                    self.draw_arc_with_center_and_normal(
-                        gizmos,
-                        portal.position,
-                        portal.radius,
-                        portal.normal().as_vec3(),
-                        face_color,
-                        resolution,
-                        points[0],
-                        points[1],
-                    );
-                },
-                MultiFaceGeometry::Edge { .. } => {
-                    // Edge overextended faces
-                    let center = self.rotate_portal_center_to_target_face(
-                        portal.position,
-                        portal.normal(),
-                        face,
-                    );
-                    gizmos
-                        .short_arc_3d_between(center, points[0], points[1], face_color)
-                        .resolution(resolution);
-                },
-                MultiFaceGeometry::Corner { .. } => {
-                    // For ALL corner faces (including primary)
-                    gizmos
-                        .short_arc_3d_between(portal.position, points[0], points[1], face_color)
-                        .resolution(resolution);
-                },
-            }
+            gizmos.linestrip(clipped, face_color);
         }
     }
 
-    // when we rotate this to the target face we get a new center
-    // for the arc that is drawn outside the boundary
-    // wrapped to a point that provide a center that gives
-    // the illusion of having the circle wrap around the edge
-    fn rotate_portal_center_to_target_face(
-        &self,
-        position: Vec3,
-        normal: Dir3,
-        target_face: BoundaryFace,
-    ) -> Vec3 {
-        let current_normal = normal.as_vec3();
-        let target_normal = target_face.get_normal();
-
-        // The rotation axis is the cross product of the current and target normals
-        let rotation_axis = current_normal.cross(target_normal).normalize();
-
-        // Find the closest point on the rotation axis to the current position
-        let rotation_point =
-            self.find_closest_point_on_edge(position, current_normal, target_normal);
-
-        // Create a rotation quaternion (90 degrees around the rotation axis)
-        let rotation = Quat::from_axis_angle(rotation_axis, std::f32::consts::FRAC_PI_2);
-
-        // Apply the rotation to the position relative to the rotation point
-        let relative_pos = position - rotation_point;
-        let rotated_pos = rotation * relative_pos;
-
-        let mut result = rotation_point + rotated_pos;
-
-        // Rotation math at corners can produce off-plane positions - force result onto target
-        // face's plane
-        let half_extents = self.transform.scale / 2.0;
-        let center = self.transform.translation;
-
-        match target_face {
-            BoundaryFace::Right => result.x = center.x + half_extents.x,
-            BoundaryFace::Left => result.x = center.x - half_extents.x,
-            BoundaryFace::Top => result.y = center.y + half_extents.y,
-            BoundaryFace::Bottom => result.y = center.y - half_extents.y,
-            BoundaryFace::Front => result.z = center.z + half_extents.z,
-            BoundaryFace::Back => result.z = center.z - half_extents.z,
-        }
-
-        result
-    }
-
-    fn find_closest_point_on_edge(&self, position: Vec3, normal1: Vec3, normal2: Vec3) -> Vec3 {
-        let half = self.transform.scale / 2.0;
-        let center = self.transform.translation;
-        let min = center - half;
-        let max = center + half;
-
-        // For axis-aligned cuboid, the edge between two faces runs along one axis
-        // with the other two coordinates fixed at the boundary planes.
-        // For each axis: if either normal points along it, fix at that boundary;
-        // otherwise the edge runs along that axis, so use position's coordinate.
-
-        let x = if normal1.x != 0.0 {
-            if normal1.x > 0.0 { max.x } else { min.x }
-        } else if normal2.x != 0.0 {
-            if normal2.x > 0.0 { max.x } else { min.x }
-        } else {
-            position.x // Edge runs along X axis
-        };
-
-        let y = if normal1.y != 0.0 {
-            if normal1.y > 0.0 { max.y } else { min.y }
-        } else if normal2.y != 0.0 {
-            if normal2.y > 0.0 { max.y } else { min.y }
-        } else {
-            position.y // Edge runs along Y axis
-        };
-
-        let z = if normal1.z != 0.0 {
-            if normal1.z > 0.0 { max.z } else { min.z }
-        } else if normal2.z != 0.0 {
-            if normal2.z > 0.0 { max.z } else { min.z }
-        } else {
-            position.z // Edge runs along Z axis
-        };
-
-        Vec3::new(x, y, z)
-    }
-
-    // Helper function to draw an arc with explicit center, radius, and normal
-    // Used for primary face arcs - inverts the angle for proper rendering
-    fn draw_arc_with_center_and_normal(
-        &self,
-        gizmos: &mut Gizmos<PortalGizmo>,
-        center: Vec3,
-        radius: f32,
-        normal: Vec3,
-        color: Color,
-        resolution: u32,
-        from: Vec3,
-        to: Vec3,
-    ) {
-        // Calculate vectors from center to intersection points
-        let vec_from = (from - center).normalize();
-        let vec_to = (to - center).normalize();
-
-        // Calculate the angle and determine direction
-        let mut angle = vec_from.angle_between(vec_to);
-        let cross_product = vec_from.cross(vec_to);
-        let is_clockwise = cross_product.dot(normal) < 0.0;
-
-        // Invert the angle for arc_3d rendering logic
-        angle = std::f32::consts::TAU - angle;
-
-        // Calculate the rotation to align the arc with the boundary face
-        let face_rotation = Quat::from_rotation_arc(Vec3::Y, normal);
-
-        // Determine the start vector based on clockwise/counterclockwise
-        let start_vec = if is_clockwise { vec_from } else { vec_to };
-        let start_rotation = Quat::from_rotation_arc(face_rotation * Vec3::X, start_vec);
-
-        // Combine rotations
-        let final_rotation = start_rotation * face_rotation;
-
-        // Draw the arc
-        gizmos
-            .arc_3d(
-                angle,
-                radius,
-                Isometry3d::new(center, final_rotation),
-                color,
-            )
-            .resolution(resolution);
-    }
-
     fn get_overextended_faces_for(&self, portal: &Portal) -> Vec<BoundaryFace> {
         let mut overextended_faces = Vec::new();
         let half_size = self.transform.scale / 2.0;
@@ -627,80 +710,157 @@ impl Boundary {
         overextended_faces.retain(|&face| face != portal.face);
         overextended_faces
     }
-    /// Returns the normal of the closest boundary face to a position.
-    /// Uses distance-based matching because teleported positions have offsets (e.g., -54.97 instead
-    /// of -55.0) that break simple epsilon matching.
-    pub fn get_normal_for_position(&self, position: Vec3) -> Dir3 {
+
+    /// Alternative to corner-wrapping ([`Self::draw_multiface_portal`]): shifts an overextended
+    /// portal inward along its own face's plane until the whole disc lands on its primary face,
+    /// mirroring Source-style portal placement. Sums the per-axis overextension offsets (how far
+    /// `position ± radius` exceeds that axis's `min`/`max`) and translates `position` inward by
+    /// that total, capped at `portal.radius` - beyond that the disc genuinely cannot fit on a
+    /// single face, so the caller should fall back to the wrap-around-corners rendering instead.
+    pub fn fit_portal_to_face(&self, portal: &Portal) -> Option<Portal> {
         let half_size = self.transform.scale / 2.0;
-        let boundary_min = self.transform.translation - half_size;
-        let boundary_max = self.transform.translation + half_size;
-
-        // Calculate distance to all 6 faces and return normal of closest
-        let dist_to_min_x = (position.x - boundary_min.x).abs();
-        let dist_to_max_x = (position.x - boundary_max.x).abs();
-        let dist_to_min_y = (position.y - boundary_min.y).abs();
-        let dist_to_max_y = (position.y - boundary_max.y).abs();
-        let dist_to_min_z = (position.z - boundary_min.z).abs();
-        let dist_to_max_z = (position.z - boundary_max.z).abs();
-
-        let min_dist = dist_to_min_x
-            .min(dist_to_max_x)
-            .min(dist_to_min_y)
-            .min(dist_to_max_y)
-            .min(dist_to_min_z)
-            .min(dist_to_max_z);
-
-        if (dist_to_min_x - min_dist).abs() < 0.001 {
-            Dir3::NEG_X
-        } else if (dist_to_max_x - min_dist).abs() < 0.001 {
-            Dir3::X
-        } else if (dist_to_min_y - min_dist).abs() < 0.001 {
-            Dir3::NEG_Y
-        } else if (dist_to_max_y - min_dist).abs() < 0.001 {
-            Dir3::Y
-        } else if (dist_to_min_z - min_dist).abs() < 0.001 {
-            Dir3::NEG_Z
-        } else if (dist_to_max_z - min_dist).abs() < 0.001 {
-            Dir3::Z
-        } else {
-            // Fallback to Y
-            Dir3::Y
+        let min = self.transform.translation - half_size;
+        let max = self.transform.translation + half_size;
+        let radius = portal.radius;
+
+        let overextended_faces = self.get_overextended_faces_for(portal);
+        if overextended_faces.is_empty() {
+            return Some(portal.clone());
+        }
+
+        let mut bumped_position = portal.position;
+        let mut total_bump = 0.0;
+
+        for face in overextended_faces {
+            let offset = match face {
+                BoundaryFace::Left => (min.x - (portal.position.x - radius)).max(0.0),
+                BoundaryFace::Right => ((portal.position.x + radius) - max.x).max(0.0),
+                BoundaryFace::Bottom => (min.y - (portal.position.y - radius)).max(0.0),
+                BoundaryFace::Top => ((portal.position.y + radius) - max.y).max(0.0),
+                BoundaryFace::Back => (min.z - (portal.position.z - radius)).max(0.0),
+                BoundaryFace::Front => ((portal.position.z + radius) - max.z).max(0.0),
+            };
+
+            total_bump += offset;
+            bumped_position -= face.get_normal() * offset;
         }
+
+        if total_bump > radius {
+            return None;
+        }
+
+        let mut fitted = portal.clone();
+        fitted.position = bumped_position;
+        Some(fitted)
     }
+    /// Returns the normal of the boundary face closest to a position, by finding the axis where
+    /// `position` sits proportionally closest to that axis's half-extent (rather than comparing
+    /// raw, unnormalized distances across axes of potentially different sizes). Exact - no
+    /// epsilon tuning or fallback case needed, unlike the old closest-of-six-raw-distances scheme.
+    ///
+    /// The dominant axis is found in the boundary's local frame, then rotated back out, so the
+    /// returned normal is always world-space even when `transform.rotation` isn't identity.
+    pub fn get_normal_for_position(&self, position: Vec3) -> Dir3 {
+        let half_size = self.transform.scale / 2.0;
+        let normalized = self.to_local(position) / half_size;
 
-    pub fn find_edge_point(&self, origin: Vec3, direction: Vec3) -> Option<Vec3> {
-        let boundary_min = self.transform.translation - self.transform.scale / 2.0;
-        let boundary_max = self.transform.translation + self.transform.scale / 2.0;
-
-        let mut t_min = f32::MAX;
-
-        for (start, dir, pos_bound, neg_bound) in [
-            (origin.x, direction.x, boundary_max.x, boundary_min.x),
-            (origin.y, direction.y, boundary_max.y, boundary_min.y),
-            (origin.z, direction.z, boundary_max.z, boundary_min.z),
-        ] {
-            if dir != 0.0 {
-                let mut update_t_min = |boundary: f32| {
-                    let t = (boundary - start) / dir;
-                    let point = origin + direction * t;
-                    if t > 0.0
-                        && t < t_min
-                        && is_in_bounds(point, start, origin, boundary_min, boundary_max)
-                    {
-                        t_min = t;
-                    }
-                };
+        let axes = [
+            (normalized.x, Dir3::X, Dir3::NEG_X),
+            (normalized.y, Dir3::Y, Dir3::NEG_Y),
+            (normalized.z, Dir3::Z, Dir3::NEG_Z),
+        ];
+
+        let local_normal = axes
+            .into_iter()
+            .max_by(|(a, ..), (b, ..)| a.abs().total_cmp(&b.abs()))
+            .map(|(signed, positive, negative)| if signed >= 0.0 { positive } else { negative })
+            .unwrap_or(Dir3::Y);
+
+        Dir3::new(self.to_world_dir(local_normal.as_vec3())).unwrap_or(local_normal)
+    }
+
+    /// Full ray-box intersection against the boundary cuboid via the slab method: per axis,
+    /// `t_lo`/`t_hi` are the candidate `t` values against that axis's min/max planes, ordered into
+    /// a near/far pair with the normal of whichever plane produced it. `enter` is the largest near
+    /// value across axes (and its normal), `exit` the smallest far value (and its normal). Unlike
+    /// [`Self::ray_boundary_exit`]/[`Self::crossing`], which only report the forward exit, this
+    /// returns both ends of the segment the ray spends inside the box - the single primitive
+    /// callers needing entry *and* exit (missile prediction, camera framing) can share instead of
+    /// each re-deriving the per-axis loop. Returns `None` when the ray misses the box
+    /// (`enter > exit`). `origin`/`dir` are transformed into the boundary's local frame before the
+    /// slab test so a rotated `transform` is honored; the result's points and normals are rotated
+    /// back out to world space.
+    pub fn intersect_ray(&self, origin: Vec3, dir: Vec3) -> Option<BoundaryIntersection> {
+        let boundary_min = -self.transform.scale / 2.0;
+        let boundary_max = self.transform.scale / 2.0;
+        let origin = self.to_local(origin);
+        let dir = self.to_local_dir(dir);
+
+        let axes = [
+            (origin.x, dir.x, boundary_min.x, boundary_max.x, Dir3::NEG_X, Dir3::X),
+            (origin.y, dir.y, boundary_min.y, boundary_max.y, Dir3::NEG_Y, Dir3::Y),
+            (origin.z, dir.z, boundary_min.z, boundary_max.z, Dir3::NEG_Z, Dir3::Z),
+        ];
+
+        let mut enter = f32::NEG_INFINITY;
+        let mut exit = f32::INFINITY;
+        let mut enter_normal = Dir3::X;
+        let mut exit_normal = Dir3::X;
 
-                update_t_min(pos_bound);
-                update_t_min(neg_bound);
+        for (origin_axis, d, min, max, min_normal, max_normal) in axes {
+            if d.abs() < f32::EPSILON {
+                if origin_axis < min || origin_axis > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let t_lo = (min - origin_axis) / d;
+            let t_hi = (max - origin_axis) / d;
+
+            let (axis_near, near_normal, axis_far, far_normal) = if t_lo <= t_hi {
+                (t_lo, min_normal, t_hi, max_normal)
+            } else {
+                (t_hi, max_normal, t_lo, min_normal)
+            };
+
+            if axis_near > enter {
+                enter = axis_near;
+                enter_normal = near_normal;
+            }
+            if axis_far < exit {
+                exit = axis_far;
+                exit_normal = far_normal;
             }
         }
 
-        if t_min != f32::MAX {
-            let edge_point = origin + direction * t_min;
-            return Some(edge_point);
+        if enter > exit {
+            return None;
+        }
+
+        Some(BoundaryIntersection {
+            enter,
+            exit,
+            enter_point: self.to_world(origin + dir * enter),
+            exit_point: self.to_world(origin + dir * exit),
+            enter_normal: Dir3::new(self.to_world_dir(enter_normal.as_vec3())).unwrap_or(enter_normal),
+            exit_normal: Dir3::new(self.to_world_dir(exit_normal.as_vec3())).unwrap_or(exit_normal),
+        })
+    }
+
+    /// The first forward (`t >= 0`) hit of a ray against the boundary, built on
+    /// [`Self::intersect_ray`]: `enter` when the ray starts outside the box, `exit` when it starts
+    /// inside (the common case - an actor searching for the wall ahead of it).
+    pub fn find_edge_point(&self, origin: Vec3, direction: Vec3) -> Option<Vec3> {
+        let intersection = self.intersect_ray(origin, direction)?;
+
+        if intersection.enter >= 0.0 {
+            Some(intersection.enter_point)
+        } else if intersection.exit >= 0.0 {
+            Some(intersection.exit_point)
+        } else {
+            None
         }
-        None
     }
 
     pub fn longest_diagonal(&self) -> f32 {
@@ -715,48 +875,45 @@ impl Boundary {
 
     pub fn scale(&self) -> Vec3 { self.boundary_scalar * self.cell_count.as_vec3() }
 
-    /// Returns the 8 corner points of the boundary as a fixed-size array
+    /// The center point of a boundary face, in world space.
+    pub fn face_center(&self, face: BoundaryFace) -> Vec3 {
+        self.to_world((self.transform.scale / 2.0) * face.get_normal())
+    }
+
+    /// Signed distance from `position` to `face`'s plane, measured along that face's outward
+    /// normal - positive while `position` is still inside the boundary, crossing zero exactly as
+    /// it passes the face. `WraparoundGhostPlugin` compares this against an actor's collider
+    /// radius to decide which face(s) it's currently straddling.
+    pub fn distance_to_face(&self, position: Vec3, face: BoundaryFace) -> f32 {
+        let half_extent = self.transform.scale / 2.0;
+        let local = self.to_local(position);
+        half_extent.dot(face.get_normal().abs()) - local.dot(face.get_normal())
+    }
+
+    /// Where a ghost duplicate of `position` belongs so it appears to protrude from the wall
+    /// opposite `face` - the same full-extent offset `calculate_teleport_position` applies on an
+    /// actual wrap, just without waiting for `position` to actually cross over first.
+    pub fn wraparound_ghost_position(&self, position: Vec3, face: BoundaryFace) -> Vec3 {
+        let local = self.to_local(position);
+        self.to_world(local - face.get_normal() * self.transform.scale)
+    }
+
+    /// Returns the 8 corner points of the boundary, in world space, as a fixed-size array.
     pub fn corners(&self) -> [Vec3; 8] {
-        let grid_size = self.scale();
-        let half_size = grid_size / 2.0;
+        let half_size = self.scale() / 2.0;
         [
-            Vec3::new(-half_size.x, -half_size.y, -half_size.z),
-            Vec3::new(half_size.x, -half_size.y, -half_size.z),
-            Vec3::new(-half_size.x, half_size.y, -half_size.z),
-            Vec3::new(half_size.x, half_size.y, -half_size.z),
-            Vec3::new(-half_size.x, -half_size.y, half_size.z),
-            Vec3::new(half_size.x, -half_size.y, half_size.z),
-            Vec3::new(-half_size.x, half_size.y, half_size.z),
-            Vec3::new(half_size.x, half_size.y, half_size.z),
+            self.to_world(Vec3::new(-half_size.x, -half_size.y, -half_size.z)),
+            self.to_world(Vec3::new(half_size.x, -half_size.y, -half_size.z)),
+            self.to_world(Vec3::new(-half_size.x, half_size.y, -half_size.z)),
+            self.to_world(Vec3::new(half_size.x, half_size.y, -half_size.z)),
+            self.to_world(Vec3::new(-half_size.x, -half_size.y, half_size.z)),
+            self.to_world(Vec3::new(half_size.x, -half_size.y, half_size.z)),
+            self.to_world(Vec3::new(-half_size.x, half_size.y, half_size.z)),
+            self.to_world(Vec3::new(half_size.x, half_size.y, half_size.z)),
         ]
     }
 }
 
-fn is_in_bounds(
-    point: Vec3,
-    start: f32,
-    origin: Vec3,
-    boundary_min: Vec3,
-    boundary_max: Vec3,
-) -> bool {
-    if (start - origin.x).abs() < BOUNDARY_SNAP_EPSILON {
-        point.y >= boundary_min.y
-            && point.y <= boundary_max.y
-            && point.z >= boundary_min.z
-            && point.z <= boundary_max.z
-    } else if (start - origin.y).abs() < BOUNDARY_SNAP_EPSILON {
-        point.x >= boundary_min.x
-            && point.x <= boundary_max.x
-            && point.z >= boundary_min.z
-            && point.z <= boundary_max.z
-    } else {
-        point.x >= boundary_min.x
-            && point.x <= boundary_max.x
-            && point.y >= boundary_min.y
-            && point.y <= boundary_max.y
-    }
-}
-
 /// draw the grid and then slightly outside the grid, draw the boundary around the whole grid
 /// transform
 fn draw_boundary(
@@ -771,9 +928,24 @@ fn draw_boundary(
     // so the fixed camera can be positioned based on the boundary scale
     boundary.transform.scale = boundary.scale();
 
+    let camera = camera_query.single().ok();
+
+    // Skip the whole grid/outer-cuboid gizmo submission when the boundary's AABB (from its
+    // world-space corners) is entirely outside the camera's view frustum - cheap for a single
+    // cell, but the win grows with cell_count since grid_3d submits one line per cell edge.
+    if let Some((_, projection, camera_transform)) = camera {
+        let frustum = FrustumPlanes::from_camera(projection, camera_transform);
+        let corners = boundary.corners();
+        let min = corners.into_iter().fold(Vec3::INFINITY, Vec3::min);
+        let max = corners.into_iter().fold(Vec3::NEG_INFINITY, Vec3::max);
+        if frustum.test_aabb((min + max) / 2.0, (max - min) / 2.0) == FrustumTest::Out {
+            return;
+        }
+    }
+
     grid_gizmo
         .grid_3d(
-            Isometry3d::new(boundary.transform.translation, Quat::IDENTITY),
+            Isometry3d::new(boundary.transform.translation, boundary.transform.rotation),
             boundary.cell_count,
             Vec3::splat(boundary.boundary_scalar),
             boundary.grid_color,
@@ -781,8 +953,8 @@ fn draw_boundary(
         .outer_edges();
 
     // Calculate world-space offset based on camera projection
-    let Ok((camera, projection, camera_transform)) = camera_query.single() else {
-        return; // No camera yet, skip gizmo rendering this frame
+    let Some((camera, projection, camera_transform)) = camera else {
+        return; // No camera yet, skip the perspective-dependent outer boundary sizing below
     };
     let Projection::Perspective(perspective) = projection else {
         return; // Not perspective camera, skip
@@ -805,152 +977,89 @@ fn draw_boundary(
 
     outer_boundary_gizmo.primitive_3d(
         &Cuboid::from_size(outer_scale),
-        Isometry3d::new(boundary.transform.translation, Quat::IDENTITY),
+        Isometry3d::new(boundary.transform.translation, boundary.transform.rotation),
         boundary.outer_color,
     );
 }
 
-pub fn intersect_portal_with_rectangle(portal: &Portal, rectangle_points: &[Vec3; 4]) -> Vec<Vec3> {
-    let mut intersections = Vec::new();
-
-    for i in 0..4 {
-        let start = rectangle_points[i];
-        let end = rectangle_points[(i + 1) % 4];
-
-        let edge_intersections = intersect_circle_with_line_segment(portal, start, end);
-        intersections.extend(edge_intersections);
-    }
-
-    intersections
+/// Tessellates the portal disc into an N-gon of `resolution` vertices lying in the portal's own
+/// plane - the subject polygon for [`clip_polygon_to_face_rectangle`].
+fn tessellate_portal_disc(portal: &Portal, resolution: u32) -> Vec<Vec3> {
+    let normal = portal.normal().as_vec3();
+    let tangent = normal.any_orthonormal_vector();
+    let bitangent = normal.cross(tangent);
+    let resolution = resolution.max(3);
+
+    (0..resolution)
+        .map(|i| {
+            let angle = (i as f32 / resolution as f32) * std::f32::consts::TAU;
+            portal.position + (tangent * angle.cos() + bitangent * angle.sin()) * portal.radius
+        })
+        .collect()
 }
 
-fn intersect_circle_with_line_segment(portal: &Portal, start: Vec3, end: Vec3) -> Vec<Vec3> {
-    let edge = end - start;
-    let center_to_start = start - portal.position;
-
-    let a = edge.dot(edge);
-    let b = 2.0 * center_to_start.dot(edge);
-    let c = center_to_start.dot(center_to_start) - portal.radius * portal.radius;
-
-    let discriminant = b * b - 4.0 * a * c;
-
-    if discriminant < 0.0 {
-        return vec![];
+/// One Sutherland–Hodgman clip pass against the half-space `{v : dot(v, plane_normal) -
+/// plane_d >= 0}`, where `plane_d = dot(plane_point, plane_normal)`: walks `polygon`'s edges,
+/// keeping vertices on the positive side and inserting the interpolated point wherever the signed
+/// distance changes sign.
+fn clip_polygon_against_plane(polygon: &[Vec3], plane_normal: Vec3, plane_point: Vec3) -> Vec<Vec3> {
+    if polygon.is_empty() {
+        return Vec::new();
     }
 
-    let mut intersections = Vec::new();
-    let t1 = (-b + discriminant.sqrt()) / (2.0 * a);
-    let t2 = (-b - discriminant.sqrt()) / (2.0 * a);
+    let plane_d = plane_normal.dot(plane_point);
+    let signed_distance = |v: Vec3| plane_normal.dot(v) - plane_d;
 
-    if (0.0..=1.0).contains(&t1) {
-        intersections.push(start + t1 * edge);
-    }
-    if (0.0..=1.0).contains(&t2) && (t1 - t2).abs() > 1e-6 {
-        intersections.push(start + t2 * edge);
-    }
+    let mut output = Vec::new();
+    let mut prev = *polygon.last().expect("checked non-empty above");
+    let mut prev_d = signed_distance(prev);
 
-    intersections
-}
+    for &current in polygon {
+        let current_d = signed_distance(current);
 
-/// Filters intersection points to only include those within the face's boundary limits.
-/// At corners, this prevents arcs from extending into adjacent faces.
-///
-/// Returns filtered vector containing only points within valid region. May be empty
-/// if all points were outside boundaries (e.g., small portal near corner).
-fn constrain_intersection_points(
-    raw_intersections: Vec<Vec3>,
-    current_face: BoundaryFace,
-    all_faces_in_corner: &[BoundaryFace],
-    min: &Vec3,
-    max: &Vec3,
-) -> Vec<Vec3> {
-    raw_intersections
-        .into_iter()
-        .filter(|point| {
-            point_within_boundary_for_face(*point, current_face, all_faces_in_corner, min, max)
-        })
-        .collect()
-}
-
-fn point_within_boundary_for_face(
-    point: Vec3,
-    current_face: BoundaryFace,
-    all_faces_in_corner: &[BoundaryFace],
-    min: &Vec3,
-    max: &Vec3,
-) -> bool {
-    // Check that point doesn't extend beyond ANY of the other faces in the corner
-    for &other_face in all_faces_in_corner {
-        if other_face == current_face {
-            continue; // Skip checking against ourselves
-        }
-        if faces_share_axis(current_face, other_face) {
-            continue; // Same axis, no constraint needed (optimization)
+        if current_d >= 0.0 {
+            if prev_d < 0.0 {
+                let t = prev_d / (prev_d - current_d);
+                output.push(prev.lerp(current, t));
+            }
+            output.push(current);
+        } else if prev_d >= 0.0 {
+            let t = prev_d / (prev_d - current_d);
+            output.push(prev.lerp(current, t));
         }
 
-        // Check if point exceeds the boundary this other face represents
-        // These are exact comparisons - no epsilon needed for geometric filtering
-        match other_face {
-            BoundaryFace::Left => {
-                if point.x < min.x {
-                    return false;
-                }
-            },
-            BoundaryFace::Right => {
-                if point.x > max.x {
-                    return false;
-                }
-            },
-            BoundaryFace::Bottom => {
-                if point.y < min.y {
-                    return false;
-                }
-            },
-            BoundaryFace::Top => {
-                if point.y > max.y {
-                    return false;
-                }
-            },
-            BoundaryFace::Back => {
-                if point.z < min.z {
-                    return false;
-                }
-            },
-            BoundaryFace::Front => {
-                if point.z > max.z {
-                    return false;
-                }
-            },
-        }
+        prev = current;
+        prev_d = current_d;
     }
 
-    true
+    output
 }
 
-/// Returns true if two faces are perpendicular to the same axis.
-/// Used to optimize constraint checks by skipping geometrically impossible conditions.
-///
-/// Faces share an axis when they're perpendicular to the same coordinate axis:
-/// - Left/Right: both perpendicular to X-axis (points have fixed X, varying Y/Z)
-/// - Top/Bottom: both perpendicular to Y-axis (points have fixed Y, varying X/Z)
-/// - Front/Back: both perpendicular to Z-axis (points have fixed Z, varying X/Y)
-///
-/// Example: When drawing on Left face (x = -55) with Right overextended (x = 55),
-/// the constraint `point.x > 55` is impossible (point.x is fixed at -55).
-/// Skipping this check is a performance optimization.
-fn faces_share_axis(face1: BoundaryFace, face2: BoundaryFace) -> bool {
-    use BoundaryFace::*;
-    matches!(
-        (face1, face2),
-        // Same face (optimization for redundant self-checks)
-        (Left, Left) | (Right, Right) |
-        (Top, Top) | (Bottom, Bottom) |
-        (Front, Front) | (Back, Back) |
-        // Opposite faces on same axis
-        (Left, Right) | (Right, Left) |
-        (Top, Bottom) | (Bottom, Top) |
-        (Front, Back) | (Back, Front)
-    )
+/// Clips `polygon` successively against `face_points`' four bounding half-space planes, one per
+/// rectangle edge (plane normal perpendicular to the edge, within the face's own plane, pointed
+/// inward by the rectangle's winding order). The survivors are the portion of `polygon` actually
+/// visible on that face - straight seams at shared edges/corners instead of a reconstructed arc
+/// angle, so a portal spanning three faces unevenly still gets an exact sweep on each. This is the
+/// full Sutherland-Hodgman treatment (clip each edge against every plane, keep insiders, interpolate
+/// crossings) rather than dropping individual points that spill past a neighboring face, so arcs
+/// stay continuous right up to a corner regardless of how many faces are overextended.
+fn clip_polygon_to_face_rectangle(polygon: &[Vec3], face_points: &[Vec3; 4]) -> Vec<Vec3> {
+    let face_normal = (face_points[1] - face_points[0])
+        .cross(face_points[3] - face_points[0])
+        .normalize();
+
+    let mut clipped = polygon.to_vec();
+    for i in 0..4 {
+        if clipped.is_empty() {
+            break;
+        }
+        let start = face_points[i];
+        let end = face_points[(i + 1) % 4];
+        let edge_dir = (end - start).normalize();
+        let inward_normal = face_normal.cross(edge_dir);
+        clipped = clip_polygon_against_plane(&clipped, inward_normal, start);
+    }
+    clipped
 }
 
 #[cfg(test)]
@@ -1198,4 +1307,213 @@ mod tests {
         let result = boundary.calculate_teleport_position(position);
         assert_eq!(result.z, 35.0);
     }
+
+    #[test]
+    fn test_intersect_ray_hits_axis_aligned_box() {
+        let boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+
+        let intersection = boundary
+            .intersect_ray(Vec3::new(-100.0, 0.0, 0.0), Vec3::X)
+            .expect("ray along +X through the box should hit");
+
+        assert_eq!(intersection.enter, 50.0);
+        assert_eq!(intersection.exit, 150.0);
+        assert_eq!(intersection.enter_normal, Dir3::NEG_X);
+        assert_eq!(intersection.exit_normal, Dir3::X);
+    }
+
+    #[test]
+    fn test_intersect_ray_misses_when_parallel_and_outside_slab() {
+        let boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+
+        // Ray travels along Z only, but starts outside the X slab - the `d.abs() < f32::EPSILON`
+        // branch must reject it immediately rather than falling through to the other axes.
+        let result = boundary.intersect_ray(Vec3::new(100.0, 0.0, 0.0), Vec3::Z);
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_intersect_ray_parallel_and_inside_slab_still_hits_other_axes() {
+        let boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+
+        // Ray travels along Z only, starting inside the X/Y slabs - those axes should be skipped
+        // (not constrain enter/exit) rather than rejecting the ray.
+        let intersection = boundary
+            .intersect_ray(Vec3::ZERO, Vec3::Z)
+            .expect("ray starting inside the X/Y slabs should still hit the Z faces");
+
+        assert_eq!(intersection.enter, -50.0);
+        assert_eq!(intersection.exit, 50.0);
+        assert_eq!(intersection.enter_normal, Dir3::NEG_Z);
+        assert_eq!(intersection.exit_normal, Dir3::Z);
+    }
+
+    #[test]
+    fn test_intersect_ray_starting_inside_box() {
+        let boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+
+        let intersection = boundary
+            .intersect_ray(Vec3::ZERO, Vec3::X)
+            .expect("ray starting inside the box should still report both ends");
+
+        // Origin is inside, so `enter` lands behind the ray and `exit` ahead of it.
+        assert_eq!(intersection.enter, -50.0);
+        assert_eq!(intersection.exit, 50.0);
+    }
+
+    #[test]
+    fn test_crossing_diagonal_picks_first_crossed_face_not_axis_order() {
+        let boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+
+        // Moves twice as fast along Y as X, so the top face (Y) is reached at t=0.25, well
+        // before the right face (X) would be reached at t=0.5 - despite X being checked first
+        // in `crossing`'s per-axis loop, `exit_face` must still come out as `Top`.
+        let result = boundary.crossing(Vec3::ZERO, Vec3::new(100.0, 200.0, 0.0));
+
+        let (position, exit_face, t) = result.expect("diagonal motion should cross the boundary");
+        assert_eq!(exit_face, BoundaryFace::Top);
+        assert_eq!(t, 0.25);
+        assert_eq!(position, Vec3::new(25.0, 50.0, 0.0));
+    }
+
+    #[test]
+    fn test_crossing_with_zero_velocity_axis_inside_slab() {
+        let boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+
+        // Y and Z don't move at all this frame; both sit inside their slabs, so they should be
+        // skipped rather than blocking the crossing found on X.
+        let result = boundary.crossing(Vec3::ZERO, Vec3::new(100.0, 0.0, 0.0));
+
+        let (position, exit_face, t) = result.expect("motion along X alone should still cross");
+        assert_eq!(exit_face, BoundaryFace::Right);
+        assert_eq!(t, 0.5);
+        assert_eq!(position, Vec3::new(50.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_crossing_none_when_stationary_axis_starts_outside_slab() {
+        let boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+
+        // Y doesn't move this frame and already sits outside its slab - no amount of X motion
+        // can possibly cross the boundary within this frame's segment.
+        let result = boundary.crossing(Vec3::new(0.0, 60.0, 0.0), Vec3::new(100.0, 0.0, 0.0));
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_clip_polygon_against_plane_through_shared_edge_keeps_whole_polygon() {
+        // Square spanning x in [0, 2], clipped against the plane x=0 - which runs exactly along
+        // the square's own left edge, so every vertex sits exactly on the plane (distance 0) and
+        // should count as "inside" rather than being clipped away.
+        let polygon = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(2.0, 2.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+        ];
+
+        let result = clip_polygon_against_plane(&polygon, Vec3::X, Vec3::ZERO);
+
+        assert_eq!(result, polygon);
+    }
+
+    #[test]
+    fn test_clip_polygon_against_plane_cuts_quad_in_half() {
+        // Same square, clipped against x=1 - should come back as the right half: a rectangle
+        // spanning x in [1, 2].
+        let polygon = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(2.0, 2.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+        ];
+
+        let result = clip_polygon_against_plane(&polygon, Vec3::X, Vec3::new(1.0, 0.0, 0.0));
+
+        assert_eq!(result, vec![
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(2.0, 2.0, 0.0),
+            Vec3::new(1.0, 2.0, 0.0),
+        ]);
+    }
+
+    #[test]
+    fn test_clip_polygon_against_plane_missing_polygon_entirely_returns_empty() {
+        // Plane sits at x=10, far past this square (x in [0, 2]) - every vertex is on the
+        // negative side, so the result must be an empty polygon, not a degenerate sliver.
+        let polygon = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(2.0, 2.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+        ];
+
+        let result = clip_polygon_against_plane(&polygon, Vec3::X, Vec3::new(10.0, 0.0, 0.0));
+
+        assert_eq!(result, Vec::<Vec3>::new());
+    }
+
+    #[test]
+    fn test_resolve_boundary_crossing_reflected() {
+        let mut boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+        boundary.face_behaviors.right = BoundaryFaceBehavior::Reflect;
+
+        let result = boundary.resolve_boundary_crossing(Vec3::new(55.0, 0.0, 0.0));
+
+        match result {
+            BoundaryCrossing::Reflected { position, normal } => {
+                assert_eq!(position, Vec3::new(50.0 - BOUNDARY_SNAP_EPSILON, 0.0, 0.0));
+                assert_eq!(normal, Dir3::X);
+            },
+            other => panic!("expected Reflected, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_boundary_crossing_stopped() {
+        let mut boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+        boundary.face_behaviors.left = BoundaryFaceBehavior::Solid;
+
+        let result = boundary.resolve_boundary_crossing(Vec3::new(-60.0, 0.0, 0.0));
+
+        match result {
+            BoundaryCrossing::Stopped(position) => {
+                assert_eq!(position, Vec3::new(-50.0 + BOUNDARY_SNAP_EPSILON, 0.0, 0.0));
+            },
+            other => panic!("expected Stopped, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_boundary_crossing_still_wraps_by_default() {
+        let boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+
+        let result = boundary.resolve_boundary_crossing(Vec3::new(55.0, 0.0, 0.0));
+
+        assert_eq!(result, BoundaryCrossing::Wrapped(Vec3::new(-45.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_ray_boundary_exit_hits_ahead() {
+        let boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+
+        let result = boundary.ray_boundary_exit(Vec3::ZERO, Dir3::X);
+
+        assert_eq!(result, Some((Vec3::new(50.0, 0.0, 0.0), Dir3::X)));
+    }
+
+    #[test]
+    fn test_ray_boundary_exit_none_when_exit_is_behind_origin() {
+        let boundary = create_test_boundary(Vec3::new(100.0, 100.0, 100.0));
+
+        // Origin already past the right face, moving further away - both the entry and exit of
+        // the box lie behind the ray (`exit < 0.0`), the branch `ray_boundary_exit`'s doc comment
+        // calls out.
+        let result = boundary.ray_boundary_exit(Vec3::new(100.0, 0.0, 0.0), Dir3::X);
+
+        assert_eq!(result, None);
+    }
 }
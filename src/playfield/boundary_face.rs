@@ -2,7 +2,7 @@ use bevy::math::Dir3;
 use bevy::math::Vec3;
 use bevy::prelude::Reflect;
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
 pub enum BoundaryFace {
     #[default]
     Left,
@@ -49,6 +49,29 @@ impl BoundaryFace {
         }
     }
 
+    /// The face on the opposite side of the boundary along the same axis - the default
+    /// "straight wrap" pairing used before portal transforms could be reconfigured.
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Front => Self::Back,
+            Self::Back => Self::Front,
+        }
+    }
+
+    /// A tangent direction spanning this face's plane, shared by both faces on the same axis
+    /// so a straight (same-axis) pairing resolves to an identity `PortalTransform`.
+    pub const fn tangent_u(self) -> Vec3 {
+        match self {
+            Self::Left | Self::Right => Vec3::Y,
+            Self::Top | Self::Bottom => Vec3::Z,
+            Self::Front | Self::Back => Vec3::X,
+        }
+    }
+
     pub const fn get_face_points(self, min: &Vec3, max: &Vec3) -> [Vec3; 4] {
         match self {
             Self::Left => [
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+/// The camera's six frustum planes, each stored as `(normal, d)` such that a point is on the
+/// inside of the plane when `normal.dot(point) + d >= 0.0`. Extracted from the view-projection
+/// matrix via the standard Gribb/Hartmann row-subtraction technique, adapted for Bevy's
+/// reverse-Z `[0, 1]` NDC depth range (so `near`/`far` come from `row3 - row2` and `row2` rather
+/// than the OpenGL-style `row3 + row2` / `row3 - row2` pair).
+pub struct FrustumPlanes {
+    planes: [(Vec3, f32); 6],
+}
+
+impl FrustumPlanes {
+    /// Builds the six frustum planes from a camera's projection and world transform.
+    pub fn from_camera(projection: &Projection, camera_transform: &GlobalTransform) -> Self {
+        let clip_from_view = projection.get_clip_from_view();
+        let view_from_world = camera_transform.to_matrix().inverse();
+        Self::from_view_projection(clip_from_view * view_from_world)
+    }
+
+    /// Builds the six frustum planes from a camera's combined `projection * view` matrix.
+    fn from_view_projection(view_projection: Mat4) -> Self {
+        let row0 = view_projection.row(0);
+        let row1 = view_projection.row(1);
+        let row2 = view_projection.row(2);
+        let row3 = view_projection.row(3);
+
+        let to_plane = |row: Vec4| (row.truncate(), row.w);
+
+        Self {
+            planes: [
+                to_plane(row3 + row0), // left
+                to_plane(row3 - row0), // right
+                to_plane(row3 + row1), // bottom
+                to_plane(row3 - row1), // top
+                to_plane(row2),        // far
+                to_plane(row3 - row2), // near
+            ],
+        }
+    }
+
+    /// Classifies an axis-aligned bounding box (given by `center` and per-axis `half_extents`)
+    /// against the frustum by testing all eight corners' signed distances against every plane:
+    /// if every corner is behind a single plane the box is entirely outside it (short-circuits to
+    /// [`FrustumTest::Out`]), if no corner is ever behind any plane the box is fully inside
+    /// ([`FrustumTest::In`]), otherwise the box straddles at least one plane
+    /// ([`FrustumTest::Clip`]).
+    pub fn test_aabb(&self, center: Vec3, half_extents: Vec3) -> FrustumTest {
+        let corners = aabb_corners(center, half_extents);
+        let mut straddles_any_plane = false;
+
+        for &(normal, d) in &self.planes {
+            let mut all_behind = true;
+            let mut any_behind = false;
+
+            for corner in corners {
+                if normal.dot(corner) + d < 0.0 {
+                    any_behind = true;
+                } else {
+                    all_behind = false;
+                }
+            }
+
+            if all_behind {
+                return FrustumTest::Out;
+            }
+            if any_behind {
+                straddles_any_plane = true;
+            }
+        }
+
+        if straddles_any_plane {
+            FrustumTest::Clip
+        } else {
+            FrustumTest::In
+        }
+    }
+}
+
+/// Result of testing a bounding volume against the frustum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrustumTest {
+    /// Entirely inside every plane.
+    In,
+    /// Straddles at least one plane - partially visible.
+    Clip,
+    /// Entirely outside at least one plane - not visible at all.
+    Out,
+}
+
+impl FrustumTest {
+    /// Whether the tested volume needs to be rendered at all (anything but fully outside).
+    pub fn is_visible(self) -> bool { self != FrustumTest::Out }
+}
+
+fn aabb_corners(center: Vec3, half_extents: Vec3) -> [Vec3; 8] {
+    let mut corners = [Vec3::ZERO; 8];
+    let mut i = 0;
+    for sx in [-1.0, 1.0] {
+        for sy in [-1.0, 1.0] {
+            for sz in [-1.0, 1.0] {
+                corners[i] = center + Vec3::new(sx, sy, sz) * half_extents;
+                i += 1;
+            }
+        }
+    }
+    corners
+}
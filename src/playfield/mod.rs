@@ -1,18 +1,30 @@
 mod boundary;
 mod boundary_face;
+mod frustum;
 mod planes;
+mod portal_render;
+mod portal_transform;
 mod portals;
 mod screen_boundary;
 mod types;
+mod wraparound_ghost;
 
 use bevy::prelude::*;
 
 pub use crate::playfield::boundary::Boundary;
+pub use crate::playfield::boundary::BoundaryCrossing;
 use crate::playfield::boundary::BoundaryPlugin;
+pub use crate::playfield::boundary_face::BoundaryFace;
 use crate::playfield::planes::PlanesPlugin;
+use crate::playfield::portal_render::PortalRenderPlugin;
+pub use crate::playfield::portal_transform::PortalTransform;
 pub use crate::playfield::portals::ActorPortals;
+pub use crate::playfield::portals::PortalConfig;
+pub use crate::playfield::portals::PortalEasing;
 use crate::playfield::portals::PortalPlugin;
 use crate::playfield::screen_boundary::ScreenBoundaryPlugin;
+pub use crate::playfield::wraparound_ghost::WraparoundGhosts;
+use crate::playfield::wraparound_ghost::WraparoundGhostPlugin;
 
 pub struct PlayfieldPlugin;
 
@@ -21,6 +33,8 @@ impl Plugin for PlayfieldPlugin {
         app.add_plugins(BoundaryPlugin)
             .add_plugins(PlanesPlugin)
             .add_plugins(PortalPlugin)
-            .add_plugins(ScreenBoundaryPlugin);
+            .add_plugins(PortalRenderPlugin)
+            .add_plugins(ScreenBoundaryPlugin)
+            .add_plugins(WraparoundGhostPlugin);
     }
 }
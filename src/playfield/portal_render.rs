@@ -0,0 +1,152 @@
+//! Renders a live view through each actor's active approaching portal, pairing it with a
+//! camera planted at the paired exit face so the disc shows what's actually waiting on the
+//! other side of the wrap instead of a flat gizmo ring. Gated behind
+//! [`PortalConfig::see_through`] since a render-target camera per portal is markedly more
+//! expensive than the ring it replaces.
+use bevy::camera::RenderTarget;
+use bevy::camera::visibility::RenderLayers;
+use bevy::image::Image;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::Extent3d;
+use bevy::render::render_resource::TextureDimension;
+use bevy::render::render_resource::TextureFormat;
+use bevy::render::render_resource::TextureUsages;
+
+use crate::camera::RenderLayer;
+use crate::orientation::CameraOrientation;
+use crate::playfield::ActorPortals;
+use crate::playfield::Boundary;
+use crate::playfield::PortalConfig;
+use crate::state::PlayingGame;
+
+pub struct PortalRenderPlugin;
+
+impl Plugin for PortalRenderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, sync_portal_windows.run_if(in_state(PlayingGame)));
+    }
+}
+
+/// The render camera and disc mesh standing in for an actor's approaching portal. Recycled
+/// as soon as the portal that spawned it closes; if the actor entity itself is despawned while
+/// the portal is open, its camera, disc and render-target image are not cleaned up.
+#[derive(Component)]
+struct PortalWindow {
+    camera: Entity,
+    disc:   Entity,
+    image:  Handle<Image>,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sync_portal_windows(
+    mut commands: Commands,
+    config: Res<PortalConfig>,
+    boundary: Res<Boundary>,
+    orientation: Res<CameraOrientation>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+    q_actors: Query<(Entity, &ActorPortals, Option<&PortalWindow>)>,
+    mut q_camera_transform: Query<&mut Transform, With<Camera3d>>,
+    mut q_disc_transform: Query<&mut Transform, Without<Camera3d>>,
+) {
+    for (entity, portals, window) in &q_actors {
+        let approaching = portals.approaching.as_ref().filter(|_| config.see_through);
+
+        let Some(approaching) = approaching else {
+            if let Some(window) = window {
+                despawn_window(&mut commands, &mut images, window);
+                commands.entity(entity).remove::<PortalWindow>();
+            }
+            continue;
+        };
+
+        let exit_face = config.exit_face(approaching.face);
+        let exit_rotation = config.transform_for(approaching.face).rotation();
+        let disc_rotation = Quat::from_rotation_arc(
+            orientation.config.axis_profundus,
+            approaching.normal().as_vec3(),
+        );
+        let camera_rotation =
+            Quat::from_rotation_arc(Vec3::NEG_Z, -exit_face.get_normal()) * exit_rotation;
+        let camera_position = boundary.face_center(exit_face) + exit_face.get_normal() * 0.5;
+
+        if let Some(window) = window {
+            if let Ok(mut camera_transform) = q_camera_transform.get_mut(window.camera) {
+                camera_transform.translation = camera_position;
+                camera_transform.rotation = camera_rotation;
+            }
+            if let Ok(mut disc_transform) = q_disc_transform.get_mut(window.disc) {
+                disc_transform.translation = approaching.position;
+                disc_transform.rotation = disc_rotation;
+                disc_transform.scale = Vec3::splat(approaching.radius * 2.0);
+            }
+            continue;
+        }
+
+        let image_handle = images.add(new_render_target_image(config.render_resolution));
+
+        let camera = commands
+            .spawn((
+                Camera3d::default(),
+                Camera {
+                    target: RenderTarget::Image(image_handle.clone().into()),
+                    order: -1,
+                    ..default()
+                },
+                Transform::from_translation(camera_position).with_rotation(camera_rotation),
+                RenderLayers::from_layers(RenderLayer::Game.layers()),
+                Name::new("PortalWindowCamera"),
+            ))
+            .id();
+
+        let disc = commands
+            .spawn((
+                Mesh3d(meshes.add(Circle::new(0.5))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color_texture: Some(image_handle.clone()),
+                    unlit: true,
+                    ..default()
+                })),
+                Transform::from_translation(approaching.position)
+                    .with_rotation(disc_rotation)
+                    .with_scale(Vec3::splat(approaching.radius * 2.0)),
+                RenderLayers::from_layers(RenderLayer::Game.layers()),
+                Name::new("PortalWindowDisc"),
+            ))
+            .id();
+
+        commands.entity(entity).insert(PortalWindow {
+            camera,
+            disc,
+            image: image_handle,
+        });
+    }
+}
+
+fn despawn_window(commands: &mut Commands, images: &mut Assets<Image>, window: &PortalWindow) {
+    commands.entity(window.camera).despawn();
+    commands.entity(window.disc).despawn();
+    images.remove(&window.image);
+}
+
+fn new_render_target_image(resolution: u32) -> Image {
+    let size = Extent3d {
+        width:                 resolution,
+        height:                resolution,
+        depth_or_array_layers: 1,
+    };
+
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING
+        | TextureUsages::COPY_DST
+        | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
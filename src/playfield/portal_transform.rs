@@ -0,0 +1,95 @@
+//! Rotates velocity (and facing) across a teleport between two boundary faces, so wrap
+//! topologies other than a straight mirror - e.g. top -> right with a turn - carry the
+//! actor's motion through at the correct relative angle. Loosely modeled on the portal-
+//! transform trio from the QuakeC portal mod scene: `apply` rotates a vector into the exit
+//! frame, `then` composes two transforms, and `invert` reverses one for backtracking.
+use bevy::prelude::*;
+
+use super::boundary_face::BoundaryFace;
+
+/// A rotation carrying a vector from an entry face's frame into an exit face's frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PortalTransform(Quat);
+
+impl PortalTransform {
+    pub const IDENTITY: Self = Self(Quat::IDENTITY);
+
+    /// Builds the transform for exiting `exit` after entering through `entry`: first aligns
+    /// the entry normal with the exit's outward direction (you leave *out of* `exit`, not
+    /// into it, hence the negation), then rolls around that shared axis so the entry's
+    /// tangent lines up with the exit's. For a same-axis pair (the default straight wrap)
+    /// this resolves to `IDENTITY`.
+    pub fn between_faces(entry: BoundaryFace, exit: BoundaryFace) -> Self {
+        let align_normals = Quat::from_rotation_arc(entry.get_normal(), -exit.get_normal());
+        let rotated_tangent = align_normals * entry.tangent_u();
+        let roll = Quat::from_rotation_arc(rotated_tangent, exit.tangent_u());
+
+        Self(roll * align_normals)
+    }
+
+    pub fn from_rotation(rotation: Quat) -> Self { Self(rotation) }
+
+    /// Rotates `vector` into the exit frame.
+    pub fn apply(self, vector: Vec3) -> Vec3 { self.0 * vector }
+
+    /// Composes `self` followed by `other`: `a.then(b).apply(v) == b.apply(a.apply(v))`.
+    pub fn then(self, other: Self) -> Self { Self(other.0 * self.0) }
+
+    /// Reverses the transform, for traversing the same pair the other way.
+    pub fn invert(self) -> Self { Self(self.0.inverse()) }
+
+    pub fn rotation(self) -> Quat { self.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn straight_wrap_is_identity() {
+        for face in [
+            BoundaryFace::Left,
+            BoundaryFace::Right,
+            BoundaryFace::Top,
+            BoundaryFace::Bottom,
+            BoundaryFace::Front,
+            BoundaryFace::Back,
+        ] {
+            let transform = PortalTransform::between_faces(face, face.opposite());
+            assert!(
+                transform.rotation().abs_diff_eq(Quat::IDENTITY, 1e-5),
+                "expected identity for {face:?} -> {:?}, got {:?}",
+                face.opposite(),
+                transform.rotation()
+            );
+        }
+    }
+
+    #[test]
+    fn exits_out_of_the_exit_face() {
+        // whatever the relative orientation, an actor must leave travelling away from the
+        // exit face, i.e. the entry normal maps to the negated exit normal
+        let transform = PortalTransform::between_faces(BoundaryFace::Top, BoundaryFace::Right);
+        let rotated = transform.apply(BoundaryFace::Top.get_normal());
+        assert!(rotated.abs_diff_eq(-BoundaryFace::Right.get_normal(), 1e-5));
+    }
+
+    #[test]
+    fn invert_undoes_apply() {
+        let transform = PortalTransform::between_faces(BoundaryFace::Top, BoundaryFace::Right);
+        let velocity = Vec3::new(3.0, -7.0, 2.0);
+        let round_tripped = transform.invert().apply(transform.apply(velocity));
+        assert!(round_tripped.abs_diff_eq(velocity, 1e-4));
+    }
+
+    #[test]
+    fn then_composes_in_apply_order() {
+        let a = PortalTransform::between_faces(BoundaryFace::Top, BoundaryFace::Right);
+        let b = PortalTransform::between_faces(BoundaryFace::Right, BoundaryFace::Back);
+        let velocity = Vec3::new(1.0, 0.5, -2.0);
+
+        let composed = a.then(b).apply(velocity);
+        let sequential = b.apply(a.apply(velocity));
+        assert!(composed.abs_diff_eq(sequential, 1e-4));
+    }
+}
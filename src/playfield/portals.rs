@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use avian3d::prelude::*;
 use bevy::app::App;
 use bevy::app::Plugin;
@@ -10,6 +12,7 @@ use bevy::prelude::*;
 use bevy_inspector_egui::inspector_options::std_options::NumberDisplay;
 use bevy_inspector_egui::prelude::*;
 use bevy_inspector_egui::quick::ResourceInspectorPlugin;
+use bevy_panorbit_camera::PanOrbitCamera;
 
 use crate::actor::Aabb;
 use crate::actor::Deaderoid;
@@ -20,6 +23,9 @@ use crate::game_input::toggle_active;
 use crate::orientation::CameraOrientation;
 use crate::playfield::Boundary;
 use crate::playfield::boundary_face::BoundaryFace;
+use crate::playfield::frustum::FrustumPlanes;
+use crate::playfield::frustum::FrustumTest;
+use crate::playfield::portal_transform::PortalTransform;
 use crate::state::IsPaused;
 use crate::state::PlayingGame;
 
@@ -61,34 +67,97 @@ fn update_portal_config(
     config.render_layers = RenderLayers::from_layers(RenderLayer::Game.layers());
 }
 
+/// Shape of the 0→1 proximity progress that [`get_approaching_radius`] and
+/// [`update_emerging_portals`] ease the circle radius through, instead of scaling it linearly -
+/// see [`Self::ease`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PortalEasing {
+    #[default]
+    Linear,
+    EaseInQuad,
+    EaseOutQuad,
+    EaseInOutCubic,
+    EaseOutBack,
+}
+
+impl PortalEasing {
+    /// Eased progress for linear progress `t` (clamped to `[0, 1]`). The result also stays in
+    /// `[0, 1]` for every curve except `EaseOutBack`, which overshoots past `1.0` before settling.
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseInQuad => t * t,
+            Self::EaseOutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            Self::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            },
+            Self::EaseOutBack => {
+                const C1: f32 = 1.70158;
+                const C3: f32 = C1 + 1.0;
+                1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
+            },
+        }
+    }
+}
+
 #[derive(Resource, Reflect, InspectorOptions, Clone, Debug)]
 #[reflect(Resource, InspectorOptions)]
-struct PortalConfig {
-    color_approaching:             Color,
-    color_approaching_deaderoid:   Color,
-    color_emerging:                Color,
+pub struct PortalConfig {
+    color_approaching:               Color,
+    color_approaching_deaderoid:     Color,
+    color_emerging:                  Color,
     #[inspector(min = 0.0, max = std::f32::consts::PI, display = NumberDisplay::Slider)]
-    pub direction_change_factor:   f32,
+    pub direction_change_factor:     f32,
     #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
-    pub distance_approach:         f32,
+    pub distance_approach:           f32,
     #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
-    pub distance_shrink:           f32,
+    pub distance_shrink:             f32,
     #[inspector(min = 1.0, max = 30.0, display = NumberDisplay::Slider)]
-    pub fadeout_duration:          f32,
+    pub fadeout_duration:            f32,
     #[inspector(min = 0, max = 40, display = NumberDisplay::Slider)]
-    line_joints:                   u32,
+    line_joints:                     u32,
     #[inspector(min = 0.1, max = 40.0, display = NumberDisplay::Slider)]
-    line_width:                    f32,
+    line_width:                      f32,
     #[inspector(min = 0.001, max = 1.0, display = NumberDisplay::Slider)]
-    pub minimum_radius:            f32,
+    pub minimum_radius:              f32,
     #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
-    pub movement_smoothing_factor: f32,
+    pub movement_smoothing_factor:   f32,
+    /// Starting step size (world units) a telefragged emergence is nudged inward/laterally
+    /// before giving up and checking further steps. See [`PortalConfig::nudge_max_distance`].
+    #[inspector(min = 0.0, max = 5.0, display = NumberDisplay::Slider)]
+    pub nudge_base_distance:         f32,
+    /// Largest step size tried before suppressing the teleport outright for lack of a clear
+    /// emergence spot.
+    #[inspector(min = 0.0, max = 20.0, display = NumberDisplay::Slider)]
+    pub nudge_max_distance:          f32,
     #[inspector(min = 1., max = 10., display = NumberDisplay::Slider)]
-    pub portal_scalar:             f32,
+    pub portal_scalar:               f32,
     #[inspector(min = 1., max = 10., display = NumberDisplay::Slider)]
-    pub portal_smallest:           f32,
+    pub portal_smallest:             f32,
+    /// Curve the approaching/emerging circle radius is eased through as it grows/shrinks,
+    /// instead of scaling linearly with proximity.
+    pub radius_easing:               PortalEasing,
     #[inspector(min = 3, max = 256, display = NumberDisplay::Slider)]
-    resolution:                    u32,
+    resolution:                      u32,
+    /// Renders a live view through the boundary's approaching portals instead of a flat gizmo
+    /// ring. Off by default since it's markedly more expensive than the ring.
+    pub see_through:                 bool,
+    #[inspector(min = 32, max = 512, display = NumberDisplay::Slider)]
+    pub render_resolution:           u32,
+    /// Which exit face an entry face wraps to. Defaults to the opposite face on the same axis
+    /// (a straight mirror wrap); overriding an entry here builds non-trivial wrap topologies
+    /// (e.g. top -> right).
+    #[reflect(ignore)]
+    pub face_pairings:               HashMap<BoundaryFace, BoundaryFace>,
+    /// Extra rotation (degrees, around the exit normal) layered onto a pair's base
+    /// [`PortalTransform`], keyed by `(entry, exit)`.
+    #[reflect(ignore)]
+    pub pair_extra_rotation_degrees: HashMap<(BoundaryFace, BoundaryFace), f32>,
 }
 
 impl Default for PortalConfig {
@@ -105,9 +174,48 @@ impl Default for PortalConfig {
             line_width:                  2.,
             minimum_radius:              0.1,
             movement_smoothing_factor:   0.08,
+            nudge_base_distance:         0.5,
+            nudge_max_distance:          5.,
             portal_scalar:               2.,
             portal_smallest:             5.,
+            radius_easing:               PortalEasing::EaseOutQuad,
             resolution:                  128,
+            see_through:                 false,
+            render_resolution:           256,
+            face_pairings:               HashMap::new(),
+            pair_extra_rotation_degrees: HashMap::new(),
+        }
+    }
+}
+
+impl PortalConfig {
+    /// The exit face for `entry`, falling back to the opposite face when unpaired.
+    pub fn exit_face(&self, entry: BoundaryFace) -> BoundaryFace {
+        self.face_pairings
+            .get(&entry)
+            .copied()
+            .unwrap_or(entry.opposite())
+    }
+
+    /// The transform an actor's velocity goes through when teleporting out of `entry`.
+    pub fn transform_for(&self, entry: BoundaryFace) -> PortalTransform {
+        let exit = self.exit_face(entry);
+        let base = PortalTransform::between_faces(entry, exit);
+
+        let extra_degrees = self
+            .pair_extra_rotation_degrees
+            .get(&(entry, exit))
+            .copied()
+            .unwrap_or(0.0);
+
+        if extra_degrees == 0.0 {
+            base
+        } else {
+            let twist = PortalTransform::from_rotation(Quat::from_axis_angle(
+                exit.get_normal(),
+                extra_degrees.to_radians(),
+            ));
+            base.then(twist)
         }
     }
 }
@@ -128,8 +236,15 @@ pub struct Portal {
     pub face:                       BoundaryFace,
     pub face_count:                 usize,
     fade_out_started:               Option<f32>,
+    /// Offset from `position` to the actor's leading corner along `actor_direction`, so
+    /// approach distance is measured from the actor's forward face rather than its center.
+    pub leading_edge_offset:        Vec3,
     pub position:                   Vec3,
     pub radius:                     f32,
+    /// Rotation the actor's velocity was carried through on the teleport that created this
+    /// portal - identity for a straight mirror wrap, non-identity for a turning pair. Exposed
+    /// for gizmo rendering to eventually orient a directional indicator by.
+    pub exit_rotation:              Quat,
 }
 
 impl Portal {
@@ -148,8 +263,10 @@ impl Default for Portal {
             face:                       BoundaryFace::Right,
             face_count:                 1,
             fade_out_started:           None,
+            leading_edge_offset:        Vec3::ZERO,
             position:                   Vec3::ZERO,
             radius:                     0.,
+            exit_rotation:              Quat::IDENTITY,
         }
     }
 }
@@ -183,6 +300,8 @@ fn init_portals(
 
         let portal_position = transform.translation;
         let actor_direction = velocity.normalize_or_zero();
+        let leading_edge_offset =
+            aabb.world_support_point(transform, actor_direction) - portal_position;
 
         let color = if deaderoid.is_some() {
             portal_config.color_approaching_deaderoid
@@ -192,6 +311,7 @@ fn init_portals(
 
         let portal = Portal {
             actor_direction,
+            leading_edge_offset,
             position: portal_position,
             boundary_distance_approach,
             boundary_distance_shrink,
@@ -203,6 +323,8 @@ fn init_portals(
         handle_approaching_visual(
             &boundary,
             portal.clone(),
+            aabb,
+            transform,
             &portal_config,
             &time,
             &mut visual,
@@ -270,6 +392,7 @@ fn handle_emerging_visual(
                     face: final_face.unwrap_or(face),
                     position: snapped_position,
                     fade_out_started: Some(time.elapsed_secs()),
+                    exit_rotation: teleporter.last_exit_rotation,
                     ..portal
                 });
             }
@@ -287,17 +410,33 @@ fn handle_emerging_visual(
 fn handle_approaching_visual(
     boundary: &Res<Boundary>,
     portal: Portal,
+    aabb: &Aabb,
+    transform: &Transform,
     portal_config: &Res<PortalConfig>,
     time: &Res<Time>,
     visual: &mut Mut<ActorPortals>,
 ) {
-    if let Some(collision_point) = boundary.find_edge_point(portal.position, portal.actor_direction)
-    {
-        let actor_distance_to_wall = portal.position.distance(collision_point);
+    // measure from the actor's leading corner, not its center, so the portal opens when the
+    // forward face reaches the wall regardless of actor size
+    let leading_edge = portal.position + portal.leading_edge_offset;
+
+    if let Some(collision_point) = boundary.find_edge_point(leading_edge, portal.actor_direction) {
+        // The direction-of-travel corner above is a good first guess at which wall is being
+        // approached, but it isn't necessarily the AABB's closest point to that wall's plane -
+        // e.g. a shallow diagonal approach picks a corner that leads forward without being
+        // flush with the wall. Once the wall's normal is known, re-derive the leading corner
+        // along that normal instead and re-measure from there, so `actor_distance_to_wall`
+        // reflects the object's true surface distance to the wall it's actually approaching.
+        let normal = boundary.get_normal_for_position(collision_point);
+        let true_leading_edge = aabb.world_support_point(transform, normal.as_vec3());
+        let actor_distance_to_wall = boundary
+            .find_edge_point(true_leading_edge, portal.actor_direction)
+            .map_or_else(
+                || leading_edge.distance(collision_point),
+                |refined_point| true_leading_edge.distance(refined_point),
+            );
 
         if actor_distance_to_wall <= portal.boundary_distance_approach {
-            let normal = boundary.get_normal_for_position(collision_point);
-
             // Create temporary portal at collision point to calculate face count BEFORE smoothing
             let face = BoundaryFace::from_normal(normal).unwrap_or(BoundaryFace::Right);
             let temp_portal = Portal {
@@ -395,7 +534,7 @@ fn update_approaching_portals(
 ) {
     for mut portal in q_portals.iter_mut() {
         if let Some(ref mut approaching) = portal.approaching {
-            let radius = get_approaching_radius(approaching);
+            let radius = get_approaching_radius(approaching, config.radius_easing);
 
             // handle fadeout and get rid of it if we're past duration
             // otherwise proceed
@@ -426,11 +565,21 @@ fn draw_approaching_portals(
     boundary: Res<Boundary>,
     config: Res<PortalConfig>,
     orientation: Res<CameraOrientation>,
+    camera_query: Query<(&Projection, &GlobalTransform), With<PanOrbitCamera>>,
     q_portals: Query<(&ActorPortals, Option<&Deaderoid>)>,
     mut gizmos: Gizmos<PortalGizmo>,
 ) {
+    let Ok((projection, camera_transform)) = camera_query.single() else {
+        return; // No camera yet, skip gizmo rendering this frame
+    };
+    let frustum = FrustumPlanes::from_camera(projection, camera_transform);
+
     for (portal, deaderoid) in q_portals.iter() {
         if let Some(ref approaching) = portal.approaching {
+            if boundary.portal_frustum_test(approaching, &frustum) == FrustumTest::Out {
+                continue;
+            }
+
             // Compute color based on current deaderoid status, not stored color
             let portal_color = if deaderoid.is_some() {
                 config.color_approaching_deaderoid
@@ -451,7 +600,7 @@ fn draw_approaching_portals(
 }
 
 // extracted for readability
-fn get_approaching_radius(approaching: &mut Portal) -> f32 {
+fn get_approaching_radius(approaching: &mut Portal, easing: PortalEasing) -> f32 {
     // 0.5 corresponds to making sure that the aabb's of an actor fits
     // once radius shrinks down - we make sure the aabb always fits
     // for now not parameterizing but maybe i'll care in the future
@@ -466,7 +615,7 @@ fn get_approaching_radius(approaching: &mut Portal) -> f32 {
         let scale_factor = (approaching.actor_distance_to_wall
             / approaching.boundary_distance_shrink)
             .clamp(0.0, 1.0);
-        min_radius + (max_radius - min_radius) * scale_factor
+        min_radius + (max_radius - min_radius) * easing.ease(scale_factor)
     }
 }
 
@@ -487,10 +636,11 @@ fn update_emerging_portals(
 
             // Calculate the progress based on elapsed time
             let progress = (elapsed_time / emerging_duration).clamp(0.0, 1.0);
+            let eased_progress = config.radius_easing.ease(progress);
 
             // Interpolate the radius from the full size down to zero
             let initial_radius = emerging.radius;
-            let radius = initial_radius * (1.0 - progress); // Scale down as progress increases
+            let radius = initial_radius * (1.0 - eased_progress); // Scale down as progress increases
 
             if radius > 0.0 {
                 emerging.radius = radius;
@@ -508,11 +658,21 @@ fn draw_emerging_portals(
     boundary: Res<Boundary>,
     config: Res<PortalConfig>,
     orientation: Res<CameraOrientation>,
+    camera_query: Query<(&Projection, &GlobalTransform), With<PanOrbitCamera>>,
     q_portals: Query<(&ActorPortals, Option<&Deaderoid>)>,
     mut gizmos: Gizmos<PortalGizmo>,
 ) {
+    let Ok((projection, camera_transform)) = camera_query.single() else {
+        return; // No camera yet, skip gizmo rendering this frame
+    };
+    let frustum = FrustumPlanes::from_camera(projection, camera_transform);
+
     for (portal, deaderoid) in q_portals.iter() {
         if let Some(ref emerging) = portal.emerging {
+            if boundary.portal_frustum_test(emerging, &frustum) == FrustumTest::Out {
+                continue;
+            }
+
             // Compute color based on current deaderoid status, not stored color
             let portal_color = if deaderoid.is_some() {
                 config.color_approaching_deaderoid
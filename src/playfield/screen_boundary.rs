@@ -1,11 +1,17 @@
 use bevy::camera::visibility::RenderLayers;
 use bevy::color::palettes::tailwind;
+use bevy::ecs::message::MessageReader;
 use bevy::prelude::*;
+use bevy::window::WindowResized;
+use bevy::window::WindowScaleFactorChanged;
 use bevy_inspector_egui::inspector_options::std_options::NumberDisplay;
 use bevy_inspector_egui::prelude::*;
 use bevy_inspector_egui::quick::ResourceInspectorPlugin;
 use bevy_panorbit_camera::PanOrbitCamera;
 
+use crate::actor::Health;
+use crate::actor::Nateroid;
+use crate::actor::Spaceship;
 use crate::camera::Edge;
 use crate::camera::RenderLayer;
 use crate::camera::ScreenSpaceBoundary;
@@ -30,6 +36,7 @@ impl Plugin for ScreenBoundaryPlugin {
                 Update,
                 apply_screen_boundary_config.run_if(resource_changed::<ScreenBoundaryConfig>),
             )
+            .add_systems(Update, reanchor_labels_on_resize)
             .add_systems(
                 Update,
                 draw_screen_aligned_boundary_box
@@ -38,6 +45,16 @@ impl Plugin for ScreenBoundaryPlugin {
             .add_systems(
                 Update,
                 cleanup_margin_labels.run_if(toggle_active(true, GameAction::BoundaryBox)),
+            )
+            .add_systems(
+                Update,
+                draw_off_screen_indicators
+                    .run_if(toggle_active(false, GameAction::OffScreenIndicators)),
+            )
+            .add_systems(
+                Update,
+                cleanup_off_screen_indicators
+                    .run_if(toggle_active(true, GameAction::OffScreenIndicators)),
             );
     }
 }
@@ -47,26 +64,55 @@ struct MarginLabel {
     edge: Edge,
 }
 
+/// UI node radar-marking a gameplay entity that's currently outside the boundary rectangle,
+/// recycled across frames the same way [`MarginLabel`] is - keyed by the tracked entity rather
+/// than an [`Edge`], since any number of entities can be off-screen at once.
+#[derive(Component, Reflect)]
+struct OffScreenIndicator {
+    target: Entity,
+}
+
 #[derive(Default, Reflect, GizmoConfigGroup)]
 struct ScreenBoundaryGizmo {}
 
+/// How the boundary rectangle's edges are rendered.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+enum BorderStyle {
+    Solid,
+    Dashed { dash: f32, gap: f32 },
+    Dotted { spacing: f32 },
+}
+
+impl Default for BorderStyle {
+    fn default() -> Self { Self::Solid }
+}
+
 #[derive(Resource, Reflect, InspectorOptions, Clone, Debug)]
 #[reflect(Resource, InspectorOptions)]
 struct ScreenBoundaryConfig {
-    rectangle_color:  Color,
-    balanced_color:   Color,
-    unbalanced_color: Color,
+    rectangle_color:          Color,
+    balanced_color:           Color,
+    unbalanced_color:         Color,
     #[inspector(min = 0.1, max = 40.0, display = NumberDisplay::Slider)]
-    line_width:       f32,
+    line_width:               f32,
+    border_style:             BorderStyle,
+    #[inspector(min = 0.0, max = 2.0, display = NumberDisplay::Slider)]
+    corner_radius:            f32,
+    indicator_color:          Color,
+    show_indicator_distance:  bool,
 }
 
 impl Default for ScreenBoundaryConfig {
     fn default() -> Self {
         Self {
-            rectangle_color:  Color::from(tailwind::YELLOW_400),
-            balanced_color:   Color::srgb(0.0, 1.0, 0.0),
-            unbalanced_color: Color::srgb(1.0, 0.0, 0.0),
-            line_width:       1.0,
+            rectangle_color:          Color::from(tailwind::YELLOW_400),
+            balanced_color:           Color::srgb(0.0, 1.0, 0.0),
+            unbalanced_color:         Color::srgb(1.0, 0.0, 0.0),
+            line_width:               1.0,
+            border_style:             BorderStyle::Solid,
+            corner_radius:            0.0,
+            indicator_color:          Color::from(tailwind::ORANGE_400),
+            show_indicator_distance:  true,
         }
     }
 }
@@ -178,15 +224,125 @@ fn create_screen_corners(
     ]
 }
 
-/// Draws the boundary rectangle outline
+const ROUNDED_CORNER_SEGMENTS: u32 = 8;
+
+/// Draws the boundary rectangle outline, styled per `config.border_style`, with corners rounded
+/// by `config.corner_radius` (clamped per corner so it can't outrun either adjacent edge).
 fn draw_rectangle(
     gizmos: &mut Gizmos<ScreenBoundaryGizmo>,
     corners: &[Vec3; 4],
     config: &ScreenBoundaryConfig,
 ) {
+    let corner_radius: [f32; 4] = std::array::from_fn(|i| {
+        let prev = corners[(i + 3) % 4];
+        let corner = corners[i];
+        let next = corners[(i + 1) % 4];
+        config
+            .corner_radius
+            .max(0.0)
+            .min(corner.distance(prev) * 0.5)
+            .min(corner.distance(next) * 0.5)
+    });
+
     for i in 0..4 {
         let next = (i + 1) % 4;
-        gizmos.line(corners[i], corners[next], config.rectangle_color);
+        let corner = corners[i];
+        let next_corner = corners[next];
+        let dir = (next_corner - corner).normalize();
+
+        let start = corner + dir * corner_radius[i];
+        let end = next_corner - dir * corner_radius[next];
+        draw_edge_span(gizmos, start, end, config.rectangle_color, config.border_style);
+
+        if corner_radius[next] > 0.0 {
+            let outgoing_dir = (corners[(next + 1) % 4] - next_corner).normalize();
+            draw_rounded_corner(
+                gizmos,
+                next_corner,
+                dir,
+                outgoing_dir,
+                corner_radius[next],
+                config.rectangle_color,
+            );
+        }
+    }
+}
+
+/// Draws one edge span per `style`: a single line for [`BorderStyle::Solid`], stepped segments
+/// for [`BorderStyle::Dashed`], or short ticks for [`BorderStyle::Dotted`].
+fn draw_edge_span(
+    gizmos: &mut Gizmos<ScreenBoundaryGizmo>,
+    start: Vec3,
+    end: Vec3,
+    color: Color,
+    style: BorderStyle,
+) {
+    let segment = end - start;
+    let length = segment.length();
+    if length <= f32::EPSILON {
+        return;
+    }
+    let dir = segment / length;
+
+    match style {
+        BorderStyle::Solid => gizmos.line(start, end, color),
+        BorderStyle::Dashed { dash, gap } => {
+            let stride = dash + gap;
+            if stride <= f32::EPSILON {
+                gizmos.line(start, end, color);
+                return;
+            }
+            let mut t = 0.0;
+            while t < length {
+                let dash_end = (t + dash).min(length);
+                gizmos.line(start + dir * t, start + dir * dash_end, color);
+                t += stride;
+            }
+        },
+        BorderStyle::Dotted { spacing } => {
+            if spacing <= f32::EPSILON {
+                gizmos.line(start, end, color);
+                return;
+            }
+            let dot_length = (spacing * 0.2).min(length);
+            let mut t = 0.0;
+            while t <= length {
+                let dot_end = (t + dot_length).min(length);
+                gizmos.line(start + dir * t, start + dir * dot_end, color);
+                t += spacing;
+            }
+        },
+    }
+}
+
+/// Replaces a sharp 90-degree corner with a short polyline arc, sweeping from the end of the
+/// incoming edge's inset span to the start of the outgoing edge's - mirrors the arc-from-center
+/// technique in `playfield::boundary`, but sampled manually since the request calls for an
+/// explicit point sweep rather than a `Gizmos::arc_3d` call.
+fn draw_rounded_corner(
+    gizmos: &mut Gizmos<ScreenBoundaryGizmo>,
+    corner: Vec3,
+    incoming_dir: Vec3,
+    outgoing_dir: Vec3,
+    radius: f32,
+    color: Color,
+) {
+    let normal = incoming_dir.cross(outgoing_dir).normalize_or_zero();
+    if normal == Vec3::ZERO {
+        return;
+    }
+
+    let arc_start = corner - incoming_dir * radius;
+    let center = arc_start + outgoing_dir * radius;
+    let radius_start = arc_start - center;
+
+    let mut previous_point = arc_start;
+    for step in 1..=ROUNDED_CORNER_SEGMENTS {
+        let t = step as f32 / ROUNDED_CORNER_SEGMENTS as f32;
+        let rotation = Quat::from_axis_angle(normal, std::f32::consts::FRAC_PI_2 * t);
+        let point = center + rotation * radius_start;
+        gizmos.line(previous_point, point, color);
+        previous_point = point;
     }
 }
 
@@ -289,25 +445,34 @@ fn draw_screen_aligned_boundary_box(
         return;
     };
 
-    let Projection::Perspective(perspective) = projection else {
-        return;
-    };
-
-    // Get actual viewport aspect ratio
-    let aspect_ratio = if let Some(viewport_size) = cam.logical_viewport_size() {
-        viewport_size.x / viewport_size.y
-    } else {
-        perspective.aspect_ratio
+    // The two projection kinds measure the boundary differently (tangent-space vs.
+    // world-space), but converge through the same margin/rectangle/label drawing below.
+    let margins = match projection {
+        Projection::Perspective(perspective) => {
+            let aspect_ratio = if let Some(viewport_size) = cam.logical_viewport_size() {
+                viewport_size.x / viewport_size.y
+            } else {
+                perspective.aspect_ratio
+            };
+
+            ScreenSpaceBoundary::from_camera_view(
+                &boundary.corners(),
+                cam_global,
+                perspective,
+                aspect_ratio,
+                zoom_config.zoom_margin_multiplier(),
+            )
+        },
+        Projection::Orthographic(orthographic) => ScreenSpaceBoundary::from_camera_view_orthographic(
+            &boundary.corners(),
+            cam_global,
+            orthographic,
+            zoom_config.zoom_margin_multiplier(),
+        ),
+        _ => return,
     };
 
-    // Calculate screen-space bounds using ScreenSpaceMargins
-    let Some(margins) = ScreenSpaceBoundary::from_camera_view(
-        &boundary,
-        cam_global,
-        perspective,
-        aspect_ratio,
-        zoom_config.zoom_margin_multiplier(),
-    ) else {
+    let Some(margins) = margins else {
         return; // Boundary behind camera
     };
 
@@ -404,3 +569,256 @@ fn cleanup_margin_labels(mut commands: Commands, label_query: Query<Entity, With
         commands.entity(entity).despawn();
     }
 }
+
+/// Picks the boundary edge nearest a normalized point that's outside `[min_norm_x,max_norm_x] x
+/// [min_norm_y,max_norm_y]`, clamping the point onto that edge. Returns `None` if the point is
+/// actually inside the boundary's projected extent.
+fn nearest_off_screen_edge(
+    norm_x: f32,
+    norm_y: f32,
+    margins: &ScreenSpaceBoundary,
+) -> Option<(Edge, f32, f32)> {
+    let over_left = margins.min_norm_x - norm_x;
+    let over_right = norm_x - margins.max_norm_x;
+    let over_bottom = margins.min_norm_y - norm_y;
+    let over_top = norm_y - margins.max_norm_y;
+
+    let (h_edge, h_over) = if over_left > over_right {
+        (Edge::Left, over_left)
+    } else {
+        (Edge::Right, over_right)
+    };
+    let (v_edge, v_over) = if over_bottom > over_top {
+        (Edge::Bottom, over_bottom)
+    } else {
+        (Edge::Top, over_top)
+    };
+
+    if h_over <= 0.0 && v_over <= 0.0 {
+        return None;
+    }
+
+    let edge = if h_over > v_over { h_edge } else { v_edge };
+    let clamped_x = norm_x.clamp(margins.min_norm_x, margins.max_norm_x);
+    let clamped_y = norm_y.clamp(margins.min_norm_y, margins.max_norm_y);
+    Some((edge, clamped_x, clamped_y))
+}
+
+/// Returns the arrow glyph pointing outward across the given edge - used in place of rotating
+/// the UI node itself, since a fixed glyph per edge gets the same visual result with no need to
+/// reach for `bevy_ui`'s transform support.
+const fn edge_arrow(edge: Edge) -> &'static str {
+    match edge {
+        Edge::Left => "<",
+        Edge::Right => ">",
+        Edge::Top => "^",
+        Edge::Bottom => "v",
+    }
+}
+
+/// Draws a small radar-style indicator for each [`Nateroid`]/[`Spaceship`] currently outside the
+/// boundary rectangle: projects its position the same way [`draw_screen_aligned_boundary_box`]
+/// projects boundary corners, clamps it onto the nearest edge via [`nearest_off_screen_edge`], and
+/// recycles a UI node there the same way margin labels are recycled. Wall portals aren't tracked
+/// here - they're a boundary-local [`crate::playfield::PortalConfig`]-driven visual, not an
+/// entity with a world position that can itself wander off-screen.
+fn draw_off_screen_indicators(
+    mut commands: Commands,
+    config: Res<ScreenBoundaryConfig>,
+    camera: Query<(&Camera, &GlobalTransform, &Projection), With<PanOrbitCamera>>,
+    boundary: Res<Boundary>,
+    zoom_config: Res<ZoomConfig>,
+    targets: Query<
+        (Entity, &GlobalTransform, Option<&Name>, Option<&Health>),
+        Or<(With<Nateroid>, With<Spaceship>)>,
+    >,
+    mut indicator_query: Query<
+        (Entity, &OffScreenIndicator, &mut Text, &mut Node, &mut TextColor),
+        Without<Camera>,
+    >,
+) {
+    let Ok((cam, cam_global, projection)) = camera.single() else {
+        return;
+    };
+
+    let margins = match projection {
+        Projection::Perspective(perspective) => {
+            let aspect_ratio = if let Some(viewport_size) = cam.logical_viewport_size() {
+                viewport_size.x / viewport_size.y
+            } else {
+                perspective.aspect_ratio
+            };
+
+            ScreenSpaceBoundary::from_camera_view(
+                &boundary.corners(),
+                cam_global,
+                perspective,
+                aspect_ratio,
+                zoom_config.zoom_margin_multiplier(),
+            )
+        },
+        Projection::Orthographic(orthographic) => ScreenSpaceBoundary::from_camera_view_orthographic(
+            &boundary.corners(),
+            cam_global,
+            orthographic,
+            zoom_config.zoom_margin_multiplier(),
+        ),
+        _ => return,
+    };
+
+    let Some(margins) = margins else {
+        return; // Boundary behind camera
+    };
+
+    let cam_pos = cam_global.translation();
+    let cam_rot = cam_global.rotation();
+    let cam_forward = cam_rot * Vec3::NEG_Z;
+    let cam_right = cam_rot * Vec3::X;
+    let cam_up = cam_rot * Vec3::Y;
+    let is_perspective = matches!(projection, Projection::Perspective(_));
+
+    let mut visible_targets: Vec<Entity> = Vec::new();
+
+    for (entity, transform, name, health) in &targets {
+        let projected = if is_perspective {
+            ScreenSpaceBoundary::project_perspective(
+                transform.translation(),
+                cam_pos,
+                cam_right,
+                cam_up,
+                cam_forward,
+            )
+        } else {
+            ScreenSpaceBoundary::project_orthographic(
+                transform.translation(),
+                cam_pos,
+                cam_right,
+                cam_up,
+                cam_forward,
+            )
+        };
+
+        let Some((norm_x, norm_y)) = projected else {
+            continue;
+        };
+        let Some((edge, clamped_x, clamped_y)) = nearest_off_screen_edge(norm_x, norm_y, &margins)
+        else {
+            continue;
+        };
+
+        let world_pos =
+            margins.normalized_to_world(clamped_x, clamped_y, cam_pos, cam_right, cam_up, cam_forward);
+        let Ok(screen_pos) = cam.world_to_viewport(cam_global, world_pos) else {
+            continue;
+        };
+
+        visible_targets.push(entity);
+
+        let mut text = edge_arrow(edge).to_string();
+        if let Some(name) = name {
+            text = format!("{text} {name}");
+        }
+        if let Some(health) = health {
+            text = format!("{text} {:.0}hp", health.0);
+        }
+        if config.show_indicator_distance {
+            let distance = cam_pos.distance(transform.translation());
+            text = format!("{text} {distance:.0}m");
+        }
+
+        update_or_create_off_screen_indicator(
+            &mut commands,
+            &mut indicator_query,
+            entity,
+            text,
+            config.indicator_color,
+            screen_pos,
+        );
+    }
+
+    for (entity, indicator, _, _, _) in &indicator_query {
+        if !visible_targets.contains(&indicator.target) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Updates an existing off-screen indicator or creates a new one, keyed by the tracked entity.
+fn update_or_create_off_screen_indicator(
+    commands: &mut Commands,
+    indicator_query: &mut Query<
+        (Entity, &OffScreenIndicator, &mut Text, &mut Node, &mut TextColor),
+        Without<Camera>,
+    >,
+    target: Entity,
+    text: String,
+    color: Color,
+    screen_pos: Vec2,
+) {
+    for (_, indicator, mut indicator_text, mut node, mut text_color) in indicator_query {
+        if indicator.target == target {
+            indicator_text.0.clone_from(&text);
+            text_color.0 = color;
+            node.left = Val::Px(screen_pos.x);
+            node.top = Val::Px(screen_pos.y);
+            return;
+        }
+    }
+
+    commands.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(color),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(screen_pos.x),
+            top: Val::Px(screen_pos.y),
+            ..default()
+        },
+        RenderLayers::from_layers(RenderLayer::Game.layers()),
+        OffScreenIndicator { target },
+    ));
+}
+
+fn cleanup_off_screen_indicators(
+    mut commands: Commands,
+    indicator_query: Query<Entity, With<OffScreenIndicator>>,
+) {
+    for entity in &indicator_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Despawns every `MarginLabel`/`OffScreenIndicator` node the instant the window resizes or its
+/// scale factor changes, instead of leaving stale pixel offsets on screen until the next balanced
+/// redraw happens to touch that edge - `update_or_create_margin_label` and
+/// `update_or_create_off_screen_indicator` recreate them fresh (against the new
+/// `logical_viewport_size`) on the very next active frame. Viewport-size derivation itself needs no
+/// render-target-specific handling here: `Camera::logical_viewport_size` already resolves generically
+/// for both `RenderTarget::Window` and `RenderTarget::Image` (see `portal_render`'s render-to-texture
+/// camera), and the `RenderLayers::from_layers(RenderLayer::Game.layers())` tag already on every
+/// spawned label/indicator is this repo's existing mechanism for matching them to the camera that
+/// renders that target.
+fn reanchor_labels_on_resize(
+    mut commands: Commands,
+    mut resize_events: MessageReader<WindowResized>,
+    mut scale_factor_events: MessageReader<WindowScaleFactorChanged>,
+    label_query: Query<Entity, With<MarginLabel>>,
+    indicator_query: Query<Entity, With<OffScreenIndicator>>,
+) {
+    let resized = resize_events.read().count() > 0;
+    let scale_changed = scale_factor_events.read().count() > 0;
+    if !resized && !scale_changed {
+        return;
+    }
+
+    for entity in &label_query {
+        commands.entity(entity).despawn();
+    }
+    for entity in &indicator_query {
+        commands.entity(entity).despawn();
+    }
+}
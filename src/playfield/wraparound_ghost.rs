@@ -0,0 +1,98 @@
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+use crate::actor::Aabb;
+use crate::actor::Teleporter;
+use crate::playfield::Boundary;
+use crate::playfield::BoundaryFace;
+use crate::state::PlayingGame;
+
+pub struct WraparoundGhostPlugin;
+
+impl Plugin for WraparoundGhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, sync_wraparound_ghosts.run_if(in_state(PlayingGame)));
+    }
+}
+
+/// The six boundary faces, scanned each frame to decide which ones an actor's collider
+/// currently straddles - up to three at once at a corner.
+const ALL_FACES: [BoundaryFace; 6] = [
+    BoundaryFace::Left,
+    BoundaryFace::Right,
+    BoundaryFace::Top,
+    BoundaryFace::Bottom,
+    BoundaryFace::Front,
+    BoundaryFace::Back,
+];
+
+/// Visual-only duplicates of this actor standing in for it on the opposite wall(s) it's
+/// currently straddling, giving the classic Asteroids wraparound look instead of an instant pop
+/// at the moment `teleport_at_boundary` actually moves the entity. A ghost carries no collider
+/// and is never touched by anything but `sync_wraparound_ghosts`.
+#[derive(Component, Default)]
+pub struct WraparoundGhosts(Vec<(BoundaryFace, Entity)>);
+
+/// Marks an entity as a ghost duplicate spawned by [`sync_wraparound_ghosts`], so its query
+/// excludes ghosts from being treated as actors straddling the boundary in their own right.
+#[derive(Component)]
+struct WraparoundGhostMarker;
+
+#[allow(clippy::type_complexity)]
+fn sync_wraparound_ghosts(
+    mut commands: Commands,
+    boundary: Res<Boundary>,
+    mut actors: Query<
+        (&Transform, &Aabb, &SceneRoot, &RenderLayers, &mut WraparoundGhosts),
+        (With<Teleporter>, Without<WraparoundGhostMarker>),
+    >,
+    mut ghost_transforms: Query<&mut Transform, With<WraparoundGhostMarker>>,
+) {
+    for (transform, aabb, scene, render_layers, mut ghosts) in &mut actors {
+        let radius = (aabb.size() * transform.scale).max_element() / 2.0;
+
+        let straddled: Vec<BoundaryFace> = ALL_FACES
+            .into_iter()
+            .filter(|&face| {
+                boundary
+                    .distance_to_face(transform.translation, face)
+                    .abs()
+                    < radius
+            })
+            .collect();
+
+        ghosts.0.retain(|(face, ghost_entity)| {
+            if straddled.contains(face) {
+                true
+            } else {
+                commands.entity(*ghost_entity).despawn();
+                false
+            }
+        });
+
+        for face in straddled {
+            let ghost_position = boundary.wraparound_ghost_position(transform.translation, face);
+
+            if let Some((_, ghost_entity)) = ghosts.0.iter().find(|(f, _)| *f == face) {
+                if let Ok(mut ghost_transform) = ghost_transforms.get_mut(*ghost_entity) {
+                    ghost_transform.translation = ghost_position;
+                    ghost_transform.rotation = transform.rotation;
+                    ghost_transform.scale = transform.scale;
+                }
+            } else {
+                let ghost_entity = commands
+                    .spawn((
+                        WraparoundGhostMarker,
+                        SceneRoot(scene.0.clone()),
+                        render_layers.clone(),
+                        Transform::from_translation(ghost_position)
+                            .with_rotation(transform.rotation)
+                            .with_scale(transform.scale),
+                        Name::new("WraparoundGhost"),
+                    ))
+                    .id();
+                ghosts.0.push((face, ghost_entity));
+            }
+        }
+    }
+}
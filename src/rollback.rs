@@ -0,0 +1,99 @@
+//! Deterministic fixed-step plumbing required before any networked play
+//! (GGRS-style rollback) is possible: a single rollback-serializable RNG that
+//! replaces thread RNG for gameplay-affecting randomness, plus a snapshot of
+//! it and `GameState` that can be taken/restored each confirmed frame.
+use bevy::prelude::*;
+use rand::RngCore;
+use rand::SeedableRng;
+use rand_pcg::Pcg32;
+
+use crate::state::GameState;
+
+pub struct RollbackPlugin;
+
+impl Plugin for RollbackPlugin {
+    fn build(&self, app: &mut App) { app.init_resource::<RollbackRng>(); }
+}
+
+/// A single deterministic RNG shared by every system that needs gameplay
+/// randomness (e.g. `DeathCorner::Random`). Unlike `rand::rng()`, this one's
+/// entire state fits in a snapshot, so two peers that start from the same
+/// seed and consume it in the same order stay in lockstep.
+#[derive(Resource, Clone)]
+pub struct RollbackRng(Pcg32);
+
+impl Default for RollbackRng {
+    fn default() -> Self { Self(Pcg32::seed_from_u64(0)) }
+}
+
+impl RollbackRng {
+    pub fn from_seed(seed: u64) -> Self { Self(Pcg32::seed_from_u64(seed)) }
+
+    /// Returns a value in `[0, bound)`, the rollback-safe replacement for
+    /// `rand::rng().random_range(0..bound)`.
+    pub fn random_range(&mut self, bound: usize) -> usize {
+        (self.0.next_u64() % bound as u64) as usize
+    }
+
+    /// Returns a value in `[min, max)`, the rollback-safe replacement for
+    /// `rng.random_range(min..max)` on an `f32` range.
+    pub fn random_range_f32(&mut self, min: f32, max: f32) -> f32 {
+        if (max - min).abs() < f32::EPSILON {
+            return min;
+        }
+        // Top 24 bits only: every such value is exact in an f32, so `unit` stays below 1.0.
+        let unit = ((self.0.next_u32() >> 8) as f32) / ((1u32 << 24) as f32);
+        min + unit * (max - min)
+    }
+
+    pub fn random_bool(&mut self) -> bool { self.0.next_u32() & 1 == 0 }
+
+    pub fn snapshot(&self) -> RollbackSnapshot { RollbackSnapshot { rng: self.0.clone() } }
+
+    pub fn restore(&mut self, snapshot: &RollbackSnapshot) { self.0 = snapshot.rng.clone(); }
+}
+
+/// Confirmed-frame snapshot of the RNG state. [`take_snapshot`] pairs it with
+/// the authoritative `GameState` - everything a rollback resimulation needs to
+/// reproduce - and [`restore_snapshot`] puts both back before resimulating.
+#[derive(Clone)]
+pub struct RollbackSnapshot {
+    rng: Pcg32,
+}
+
+pub fn take_snapshot(rng: &RollbackRng, game_state: &GameState) -> (RollbackSnapshot, GameState) {
+    (rng.snapshot(), *game_state)
+}
+
+pub fn restore_snapshot(
+    rng: &mut RollbackRng,
+    next_state: &mut NextState<GameState>,
+    snapshot: &RollbackSnapshot,
+    game_state: GameState,
+) {
+    rng.restore(snapshot);
+    next_state.set(game_state);
+}
+
+/// Serializable subset of `GameAction` needed to resimulate a frame: only the
+/// gameplay-affecting actions matter for rollback, not debug/inspector
+/// toggles, which are never resimulated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RollbackInput(pub u8);
+
+impl RollbackInput {
+    pub const DOCK: u8 = 1 << 0;
+    pub const FIRE: u8 = 1 << 1;
+    pub const PAUSE: u8 = 1 << 2;
+    pub const TURBO: u8 = 1 << 3;
+
+    pub const fn contains(self, flag: u8) -> bool { self.0 & flag != 0 }
+
+    pub const fn with(self, flag: u8, pressed: bool) -> Self {
+        if pressed {
+            Self(self.0 | flag)
+        } else {
+            Self(self.0 & !flag)
+        }
+    }
+}
@@ -10,6 +10,55 @@ pub enum InGameSet {
     DespawnEntities,
 }
 
+/// Discrete time-warp level, mirroring the 1x/10x/100x speedups space sims expose for skipping
+/// ahead between encounters. Drives `Time<Virtual>::relative_speed` directly for `X1`/`X10`/`X100`;
+/// `Paused` instead routes through the existing `GameState`/`IsPaused` pause gating (see
+/// `step_time_accel` in `state.rs`) so `IN_GAME_SETS` stops ticking entirely rather than merely
+/// slowing to a crawl.
+#[derive(Resource, Reflect, Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[reflect(Resource)]
+pub enum TimeAccel {
+    Paused,
+    #[default]
+    X1,
+    X10,
+    X100,
+}
+
+impl TimeAccel {
+    /// Multiplier handed to `Time<Virtual>::set_relative_speed`. `Paused` is never passed through -
+    /// physics is paused outright via `IsPaused`, not slowed to near-zero.
+    pub fn relative_speed(self) -> f64 {
+        match self {
+            Self::Paused | Self::X1 => 1.0,
+            Self::X10 => 10.0,
+            Self::X100 => 100.0,
+        }
+    }
+
+    /// `true` for the levels fast enough that spin looks like uncontrolled tumbling rather than
+    /// steering - `step_time_accel` zeroes `AngularVelocity` when crossing into one of these.
+    pub fn is_high_speed(self) -> bool {
+        matches!(self, Self::X10 | Self::X100)
+    }
+
+    pub fn step_up(self) -> Self {
+        match self {
+            Self::Paused => Self::X1,
+            Self::X1 => Self::X10,
+            Self::X10 | Self::X100 => Self::X100,
+        }
+    }
+
+    pub fn step_down(self) -> Self {
+        match self {
+            Self::Paused | Self::X1 => Self::Paused,
+            Self::X10 => Self::X1,
+            Self::X100 => Self::X10,
+        }
+    }
+}
+
 pub struct SchedulePlugin;
 
 impl Plugin for SchedulePlugin {
@@ -21,22 +70,23 @@ impl Plugin for SchedulePlugin {
             InGameSet::EntityUpdates,
         );
 
-        app.configure_sets(
-            Update,
-            IN_GAME_SETS
-                .chain()
-                // the following is pretty cool - because we added an `InGameSet` system set to
-                // all the systems that are "in game" - in order to ensure proper ordering
-                // the following comes along for the ride - i.e., they will only run _if_
-                // `in_state` evaluates to true - i.e., we are in_game
-                // and we have a system that runs on state to watch for keyboard control
-                // that takes us in or out of `InGame` - i.e., pausing
-                // 1 line of code right here allows for pausing and starting the game!
-                .run_if(in_state(IsPaused::NotPaused)),
-        )
-        .configure_sets(
-            FixedUpdate,
-            IN_GAME_SETS.chain().run_if(in_state(IsPaused::NotPaused)),
-        );
+        app.init_resource::<TimeAccel>()
+            .configure_sets(
+                Update,
+                IN_GAME_SETS
+                    .chain()
+                    // the following is pretty cool - because we added an `InGameSet` system set to
+                    // all the systems that are "in game" - in order to ensure proper ordering
+                    // the following comes along for the ride - i.e., they will only run _if_
+                    // `in_state` evaluates to true - i.e., we are in_game
+                    // and we have a system that runs on state to watch for keyboard control
+                    // that takes us in or out of `InGame` - i.e., pausing
+                    // 1 line of code right here allows for pausing and starting the game!
+                    .run_if(in_state(IsPaused::NotPaused)),
+            )
+            .configure_sets(
+                FixedUpdate,
+                IN_GAME_SETS.chain().run_if(in_state(IsPaused::NotPaused)),
+            );
     }
 }
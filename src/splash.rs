@@ -113,6 +113,7 @@ fn run_splash(
         next_state.set(GameState::InGame {
             paused:     false,
             inspecting: false,
+            turbo:      false,
         });
     }
 }
@@ -128,7 +129,7 @@ fn create_spin_sequence(home_radius: f32, durations: &[f32]) -> Vec<CameraMove>
     positions
         .iter()
         .zip(durations.iter().cycle())
-        .map(|(pos, &duration)| CameraMove {
+        .map(|(pos, &duration)| CameraMove::ToTarget {
             target_translation: *pos,
             target_focus:       Vec3::ZERO,
             duration_ms:        duration,
@@ -142,57 +143,57 @@ fn create_splash_camera_moves(splash_start_radius: f32, home_radius: f32) -> Vec
         // sit still at the `splash start radius`
         // let's the text animate toward camera and moves the pitch/yaw
         // because our starting position for the camera pitch/yaw is off-kilter
-        CameraMove {
+        CameraMove::ToTarget {
             target_translation: Vec3::new(0.0, 0.0, splash_start_radius),
             target_focus:       Vec3::ZERO,
             duration_ms:        2500.0,
             easing:             EaseFunction::BounceOut,
         },
         // start spin 1
-        CameraMove {
+        CameraMove::ToTarget {
             target_translation: Vec3::new(0.0, 0.0, home_radius),
             target_focus:       Vec3::ZERO,
             duration_ms:        1500.0,
             easing:             EaseFunction::QuadraticIn,
         },
-        CameraMove {
+        CameraMove::ToTarget {
             target_translation: Vec3::new(home_radius, 0.0, 0.0),
             target_focus:       Vec3::ZERO,
             duration_ms:        500.0,
             easing:             EaseFunction::Linear,
         },
-        CameraMove {
+        CameraMove::ToTarget {
             target_translation: Vec3::new(0.0, 0.0, -home_radius),
             target_focus:       Vec3::ZERO,
             duration_ms:        400.0,
             easing:             EaseFunction::Linear,
         },
-        CameraMove {
+        CameraMove::ToTarget {
             target_translation: Vec3::new(-home_radius, 0.0, 0.0),
             target_focus:       Vec3::ZERO,
             duration_ms:        300.0,
             easing:             EaseFunction::Linear,
         },
         // start spin 2
-        CameraMove {
+        CameraMove::ToTarget {
             target_translation: Vec3::new(0.0, 0.0, home_radius),
             target_focus:       Vec3::ZERO,
             duration_ms:        200.0,
             easing:             EaseFunction::Linear,
         },
-        CameraMove {
+        CameraMove::ToTarget {
             target_translation: Vec3::new(home_radius, 0.0, 0.0),
             target_focus:       Vec3::ZERO,
             duration_ms:        100.0,
             easing:             EaseFunction::Linear,
         },
-        CameraMove {
+        CameraMove::ToTarget {
             target_translation: Vec3::new(0.0, 0.0, -home_radius),
             target_focus:       Vec3::ZERO,
             duration_ms:        50.0,
             easing:             EaseFunction::Linear,
         },
-        CameraMove {
+        CameraMove::ToTarget {
             target_translation: Vec3::new(-home_radius, 0.0, 0.0),
             target_focus:       Vec3::ZERO,
             duration_ms:        25.0,
@@ -210,7 +211,7 @@ fn create_splash_camera_moves(splash_start_radius: f32, home_radius: f32) -> Vec
     ));
 
     // Land at home with smooth easing
-    moves.push(CameraMove {
+    moves.push(CameraMove::ToTarget {
         target_translation: Vec3::new(0.0, 0.0, home_radius),
         target_focus:       Vec3::ZERO,
         duration_ms:        1200.0,
@@ -238,6 +239,7 @@ fn start_splash_camera_animation(
         zoom_config.zoom_margin_multiplier(),
         projection,
         camera,
+        zoom_config.min_home_radius,
     ) else {
         return;
     };
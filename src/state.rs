@@ -5,7 +5,15 @@ use bevy::dev_tools::states::*;
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::ActionState;
 
+use crate::actor::ActorConfig;
+use crate::actor::MissileConfig;
+use crate::actor::NateroidConfig;
+use crate::actor::Spaceship;
+use crate::actor::SpaceshipControl;
+use crate::actor::SpaceshipControlConfig;
+use crate::actor::create_spawn_timer;
 use crate::game_input::GameAction;
+use crate::schedule::TimeAccel;
 
 pub struct StatePlugin;
 
@@ -14,10 +22,14 @@ impl Plugin for StatePlugin {
         app.init_state::<GameState>()
             .add_computed_state::<PlayingGame>()
             .add_computed_state::<IsPaused>()
+            .add_computed_state::<TurboMode>()
+            .init_resource::<TurboConfig>()
             .add_systems(
                 Update,
                 (
                     toggle_pause.run_if(in_state(PlayingGame)),
+                    toggle_turbo.run_if(in_state(PlayingGame)),
+                    step_time_accel.run_if(in_state(PlayingGame)),
                     restart_game.run_if(in_state(PlayingGame)),
                     restart_with_splash.run_if(in_state(PlayingGame)),
                     transition_to_in_game.run_if(in_state(GameState::GameOver)),
@@ -25,6 +37,8 @@ impl Plugin for StatePlugin {
             )
             .add_systems(OnEnter(IsPaused::Paused), pause_physics)
             .add_systems(OnEnter(IsPaused::NotPaused), unpause_physics)
+            .add_systems(OnEnter(TurboMode), apply_turbo_scaling)
+            .add_systems(OnExit(TurboMode), remove_turbo_scaling)
             .add_systems(PostStartup, transition_to_splash_on_startup)
             .add_systems(Update, log_transitions::<GameState>);
     }
@@ -38,6 +52,7 @@ pub enum GameState {
     InGame {
         paused:     bool,
         inspecting: bool,
+        turbo:      bool,
     },
     #[default]
     GameOver,
@@ -70,6 +85,41 @@ impl ComputedStates for PlayingGame {
     }
 }
 
+/// Global turbo mode, mirroring the bevy computed_states example. Like
+/// `IsPaused`, this only exists while `InGame`, and is cleared whenever we
+/// leave `InGame` (it is not one of the persisted splash/game-over values).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TurboMode;
+
+impl ComputedStates for TurboMode {
+    type SourceStates = GameState;
+
+    fn compute(sources: GameState) -> Option<Self> {
+        match sources {
+            GameState::InGame { turbo: true, .. } => Some(Self),
+            _ => None,
+        }
+    }
+}
+
+/// Scaling factors applied to actor configs while `TurboMode` is active.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct TurboConfig {
+    /// Multiplier applied to `max_linear_velocity` / `max_angular_velocity`.
+    pub velocity_multiplier: f32,
+    /// Divisor applied to `spawn_timer_seconds`.
+    pub spawn_timer_divisor: f32,
+}
+
+impl Default for TurboConfig {
+    fn default() -> Self {
+        Self {
+            velocity_multiplier: 1.5,
+            spawn_timer_divisor: 2.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum IsPaused {
     NotPaused,
@@ -97,13 +147,110 @@ fn toggle_pause(
     state: Res<State<GameState>>,
 ) {
     if user_input.just_pressed(&GameAction::Pause)
-        && let GameState::InGame { paused, inspecting } = state.get()
+        && let GameState::InGame {
+            paused,
+            inspecting,
+            turbo,
+        } = state.get()
     {
         next_state.set(GameState::InGame {
             paused:     !*paused,
             inspecting: *inspecting,
+            turbo:      *turbo,
+        });
+    }
+}
+
+fn toggle_turbo(
+    user_input: Res<ActionState<GameAction>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    state: Res<State<GameState>>,
+) {
+    if user_input.just_pressed(&GameAction::Turbo)
+        && let GameState::InGame {
+            paused,
+            inspecting,
+            turbo,
+        } = state.get()
+    {
+        next_state.set(GameState::InGame {
+            paused:     *paused,
+            inspecting: *inspecting,
+            turbo:      !*turbo,
+        });
+    }
+}
+
+/// Steps `TimeAccel` up/down on input, wiring `Paused` into the existing `GameState`/`IsPaused`
+/// pause gating and everything else into `Time<Virtual>::relative_speed`. Two behaviors carried
+/// over from time-warp implementations: stepping to a faster level gives the spaceship a
+/// half-step velocity nudge along its facing at the configured `acceleration`, whether or not it
+/// is thrusting at that moment (so cruising under acceleration doesn't
+/// undershoot the distance it would have covered at the new rate before next frame's bigger delta
+/// catches up), and crossing into a high-speed level zeroes every `AngularVelocity` so nothing
+/// looks like it's tumbling out of control at 100x.
+fn step_time_accel(
+    user_input: Res<ActionState<SpaceshipControl>>,
+    mut time_accel: ResMut<TimeAccel>,
+    mut time: ResMut<Time<Virtual>>,
+    time_fixed: Res<Time<Fixed>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    state: Res<State<GameState>>,
+    spaceship_control_config: Res<SpaceshipControlConfig>,
+    mut spaceship_query: Query<(&mut LinearVelocity, &Transform), With<Spaceship>>,
+    mut angular_velocities: Query<&mut AngularVelocity>,
+) {
+    let GameState::InGame {
+        paused,
+        inspecting,
+        turbo,
+    } = *state.get()
+    else {
+        return;
+    };
+
+    let previous = *time_accel;
+    let next = if user_input.just_pressed(&SpaceshipControl::AccelUp) {
+        previous.step_up()
+    } else if user_input.just_pressed(&SpaceshipControl::AccelDown) {
+        previous.step_down()
+    } else {
+        previous
+    };
+
+    if next == previous {
+        return;
+    }
+    *time_accel = next;
+
+    let now_paused = next == TimeAccel::Paused;
+    if now_paused != paused {
+        next_state.set(GameState::InGame {
+            paused: now_paused,
+            inspecting,
+            turbo,
         });
     }
+    if now_paused {
+        // pause_physics (OnEnter(IsPaused::Paused)) takes it from here.
+        return;
+    }
+
+    time.set_relative_speed(next.relative_speed());
+
+    if next.is_high_speed() && !previous.is_high_speed() {
+        for mut angular_velocity in &mut angular_velocities {
+            **angular_velocity = Vec3::ZERO;
+        }
+    }
+
+    if next.relative_speed() > previous.relative_speed()
+        && let Ok((mut linear_velocity, transform)) = spaceship_query.single_mut()
+    {
+        let new_timestep = time_fixed.timestep().as_secs_f32() * next.relative_speed() as f32;
+        **linear_velocity +=
+            transform.forward().as_vec3() * spaceship_control_config.acceleration * 0.5 * new_timestep;
+    }
 }
 
 fn restart_game(
@@ -139,6 +286,7 @@ fn transition_to_in_game(mut next_state: ResMut<NextState<GameState>>) {
     next_state.set(GameState::InGame {
         paused:     false,
         inspecting: false,
+        turbo:      false,
     });
 }
 
@@ -156,3 +304,46 @@ fn unpause_physics(mut time: ResMut<Time<Physics>>) {
     debug!("unpausing game and physics");
     time.unpause();
 }
+
+/// Scales the effective velocity caps and spawn rates up on entering turbo mode.
+/// Config values are mutated in place so the existing per-spawn bundle
+/// construction (`insert_configured_components`, `create_spawn_timer`) just
+/// picks up the scaled numbers the next time an actor spawns.
+fn apply_turbo_scaling(
+    turbo_config: Res<TurboConfig>,
+    mut missile_config: ResMut<MissileConfig>,
+    mut nateroid_config: ResMut<NateroidConfig>,
+) {
+    debug!("entering turbo mode");
+    scale_actor_config(&mut missile_config.actor_config, &turbo_config, true);
+    scale_actor_config(&mut nateroid_config.actor_config, &turbo_config, true);
+}
+
+fn remove_turbo_scaling(
+    turbo_config: Res<TurboConfig>,
+    mut missile_config: ResMut<MissileConfig>,
+    mut nateroid_config: ResMut<NateroidConfig>,
+) {
+    debug!("leaving turbo mode");
+    scale_actor_config(&mut missile_config.actor_config, &turbo_config, false);
+    scale_actor_config(&mut nateroid_config.actor_config, &turbo_config, false);
+}
+
+fn scale_actor_config(config: &mut ActorConfig, turbo_config: &TurboConfig, entering: bool) {
+    let velocity_factor = if entering {
+        turbo_config.velocity_multiplier
+    } else {
+        1.0 / turbo_config.velocity_multiplier
+    };
+    config.max_linear_velocity *= velocity_factor;
+    config.max_angular_velocity *= velocity_factor;
+
+    if let Some(seconds) = config.spawn_timer_seconds.as_mut() {
+        *seconds = if entering {
+            *seconds / turbo_config.spawn_timer_divisor
+        } else {
+            *seconds * turbo_config.spawn_timer_divisor
+        };
+        config.spawn_timer = create_spawn_timer(config.spawn_timer_seconds);
+    }
+}
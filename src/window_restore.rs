@@ -6,6 +6,7 @@
     clippy::cast_precision_loss
 )]
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -14,13 +15,15 @@ use bevy::ecs::system::NonSendMarker;
 use bevy::prelude::*;
 use bevy::window::Monitor;
 use bevy::window::MonitorSelection;
-use bevy::window::PrimaryWindow;
+use bevy::window::PrimaryMonitor;
+use bevy::window::VideoMode;
 use bevy::window::VideoModeSelection;
 use bevy::window::WindowCreated;
 use bevy::window::WindowMode;
 use bevy::window::WindowMoved;
 use bevy::window::WindowPosition;
 use bevy::window::WindowResized;
+use bevy::window::WindowScaleFactorChanged;
 use bevy::winit::WINIT_WINDOWS;
 use dirs::config_dir;
 use serde::Deserialize;
@@ -31,24 +34,60 @@ use winit::window::Fullscreen;
 const WINDOW_STATE_FILENAME: &str = "windows.ron";
 /// The filename for monitor configuration
 const MONITORS_FILENAME: &str = "monitors.ron";
+/// Conventional `PersistedWindowId` for the app's main game window
+pub const PRIMARY_WINDOW_ID: &str = "primary";
 
-/// Plugin that handles window state persistence (saving on move/resize/mode change)
+/// Plugin that handles window state persistence (saving on move/resize/mode change) for every
+/// window entity carrying a `PersistedWindowId`
 pub struct WindowRestorePlugin;
 
 impl Plugin for WindowRestorePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<WindowStateTracker>().add_systems(
-            PostStartup,
-            (log_actual_window_position, save_monitors_on_startup),
-        );
+        app.init_resource::<WindowStateTracker>()
+            .init_resource::<WindowRestoreConfig>()
+            .add_observer(restore_persisted_window_on_add)
+            .add_systems(
+                PostStartup,
+                (
+                    reconcile_window_position_on_startup,
+                    reconcile_fullscreen_mode_on_startup,
+                    validate_window_visibility_on_startup,
+                    log_actual_window_position,
+                    save_monitors_on_startup,
+                )
+                    .chain(),
+            );
 
         app.add_systems(Update, on_window_created);
         app.add_systems(Update, on_window_moved);
         app.add_systems(Update, log_window_resized);
+        app.add_systems(Update, on_window_scale_factor_changed);
         app.add_systems(Last, save_on_window_events);
     }
 }
 
+/// Stable identifier an app assigns to a window entity it wants persisted. Saved/restored window
+/// layouts (`windows.ron`) are keyed by this string, so secondary tool/editor windows can each
+/// have their own entry alongside the main game window (conventionally [`PRIMARY_WINDOW_ID`]).
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PersistedWindowId(pub String);
+
+/// Tunables for `WindowRestorePlugin`'s post-restore off-screen validation
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct WindowRestoreConfig {
+    /// Minimum rectangle (logical pixels: width, height), anchored at a restored window's
+    /// top-left corner, that must overlap some monitor - enough of the title bar to grab with a
+    /// mouse. A window that clears less than this is considered off-screen and gets recentered on
+    /// the primary monitor instead.
+    pub min_visible_title_bar: Vec2,
+}
+
+impl Default for WindowRestoreConfig {
+    fn default() -> Self {
+        Self { min_visible_title_bar: Vec2::new(120.0, 32.0) }
+    }
+}
+
 /// Get the application name from the executable for config directory naming
 fn get_app_name() -> String {
     std::env::current_exe()
@@ -57,16 +96,42 @@ fn get_app_name() -> String {
         .unwrap_or_else(|| "bevy_app".to_string())
 }
 
-/// Create the primary `Window` with restored position/size/mode applied (if available)
-/// Set the title and other app-specific settings on the returned window
-pub fn primary_window() -> Window {
+/// Create a `Window` with restored position/size/mode applied (if a state was previously
+/// persisted under `id`). The caller is expected to insert `PersistedWindowId(id.to_string())`
+/// onto the resulting window entity once it exists, so `save_on_window_events` can track it going
+/// forward; windows spawned after the app is already running get their saved layout applied by
+/// `restore_persisted_window_on_add` instead, since they don't exist yet for this function to act
+/// on.
+pub fn persisted_window(id: &str) -> Window {
     let mut window = Window::default();
-    if let Some(state) = load_window_state() {
+    if let Some(state) = load_window_state_for(id) {
         apply_window_state(&mut window, &state);
     }
     window
 }
 
+/// Convenience wrapper for the app's main game window, keyed by [`PRIMARY_WINDOW_ID`]
+pub fn primary_window() -> Window {
+    persisted_window(PRIMARY_WINDOW_ID)
+}
+
+/// Restores a window's saved layout the moment it's tagged `PersistedWindowId` - the counterpart
+/// to `persisted_window` for windows spawned after the app is already running (e.g. a tool/editor
+/// window opened from a menu), which never go through that function since they're spawned
+/// directly as entities.
+fn restore_persisted_window_on_add(
+    trigger: On<Add, PersistedWindowId>,
+    mut windows: Query<(&PersistedWindowId, &mut Window)>,
+) {
+    let Ok((id, mut window)) = windows.get_mut(trigger.entity) else {
+        return;
+    };
+    let Some(state) = load_window_state_for(&id.0) else {
+        return;
+    };
+    apply_window_state(&mut window, &state);
+}
+
 /// Marker component: window is settling after creation (OS positioning it)
 #[derive(Component)]
 enum WindowSettling {
@@ -74,22 +139,27 @@ enum WindowSettling {
     Moved,
 }
 
-/// Resource to track last saved window state
-#[derive(Resource, Default)]
-struct WindowStateTracker {
+/// Last-saved layout for a single persisted window, used to diff against its live `Window` so we
+/// only write to disk on an actual change
+#[derive(Default, Clone, Copy, PartialEq)]
+struct TrackedWindowSnapshot {
     position: Option<IVec2>,
     size:     Option<(f32, f32)>,
     mode:     Option<WindowMode>,
 }
 
-/// Insert `WindowSettling` component on primary window when created
+/// Resource to track last saved window state, keyed by `PersistedWindowId`
+#[derive(Resource, Default)]
+struct WindowStateTracker(HashMap<String, TrackedWindowSnapshot>);
+
+/// Insert `WindowSettling` component on a persisted window when created
 fn on_window_created(
     mut reader: MessageReader<WindowCreated>,
     mut commands: Commands,
-    primary: Query<Entity, With<PrimaryWindow>>,
+    persisted: Query<Entity, With<PersistedWindowId>>,
 ) {
     for event in reader.read() {
-        if primary.get(event.window).is_ok() {
+        if persisted.get(event.window).is_ok() {
             info!(
                 "[WindowCreated] {:?} -> inserting WindowSettling::Created",
                 event.window
@@ -105,16 +175,55 @@ fn on_window_created(
 fn on_window_moved(
     mut reader: MessageReader<WindowMoved>,
     mut commands: Commands,
-    primary: Query<Entity, (With<PrimaryWindow>, With<WindowSettling>)>,
+    persisted: Query<Entity, (With<PersistedWindowId>, With<WindowSettling>)>,
 ) {
     for event in reader.read() {
         info!("[WindowMoved] {:?} to {:?}", event.window, event.position);
-        if primary.get(event.window).is_ok() {
+        if persisted.get(event.window).is_ok() {
             commands.entity(event.window).insert(WindowSettling::Moved);
         }
     }
 }
 
+/// Forces an immediate resave when a persisted window's scale factor changes - e.g. dragged from
+/// a 1.0-scale monitor onto a 2.0 Retina monitor mid-session. The regular diff-based
+/// `save_on_window_events` would otherwise keep using whatever `scale_factor` was recorded the
+/// last time position/size/mode changed, silently going stale the moment only the scale factor
+/// moves; resaving here re-derives everything (including the new `scale_factor`) from the window's
+/// current state.
+/// Uses `NonSendMarker` to force main thread execution (`build_window_state` needs `WINIT_WINDOWS`)
+fn on_window_scale_factor_changed(
+    mut reader: MessageReader<WindowScaleFactorChanged>,
+    mut tracker: ResMut<WindowStateTracker>,
+    window_query: Query<(Entity, &PersistedWindowId, &Window)>,
+    monitors: Query<&Monitor>,
+    _non_send: NonSendMarker,
+) {
+    for event in reader.read() {
+        let Ok((window_entity, persisted_id, window)) = window_query.get(event.window) else {
+            continue;
+        };
+
+        info!(
+            "[WindowScaleFactorChanged] id={} -> {}",
+            persisted_id.0,
+            window.scale_factor()
+        );
+
+        let state = build_window_state(window, window_entity, &monitors);
+        persist_window_state(&persisted_id.0, state);
+
+        tracker.0.insert(persisted_id.0.clone(), TrackedWindowSnapshot {
+            position: match window.position {
+                WindowPosition::At(pos) => Some(pos),
+                _ => None,
+            },
+            size:     Some((window.width(), window.height())),
+            mode:     Some(window.mode),
+        });
+    }
+}
+
 /// Test logging: track when `WindowResized` fires
 fn log_window_resized(mut reader: MessageReader<WindowResized>) {
     for event in reader.read() {
@@ -148,22 +257,20 @@ fn save_monitors_on_startup(monitors: Query<&Monitor>) {
     }
 }
 
-/// Log actual window position after it loads
-fn log_actual_window_position(window_query: Query<&Window, With<PrimaryWindow>>) {
-    let Ok(window) = window_query.single() else {
-        warn!("Failed to get primary window for position logging");
-        return;
-    };
-
-    debug!(
-        "[PostStartup] pos={:?} size={}x{} physical={}x{} scale={}",
-        window.position,
-        window.width(),
-        window.height(),
-        window.physical_width(),
-        window.physical_height(),
-        window.scale_factor()
-    );
+/// Log actual window position after it loads, for every persisted window
+fn log_actual_window_position(window_query: Query<(&PersistedWindowId, &Window)>) {
+    for (id, window) in &window_query {
+        debug!(
+            "[PostStartup] id={} pos={:?} size={}x{} physical={}x{} scale={}",
+            id.0,
+            window.position,
+            window.width(),
+            window.height(),
+            window.physical_width(),
+            window.physical_height(),
+            window.scale_factor()
+        );
+    }
 }
 
 /// Serializable window state that persists between sessions
@@ -183,6 +290,10 @@ pub struct WindowState {
     pub monitor_position: Option<IVec2>,
     /// Monitor index (for fullscreen mode selection)
     pub monitor_index:    Option<usize>,
+    /// Scale factor in effect when this state was saved - a window dragged across monitors mid-
+    /// session can change scale factor at any time, so restoring must convert using the factor
+    /// recorded here rather than whatever factor the target monitor happens to report
+    pub scale_factor:     f32,
 }
 
 /// Saved information about a single monitor
@@ -209,6 +320,21 @@ pub struct MonitorsState {
     pub monitors: Vec<MonitorInfo>,
 }
 
+/// Saved exclusive-fullscreen video mode (resolution + refresh rate), so restoring fullscreen can
+/// put the display back in the exact mode the player chose rather than always falling back to
+/// `VideoModeSelection::Current`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SavedVideoMode {
+    /// Physical width in pixels
+    pub physical_width:          u32,
+    /// Physical height in pixels
+    pub physical_height:         u32,
+    /// Color bit depth
+    pub bit_depth:               u16,
+    /// Refresh rate in millihertz
+    pub refresh_rate_millihertz: u32,
+}
+
 /// Serializable version of Bevy's `WindowMode`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum WindowModeState {
@@ -216,8 +342,8 @@ pub enum WindowModeState {
     Windowed,
     /// Borderless fullscreen
     BorderlessFullscreen,
-    /// True fullscreen
-    Fullscreen,
+    /// True fullscreen, with the exact display mode the player had chosen (if known)
+    Fullscreen(Option<SavedVideoMode>),
 }
 
 impl From<WindowMode> for WindowModeState {
@@ -225,27 +351,73 @@ impl From<WindowMode> for WindowModeState {
         match mode {
             WindowMode::Windowed => Self::Windowed,
             WindowMode::BorderlessFullscreen(_) => Self::BorderlessFullscreen,
-            WindowMode::Fullscreen(_, _) => Self::Fullscreen,
+            WindowMode::Fullscreen(_, VideoModeSelection::Current) => Self::Fullscreen(None),
+            WindowMode::Fullscreen(_, VideoModeSelection::Specific(video_mode)) => {
+                Self::Fullscreen(Some(SavedVideoMode {
+                    physical_width:          video_mode.physical_size.x,
+                    physical_height:         video_mode.physical_size.y,
+                    bit_depth:               video_mode.bit_depth,
+                    refresh_rate_millihertz: video_mode.refresh_rate_millihertz,
+                }))
+            },
         }
     }
 }
 
 impl WindowModeState {
-    /// Convert to Bevy's `WindowMode` with optional monitor selection
-    pub fn to_window_mode(&self, monitor_index: Option<usize>) -> WindowMode {
+    /// Convert to Bevy's `WindowMode` with optional monitor selection. `available_modes` is the
+    /// target monitor's real `Monitor::video_modes` - pass `&[]` when no monitor is queryable yet
+    /// (e.g. at window-creation time, before the app exists), which always falls back to
+    /// `VideoModeSelection::Current`; pass the live list once monitors are queryable
+    /// (`PostStartup`) to let [`find_closest_video_mode`] pick the closest match.
+    pub fn to_window_mode(
+        &self,
+        monitor_index: Option<usize>,
+        available_modes: &[VideoMode],
+    ) -> WindowMode {
         let monitor_selection =
             monitor_index.map_or(MonitorSelection::Current, MonitorSelection::Index);
 
         match self {
             Self::Windowed => WindowMode::Windowed,
             Self::BorderlessFullscreen => WindowMode::BorderlessFullscreen(monitor_selection),
-            Self::Fullscreen => {
-                WindowMode::Fullscreen(monitor_selection, VideoModeSelection::Current)
+            Self::Fullscreen(saved_mode) => {
+                let video_mode_selection = saved_mode
+                    .as_ref()
+                    .and_then(|saved| find_closest_video_mode(saved, available_modes))
+                    .map_or(VideoModeSelection::Current, VideoModeSelection::Specific);
+                WindowMode::Fullscreen(monitor_selection, video_mode_selection)
             },
         }
     }
 }
 
+/// Picks the available video mode that best matches `saved`: an exact resolution + refresh-rate
+/// match if one exists, otherwise the nearest by resolution (pixel-area difference) and then by
+/// refresh rate, falling back to `None` (selection `Current`) if `available` is empty.
+fn find_closest_video_mode(saved: &SavedVideoMode, available: &[VideoMode]) -> Option<VideoMode> {
+    if let Some(exact) = available.iter().find(|mode| {
+        mode.physical_size.x == saved.physical_width
+            && mode.physical_size.y == saved.physical_height
+            && mode.refresh_rate_millihertz == saved.refresh_rate_millihertz
+    }) {
+        return Some(exact.clone());
+    }
+
+    available
+        .iter()
+        .min_by_key(|mode| {
+            let size_diff = (i64::from(mode.physical_size.x) * i64::from(mode.physical_size.y)
+                - i64::from(saved.physical_width) * i64::from(saved.physical_height))
+            .abs();
+            let refresh_diff = (i64::from(mode.refresh_rate_millihertz)
+                - i64::from(saved.refresh_rate_millihertz))
+            .abs();
+            (size_diff, refresh_diff)
+        })
+        .cloned()
+}
+
 /// Get the path to the window state file
 fn get_window_state_path() -> Option<PathBuf> {
     config_dir().map(|dir| dir.join(get_app_name()).join(WINDOW_STATE_FILENAME))
@@ -306,18 +478,60 @@ fn save_monitors_state(monitors: &Query<&Monitor>) {
     }
 }
 
-/// Load window state from disk, returning `None` if file doesn't exist or is invalid
-pub fn load_window_state() -> Option<WindowState> {
+/// Load all persisted window states from disk, keyed by `PersistedWindowId`, returning `None` if
+/// the file doesn't exist or is invalid
+pub fn load_window_states() -> Option<HashMap<String, WindowState>> {
     let path = get_window_state_path()?;
     let contents = fs::read_to_string(&path).ok()?;
-    let state: WindowState = ron::from_str(&contents).ok()?;
+    ron::from_str(&contents).ok()
+}
+
+/// Load a single persisted window's state by id
+fn load_window_state_for(id: &str) -> Option<WindowState> {
+    let state = load_window_states()?.remove(id)?;
     info!(
-        "[load] {path:?} -> pos={:?} size={}x{}",
+        "[load] id={id} -> pos={:?} size={}x{}",
         state.position, state.width, state.height
     );
     Some(state)
 }
 
+/// Save all persisted window states to disk
+fn save_window_states(states: &HashMap<String, WindowState>) {
+    let Some(path) = get_window_state_path() else {
+        warn!("[save] Failed to get config directory path");
+        return;
+    };
+
+    if let Some(parent) = path.parent()
+        && let Err(e) = fs::create_dir_all(parent)
+    {
+        warn!("[save] Failed to create config directory: {e}");
+        return;
+    }
+
+    match ron::ser::to_string_pretty(states, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => {
+            if let Err(e) = fs::write(&path, contents) {
+                warn!("[save] Failed to write: {e}");
+            }
+        },
+        Err(e) => warn!("[save] Failed to serialize: {e}"),
+    }
+}
+
+/// Merge a single window's newly built state into the persisted map and write the whole map back
+/// to disk
+fn persist_window_state(id: &str, state: WindowState) {
+    let mut states = load_window_states().unwrap_or_default();
+    info!(
+        "[save] id={id} pos={:?} size={}x{} mode={:?}",
+        state.position, state.width, state.height, state.mode
+    );
+    states.insert(id.to_string(), state);
+    save_window_states(&states);
+}
+
 /// Find which monitor contains the given window position and return monitor info with index
 fn find_monitor_for_position_with_index(
     position: IVec2,
@@ -358,14 +572,204 @@ pub fn apply_window_state(window: &mut Window, state: &WindowState) {
     }
 
     window.resolution.set(state.width, state.height);
-    window.mode = state.mode.to_window_mode(state.monitor_index);
+    // No `Monitor` query exists yet at window-creation time, so a saved exclusive-fullscreen video
+    // mode can't be matched here - `reconcile_fullscreen_mode_on_startup` corrects it once monitors
+    // are queryable.
+    window.mode = state.mode.to_window_mode(state.monitor_index, &[]);
     info!(
         "[apply] size={}x{} mode={:?} monitor={:?}",
         state.width, state.height, state.mode, state.monitor_index
     );
 }
 
-/// Detect effective window mode by querying winit's actual fullscreen state
+/// Finds the saved monitor among the live ones: prefers an exact `name` match, falls back to
+/// `index`, and returns `None` if neither is found so the caller can fall back further (to the
+/// primary monitor).
+fn find_reconciled_monitor<'a>(
+    monitors: &'a Query<&Monitor>,
+    monitor_name: Option<&str>,
+    monitor_index: Option<usize>,
+) -> Option<&'a Monitor> {
+    if let Some(name) = monitor_name
+        && let Some(monitor) = monitors.iter().find(|m| m.name.as_deref() == Some(name))
+    {
+        return Some(monitor);
+    }
+
+    if let Some(index) = monitor_index {
+        return monitors.iter().nth(index);
+    }
+
+    None
+}
+
+/// Shifts `position` (physical pixels, window top-left) back inside `monitor`'s bounds if the
+/// window rectangle of `window_size` lies partly or wholly outside it - floored at the monitor's
+/// own origin so a window larger than the monitor still ends up pinned to its top-left corner
+/// rather than pushed to a negative position.
+fn clamp_to_monitor(position: IVec2, window_size: IVec2, monitor: &Monitor) -> IVec2 {
+    let monitor_min = monitor.physical_position;
+    let monitor_size = IVec2::new(monitor.physical_width as i32, monitor.physical_height as i32);
+    let monitor_max = (monitor_min + monitor_size - window_size).max(monitor_min);
+
+    position.clamp(monitor_min, monitor_max)
+}
+
+/// Startup reconciliation for monitor topology changes since a window's state was saved.
+/// `apply_window_state` places each window using whatever monitor layout `WindowState` recorded,
+/// with no way to check that monitor still exists - it runs before the app, let alone any
+/// `Monitor` query, is available. Once monitors are queryable (`PostStartup`, same timing as
+/// [`save_monitors_on_startup`]), this re-derives each persisted window's absolute position from
+/// the *current* monitor layout: match the saved `monitor_name`, then `monitor_index`, then fall
+/// back to the primary monitor if the saved one is gone (laptop undocked, display unplugged), and
+/// clamp the result so the window stays fully on whichever monitor it ends up on.
+///
+/// Matches directly against `WindowState`'s own `monitor_name`/`monitor_index` rather than
+/// cross-referencing the separately persisted `MonitorsState` (`monitors.ron`) - `WindowState`
+/// already carries the exact two fields needed, so loading `monitors.ron` too would just be a
+/// second source of truth to keep in sync with this one.
+fn reconcile_window_position_on_startup(
+    monitors: Query<&Monitor>,
+    primary_monitor: Query<&Monitor, With<PrimaryMonitor>>,
+    mut window_query: Query<(&PersistedWindowId, &mut Window)>,
+) {
+    for (id, mut window) in &mut window_query {
+        let Some(state) = load_window_state_for(&id.0) else {
+            continue;
+        };
+        let (Some(relative_pos), Some(_)) = (state.position, state.monitor_position) else {
+            continue;
+        };
+
+        let reconciled_monitor = find_reconciled_monitor(
+            &monitors,
+            state.monitor_name.as_deref(),
+            state.monitor_index,
+        )
+        .or_else(|| primary_monitor.single().ok());
+        let Some(monitor) = reconciled_monitor else {
+            continue;
+        };
+
+        // Use the scale factor recorded at save time, not the window's current one - they can
+        // differ if the window landed on a different-DPI monitor than the one it was saved on.
+        let scale_factor = state.scale_factor;
+        let monitor_position_logical = IVec2::new(
+            (monitor.physical_position.x as f32 / scale_factor) as i32,
+            (monitor.physical_position.y as f32 / scale_factor) as i32,
+        );
+        let absolute_logical = relative_pos + monitor_position_logical;
+        let absolute_physical = (absolute_logical.as_vec2() * scale_factor).as_ivec2();
+
+        let window_size_physical =
+            IVec2::new(window.physical_width() as i32, window.physical_height() as i32);
+        let reconciled_physical =
+            clamp_to_monitor(absolute_physical, window_size_physical, monitor);
+
+        info!(
+            "[reconcile] id={} monitor={:?} pos={:?}",
+            id.0, monitor.name, reconciled_physical
+        );
+        window.position = WindowPosition::At(reconciled_physical);
+    }
+}
+
+/// Companion to [`reconcile_window_position_on_startup`]: a saved exclusive-fullscreen video mode
+/// can't be matched against real hardware modes until monitors are queryable, so
+/// `apply_window_state` always falls back to `VideoModeSelection::Current` at window-creation
+/// time. Once `Monitor::video_modes` is available (`PostStartup`), re-resolve each persisted
+/// window's saved `WindowState` against it and correct `window.mode` if a closer match exists.
+fn reconcile_fullscreen_mode_on_startup(
+    monitors: Query<&Monitor>,
+    primary_monitor: Query<&Monitor, With<PrimaryMonitor>>,
+    mut window_query: Query<(&PersistedWindowId, &mut Window)>,
+) {
+    for (id, mut window) in &mut window_query {
+        let Some(state) = load_window_state_for(&id.0) else {
+            continue;
+        };
+        let WindowModeState::Fullscreen(Some(_)) = &state.mode else {
+            continue;
+        };
+
+        let reconciled_monitor = find_reconciled_monitor(
+            &monitors,
+            state.monitor_name.as_deref(),
+            state.monitor_index,
+        )
+        .or_else(|| primary_monitor.single().ok());
+        let Some(monitor) = reconciled_monitor else {
+            continue;
+        };
+
+        window.mode = state.mode.to_window_mode(state.monitor_index, &monitor.video_modes);
+    }
+}
+
+/// Post-restore safety net: even after `reconcile_window_position_on_startup` retargets a window
+/// to a monitor that still exists, the saved coordinates could still land it mostly or fully
+/// off every monitor (e.g. a display was unplugged entirely, or the saved position was already
+/// bogus). Checks each persisted window's title-bar rectangle against every live monitor; if none
+/// overlap it by at least `WindowRestoreConfig::min_visible_title_bar`, recenters the window on
+/// the primary monitor and forces an immediate resave so the bad coordinates don't persist to the
+/// next launch.
+fn validate_window_visibility_on_startup(
+    config: Res<WindowRestoreConfig>,
+    monitors: Query<&Monitor>,
+    primary_monitor: Query<&Monitor, With<PrimaryMonitor>>,
+    mut tracker: ResMut<WindowStateTracker>,
+    mut window_query: Query<(Entity, &PersistedWindowId, &mut Window)>,
+    // Forces this system to run on main thread where `WINIT_WINDOWS` thread_local is populated
+    _non_send: NonSendMarker,
+) {
+    for (window_entity, persisted_id, mut window) in &mut window_query {
+        let WindowPosition::At(pos) = window.position else {
+            continue;
+        };
+
+        let title_bar_physical =
+            (config.min_visible_title_bar * window.scale_factor()).as_ivec2();
+        let window_rect_max = pos + title_bar_physical;
+
+        let visible = monitors.iter().any(|monitor| {
+            let monitor_min = monitor.physical_position;
+            let monitor_max = monitor_min
+                + IVec2::new(monitor.physical_width as i32, monitor.physical_height as i32);
+            let overlap = window_rect_max.min(monitor_max) - pos.max(monitor_min);
+            overlap.x >= title_bar_physical.x && overlap.y >= title_bar_physical.y
+        });
+
+        if visible {
+            continue;
+        }
+
+        let Some(primary) = primary_monitor.single().ok().or_else(|| monitors.iter().next())
+        else {
+            continue;
+        };
+
+        let window_size_physical =
+            IVec2::new(window.physical_width() as i32, window.physical_height() as i32);
+        let monitor_size =
+            IVec2::new(primary.physical_width as i32, primary.physical_height as i32);
+        let centered = primary.physical_position + (monitor_size - window_size_physical) / 2;
+
+        warn!(
+            "[validate] id={} off-screen at {pos:?}, recentering on primary monitor",
+            persisted_id.0
+        );
+        window.position = WindowPosition::At(centered);
+
+        let state = build_window_state(&window, window_entity, &monitors);
+        persist_window_state(&persisted_id.0, state);
+        tracker.0.insert(persisted_id.0.clone(), TrackedWindowSnapshot {
+            position: Some(centered),
+            size:     Some((window.width(), window.height())),
+            mode:     Some(window.mode),
+        });
+    }
+}
+
 /// This is deterministic and works cross-platform, including macOS green button fullscreen
 /// Must be called from main thread (system should use `NonSendMarker`)
 fn detect_effective_mode(
@@ -385,7 +789,15 @@ fn detect_effective_mode(
         };
 
         match winit_window.fullscreen() {
-            Some(Fullscreen::Exclusive(_)) => WindowModeState::Fullscreen,
+            Some(Fullscreen::Exclusive(video_mode)) => {
+                let size = video_mode.size();
+                WindowModeState::Fullscreen(Some(SavedVideoMode {
+                    physical_width:          size.width,
+                    physical_height:         size.height,
+                    bit_depth:               video_mode.bit_depth(),
+                    refresh_rate_millihertz: video_mode.refresh_rate_millihertz(),
+                }))
+            },
             Some(Fullscreen::Borderless(_)) => {
                 // Validate: window must fill to bottom of monitor for true fullscreen
                 if let WindowPosition::At(pos) = window.position {
@@ -410,8 +822,12 @@ fn detect_effective_mode(
     })
 }
 
-/// Shared function to save window state
-fn save_window_state(window: &Window, window_entity: Entity, monitors: &Query<&Monitor>) {
+/// Build a `WindowState` snapshot of a window's current layout
+fn build_window_state(
+    window: &Window,
+    window_entity: Entity,
+    monitors: &Query<&Monitor>,
+) -> WindowState {
     let scale_factor = window.scale_factor();
 
     // Convert physical position to logical, calculate relative to monitor
@@ -445,7 +861,7 @@ fn save_window_state(window: &Window, window_entity: Entity, monitors: &Query<&M
 
     let effective_mode = detect_effective_mode(window_entity, window, monitors);
 
-    let state = WindowState {
+    WindowState {
         position: relative_position,
         width: window.width(),
         height: window.height(),
@@ -453,83 +869,55 @@ fn save_window_state(window: &Window, window_entity: Entity, monitors: &Query<&M
         monitor_name,
         monitor_position,
         monitor_index,
-    };
-
-    let Some(path) = get_window_state_path() else {
-        warn!("[save] Failed to get config directory path");
-        return;
-    };
-
-    if let Some(parent) = path.parent()
-        && let Err(e) = fs::create_dir_all(parent)
-    {
-        warn!("[save] Failed to create config directory: {e}");
-        return;
-    }
-
-    match ron::ser::to_string_pretty(&state, ron::ser::PrettyConfig::default()) {
-        Ok(contents) => {
-            if let Err(e) = fs::write(&path, contents) {
-                warn!("[save] Failed to write: {e}");
-            } else {
-                info!(
-                    "[save] pos={:?} size={}x{} mode={:?}",
-                    state.position, state.width, state.height, state.mode
-                );
-            }
-        },
-        Err(e) => warn!("[save] Failed to serialize: {e}"),
+        scale_factor,
     }
 }
 
-/// System that saves window state when it changes
+/// System that saves a persisted window's state when it changes
 /// Uses `NonSendMarker` to force main thread execution (required for `WINIT_WINDOWS` access)
 fn save_on_window_events(
     mut commands: Commands,
     mut tracker: ResMut<WindowStateTracker>,
-    window_query: Query<(Entity, &Window, Option<&WindowSettling>), With<PrimaryWindow>>,
+    window_query: Query<(Entity, &PersistedWindowId, &Window, Option<&WindowSettling>)>,
     monitors: Query<&Monitor>,
     // Forces this system to run on main thread where `WINIT_WINDOWS` thread_local is populated
     _non_send: NonSendMarker,
 ) {
-    let Ok((window_entity, window, settling)) = window_query.single() else {
-        return;
-    };
-
-    let current_position = match window.position {
-        WindowPosition::At(pos) => Some(pos),
-        _ => None,
-    };
-    let current_size = (window.width(), window.height());
-    let current_mode = window.mode;
-
-    let position_changed = tracker.position != current_position;
-    let size_changed = tracker.size != Some(current_size);
-    let mode_changed = tracker.mode != Some(current_mode);
-
-    if position_changed || size_changed || mode_changed {
-        match settling {
-            Some(WindowSettling::Created) => {
-                info!("[WindowSettling::Created] skipping save");
-                tracker.position = current_position;
-                tracker.size = Some(current_size);
-                tracker.mode = Some(current_mode);
-                return;
-            },
-            Some(WindowSettling::Moved) => {
-                info!("[WindowSettling::Moved] skipping save, removing component");
-                commands.entity(window_entity).remove::<WindowSettling>();
-                tracker.position = current_position;
-                tracker.size = Some(current_size);
-                tracker.mode = Some(current_mode);
-                return;
+    for (window_entity, persisted_id, window, settling) in &window_query {
+        let current = TrackedWindowSnapshot {
+            position: match window.position {
+                WindowPosition::At(pos) => Some(pos),
+                _ => None,
             },
-            None => {},
+            size:     Some((window.width(), window.height())),
+            mode:     Some(window.mode),
+        };
+
+        let previous = tracker.0.entry(persisted_id.0.clone()).or_default();
+        let changed = *previous != current;
+
+        if changed {
+            match settling {
+                Some(WindowSettling::Created) => {
+                    info!("[{}] WindowSettling::Created, skipping save", persisted_id.0);
+                    *previous = current;
+                    continue;
+                },
+                Some(WindowSettling::Moved) => {
+                    info!(
+                        "[{}] WindowSettling::Moved, skipping save, removing component",
+                        persisted_id.0
+                    );
+                    commands.entity(window_entity).remove::<WindowSettling>();
+                    *previous = current;
+                    continue;
+                },
+                None => {},
+            }
+            let state = build_window_state(window, window_entity, &monitors);
+            persist_window_state(&persisted_id.0, state);
         }
-        save_window_state(window, window_entity, &monitors);
-    }
 
-    tracker.position = current_position;
-    tracker.size = Some(current_size);
-    tracker.mode = Some(current_mode);
+        *previous = current;
+    }
 }